@@ -5,8 +5,8 @@ use common_base::config::placement_center::placement_center_conf;
 use common_base::error::CommonError;
 use common_base::log::placement_center::init_placement_center_log;
 use log::info;
-use tokio::select;
-use tokio::sync::oneshot;
+use placement_center::server::start_server;
+use tokio::sync::broadcast;
 
 // 定义默认的配置路径，即当命令行没配置路径时，默认的配置文件路径
 pub const DEFAULT_PLACEMENT_CENTER_CONFIG: &str = "config/placement-center.toml";
@@ -36,26 +36,9 @@ async fn main() -> Result<(), CommonError> {
 
   info!("{:?}", config);
 
-  //start_server();
-  let (tx1, rx1) = oneshot::channel();
-  let (tx2, rx2) = oneshot::channel();
-
-  tokio::spawn(async {
-    let _ = tx1.send("one");
-  });
-
-  tokio::spawn(async {
-    let _ = tx2.send("two");
-  });
-
-  select! {
-    val= rx1=>{
-      println!("rx1 completed first with {:?}",val);
-    }
-    val= rx2=>{
-      println!("rx2 completed first with {:?}",val);
-    }
-  }
+  // 4. 启动服务，收到停止信号后通过 broadcast 通知各个服务退出
+  let (stop_send, _) = broadcast::channel(2);
+  start_server(stop_send).await;
 
   Ok(())
 }
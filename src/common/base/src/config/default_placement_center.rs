@@ -2,7 +2,9 @@ use toml::Table;
 
 use super::{
   common::Log,
-  placement_center::{Heartbeat, Network, Node, Rocksdb, System},
+  placement_center::{
+    Heartbeat, Journal, Network, Node, Rocksdb, SegmentPlacementMode, SnapshotMode, System,
+  },
 };
 
 pub fn default_cluster_name() -> String {
@@ -78,9 +80,14 @@ pub fn default_rocksdb() -> Rocksdb {
   Rocksdb {
     data_path: default_data_path(),
     max_open_files: default_max_open_files(),
+    snapshot_mode: default_snapshot_mode(),
   }
 }
 
+pub fn default_snapshot_mode() -> SnapshotMode {
+  SnapshotMode::Checkpoint
+}
+
 pub fn default_heartbeat() -> Heartbeat {
   Heartbeat {
     hearbeat_timeout_ms: default_hearbeat_timeout_ms(),
@@ -95,3 +102,18 @@ pub fn default_hearbeat_timeout_ms() -> u64 {
 pub fn default_heartbeat_check_time_ms() -> u64 {
   1000
 }
+
+pub fn default_journal() -> Journal {
+  Journal {
+    segment_placement_mode: default_segment_placement_mode(),
+    zone_key: default_zone_key(),
+  }
+}
+
+pub fn default_segment_placement_mode() -> SegmentPlacementMode {
+  SegmentPlacementMode::LeastLoaded
+}
+
+pub fn default_zone_key() -> String {
+  "zone".to_string()
+}
@@ -22,6 +22,8 @@ pub struct PlacementCenterConfig {
   pub heartbeat: Heartbeat,
   #[serde(default = "default_rocksdb")]
   pub rocksdb: Rocksdb,
+  #[serde(default = "default_journal")]
+  pub journal: Journal,
   #[serde(default = "default_log")]
   pub log: Log,
 }
@@ -64,6 +66,40 @@ pub struct Rocksdb {
   pub data_path: String,
   #[serde(default = "default_max_open_files")]
   pub max_open_files: Option<i32>,
+  #[serde(default = "default_snapshot_mode")]
+  pub snapshot_mode: SnapshotMode,
+}
+
+/// Raft 状态机快照的生成方式
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SnapshotMode {
+  /// 将 cluster 列蔟中的全部数据序列化到一个快照文件中，只适合数据量较小的场景
+  Serialize,
+  /// 使用 RocksDB checkpoint 硬链接 SST 文件，快照为 checkpoint 目录中的文件集合
+  #[default]
+  Checkpoint,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct Journal {
+  #[serde(default = "default_segment_placement_mode")]
+  pub segment_placement_mode: SegmentPlacementMode,
+  #[serde(default = "default_zone_key")]
+  pub zone_key: String,
+}
+
+/// Journal Segment 副本的放置策略
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentPlacementMode {
+  /// 按 node_id 顺序轮流选择节点
+  RoundRobin,
+  /// 优先选择承载 Segment 数量最少的节点
+  #[default]
+  LeastLoaded,
+  /// 副本尽量分散到不同的机架或可用区，区域从节点 extend_info 中 `zone_key` 对应的字段读取
+  ZoneAware,
 }
 
 static PLACEMENT_CENTER_CONF: OnceLock<PlacementCenterConfig> = OnceLock::new();
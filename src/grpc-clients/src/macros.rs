@@ -50,6 +50,47 @@ macro_rules! impl_retriable_request {
     }
   };
 
+  // 读请求的匹配规则，请求中带有 consistency 字段，除允许读旧数据外都发送到 Leader。
+  // 需要放在写请求规则之前，否则 read_consistency 会被当作表达式匹配
+  ($req:ty, $client:ty, $res:ty, $getter:ident, $op:ident, read_consistency) => {
+    // 为 $req 类型实现 RetriableRequest trait
+    impl $crate::utils::RetriableRequest for $req {
+      // 指定客户端类型
+      type Client = $client;
+      // 指定响应类型
+      type Response = $res;
+      // 指定错误类型
+      type Error = common_base::error::CommonError;
+
+      /// 根据请求的一致性级别决定是否发送到 Leader
+      fn is_leader_request(&self) -> bool {
+        self.consistency() != protocol::ReadConsistency::Stale
+      }
+
+      /// 异步方法，用于从客户端池获取客户端实例
+      async fn get_client<'a>(
+        pool: &'a crate::pool::ClientPool,
+        addr: &str,
+      ) -> Result<impl std::ops::DerefMut<Target = Self::Client> + 'a, Self::Error> {
+        // 调用客户端池的 $getter 方法获取客户端实例
+        pool.$getter(addr).await
+      }
+
+      /// 异步方法，用于调用一次客户端操作
+      async fn call_once(
+        client: &mut Self::Client,
+        request: Self,
+      ) -> Result<Self::Response, Self::Error> {
+        // 调用客户端的 $op 方法发送请求，并处理响应
+        client
+          .$op(request)
+          .await
+          .map(|reply| reply.into_inner())
+          .map_err(Into::into)
+      }
+    }
+  };
+
   // 宏的第二个匹配规则，除了上述参数外，还接收一个布尔表达式，表示是否为写请求
   ($req:ty, $client:ty, $res:ty, $getter:ident, $op:ident, $is_write_request:expr) => {
     // 为 $req 类型实现 RetriableRequest trait
@@ -92,11 +133,16 @@ macro_rules! impl_retriable_request {
         client: &mut Self::Client,
         request: Self,
       ) -> Result<Self::Response, Self::Error> {
-        client.&op(request).await.map(|reply|reply.into_inner()).map_err(Into::into)
+        // 调用客户端的 $op 方法发送请求，并处理响应
+        client
+          .$op(request)
+          .await
+          .map(|reply| reply.into_inner())
+          .map_err(Into::into)
       }
     }
   };
 }
 
 // 公开该宏，以便在 crate 内部使用
-// pub(crate) use impl_retriable_request;
+pub(crate) use impl_retriable_request;
@@ -0,0 +1,98 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply,
+  amqp::{
+    CreateBindingRequest, CreateExchangeRequest, CreateQueueRequest, CreateVhostRequest,
+    DeleteBindingRequest, DeleteExchangeRequest, DeleteQueueRequest, DeleteVhostRequest,
+    ListBindingReply, ListBindingRequest, ListExchangeReply, ListExchangeRequest, ListQueueReply,
+    ListQueueRequest, ListVhostReply, ListVhostRequest,
+  },
+};
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_amqp_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_amqp_service_call!(
+  placement_create_vhost,
+  CreateVhostRequest,
+  CommonReply,
+  CreateVhost
+);
+generate_amqp_service_call!(
+  placement_delete_vhost,
+  DeleteVhostRequest,
+  CommonReply,
+  DeleteVhost
+);
+generate_amqp_service_call!(
+  placement_list_vhost,
+  ListVhostRequest,
+  ListVhostReply,
+  ListVhost
+);
+generate_amqp_service_call!(
+  placement_create_exchange,
+  CreateExchangeRequest,
+  CommonReply,
+  CreateExchange
+);
+generate_amqp_service_call!(
+  placement_delete_exchange,
+  DeleteExchangeRequest,
+  CommonReply,
+  DeleteExchange
+);
+generate_amqp_service_call!(
+  placement_list_exchange,
+  ListExchangeRequest,
+  ListExchangeReply,
+  ListExchange
+);
+generate_amqp_service_call!(
+  placement_create_queue,
+  CreateQueueRequest,
+  CommonReply,
+  CreateQueue
+);
+generate_amqp_service_call!(
+  placement_delete_queue,
+  DeleteQueueRequest,
+  CommonReply,
+  DeleteQueue
+);
+generate_amqp_service_call!(
+  placement_list_queue,
+  ListQueueRequest,
+  ListQueueReply,
+  ListQueue
+);
+generate_amqp_service_call!(
+  placement_create_binding,
+  CreateBindingRequest,
+  CommonReply,
+  CreateBinding
+);
+generate_amqp_service_call!(
+  placement_delete_binding,
+  DeleteBindingRequest,
+  CommonReply,
+  DeleteBinding
+);
+generate_amqp_service_call!(
+  placement_list_binding,
+  ListBindingRequest,
+  ListBindingReply,
+  ListBinding
+);
@@ -0,0 +1,156 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::{
+  CommonReply,
+  amqp::{
+    CreateBindingRequest, CreateExchangeRequest, CreateQueueRequest, CreateVhostRequest,
+    DeleteBindingRequest, DeleteExchangeRequest, DeleteQueueRequest, DeleteVhostRequest,
+    ListBindingReply, ListBindingRequest, ListExchangeReply, ListExchangeRequest, ListQueueReply,
+    ListQueueRequest, ListVhostReply, ListVhostRequest, amqp_service_client::AmqpServiceClient,
+  },
+};
+use tonic::transport::Channel;
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct AmqpServiceManager {
+  pub addr: String,
+}
+
+impl AmqpServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for AmqpServiceManager {
+  type Connection = AmqpServiceClient<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match AmqpServiceClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  CreateVhostRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  create_vhost,
+  true
+);
+
+impl_retriable_request!(
+  DeleteVhostRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  delete_vhost,
+  true
+);
+
+impl_retriable_request!(
+  ListVhostRequest,
+  AmqpServiceClient<Channel>,
+  ListVhostReply,
+  placement_center_amqp_service_client,
+  list_vhost
+);
+
+impl_retriable_request!(
+  CreateExchangeRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  create_exchange,
+  true
+);
+
+impl_retriable_request!(
+  DeleteExchangeRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  delete_exchange,
+  true
+);
+
+impl_retriable_request!(
+  ListExchangeRequest,
+  AmqpServiceClient<Channel>,
+  ListExchangeReply,
+  placement_center_amqp_service_client,
+  list_exchange
+);
+
+impl_retriable_request!(
+  CreateQueueRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  create_queue,
+  true
+);
+
+impl_retriable_request!(
+  DeleteQueueRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  delete_queue,
+  true
+);
+
+impl_retriable_request!(
+  ListQueueRequest,
+  AmqpServiceClient<Channel>,
+  ListQueueReply,
+  placement_center_amqp_service_client,
+  list_queue
+);
+
+impl_retriable_request!(
+  CreateBindingRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  create_binding,
+  true
+);
+
+impl_retriable_request!(
+  DeleteBindingRequest,
+  AmqpServiceClient<Channel>,
+  CommonReply,
+  placement_center_amqp_service_client,
+  delete_binding,
+  true
+);
+
+impl_retriable_request!(
+  ListBindingRequest,
+  AmqpServiceClient<Channel>,
+  ListBindingReply,
+  placement_center_amqp_service_client,
+  list_binding
+);
@@ -0,0 +1,56 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply,
+  config::{
+    DeleteClusterConfigRequest, GetClusterConfigReply, GetClusterConfigRequest,
+    ListClusterConfigReply, ListClusterConfigRequest, SetClusterConfigReply,
+    SetClusterConfigRequest, WatchClusterConfigReply, WatchClusterConfigRequest,
+  },
+};
+use tonic::codec::Streaming;
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_config_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_config_service_call!(
+  placement_set_cluster_config,
+  SetClusterConfigRequest,
+  SetClusterConfigReply,
+  SetClusterConfig
+);
+generate_config_service_call!(
+  placement_get_cluster_config,
+  GetClusterConfigRequest,
+  GetClusterConfigReply,
+  GetClusterConfig
+);
+generate_config_service_call!(
+  placement_delete_cluster_config,
+  DeleteClusterConfigRequest,
+  CommonReply,
+  DeleteClusterConfig
+);
+generate_config_service_call!(
+  placement_list_cluster_config,
+  ListClusterConfigRequest,
+  ListClusterConfigReply,
+  ListClusterConfig
+);
+generate_config_service_call!(
+  placement_watch_cluster_config,
+  WatchClusterConfigRequest,
+  Streaming<WatchClusterConfigReply>,
+  WatchClusterConfig
+);
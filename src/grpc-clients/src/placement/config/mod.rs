@@ -0,0 +1,94 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::{
+  CommonReply,
+  config::{
+    DeleteClusterConfigRequest, GetClusterConfigReply, GetClusterConfigRequest,
+    ListClusterConfigReply, ListClusterConfigRequest, SetClusterConfigReply,
+    SetClusterConfigRequest, WatchClusterConfigReply, WatchClusterConfigRequest,
+    cluster_config_service_client::ClusterConfigServiceClient,
+  },
+};
+use tonic::{codec::Streaming, transport::Channel};
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct ClusterConfigServiceManager {
+  pub addr: String,
+}
+
+impl ClusterConfigServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for ClusterConfigServiceManager {
+  type Connection = ClusterConfigServiceClient<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match ClusterConfigServiceClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  SetClusterConfigRequest,
+  ClusterConfigServiceClient<Channel>,
+  SetClusterConfigReply,
+  placement_center_config_service_client,
+  set_cluster_config,
+  true
+);
+
+impl_retriable_request!(
+  GetClusterConfigRequest,
+  ClusterConfigServiceClient<Channel>,
+  GetClusterConfigReply,
+  placement_center_config_service_client,
+  get_cluster_config
+);
+
+impl_retriable_request!(
+  DeleteClusterConfigRequest,
+  ClusterConfigServiceClient<Channel>,
+  CommonReply,
+  placement_center_config_service_client,
+  delete_cluster_config,
+  true
+);
+
+impl_retriable_request!(
+  ListClusterConfigRequest,
+  ClusterConfigServiceClient<Channel>,
+  ListClusterConfigReply,
+  placement_center_config_service_client,
+  list_cluster_config
+);
+
+impl_retriable_request!(
+  WatchClusterConfigRequest,
+  ClusterConfigServiceClient<Channel>,
+  Streaming<WatchClusterConfigReply>,
+  placement_center_config_service_client,
+  watch_cluster_config
+);
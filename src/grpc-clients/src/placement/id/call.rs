@@ -0,0 +1,30 @@
+use common_base::error::CommonError;
+use protocol::id::{AllocateIdsReply, AllocateIdsRequest, GetNextIdReply, GetNextIdRequest};
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_id_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_id_service_call!(
+  placement_allocate_ids,
+  AllocateIdsRequest,
+  AllocateIdsReply,
+  AllocateIds
+);
+generate_id_service_call!(
+  placement_get_next_id,
+  GetNextIdRequest,
+  GetNextIdReply,
+  GetNextId
+);
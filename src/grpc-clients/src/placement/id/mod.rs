@@ -0,0 +1,64 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::id::{
+  AllocateIdsReply, AllocateIdsRequest, GetNextIdReply, GetNextIdRequest,
+  id_service_client::IdServiceClient,
+};
+use tonic::transport::Channel;
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct IdServiceManager {
+  pub addr: String,
+}
+
+impl IdServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for IdServiceManager {
+  type Connection = IdServiceClient<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match IdServiceClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  AllocateIdsRequest,
+  IdServiceClient<Channel>,
+  AllocateIdsReply,
+  placement_center_id_service_client,
+  allocate_ids,
+  true
+);
+
+impl_retriable_request!(
+  GetNextIdRequest,
+  IdServiceClient<Channel>,
+  GetNextIdReply,
+  placement_center_id_service_client,
+  get_next_id
+);
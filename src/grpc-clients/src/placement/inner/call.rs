@@ -1,12 +1,51 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply, HeartbeatRequest, ListClusterReply, ListClusterRequest, ListNodeReply,
+  ListNodeRequest, RegisterNodeRequest, UnRegisterNodeRequest,
+};
+
+use crate::pool::ClientPool;
+
 #[allow(unused_macros)]
 macro_rules! generate_placement_service_call {
-    ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident)={
-        pub async fn $fn_name(
-            client_pool:&ClientPool,
-            addrs:&[impl AsRef<str>],
-            request:$req_ty,
-        )->Result<$rep_ty,CommonError>{
-            $crate::utils::retry_call(client_pool,addrs,&request).await
-        }
-    };
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
 }
+
+generate_placement_service_call!(
+  placement_register_node,
+  RegisterNodeRequest,
+  CommonReply,
+  RegisterNode
+);
+generate_placement_service_call!(
+  placement_un_register_node,
+  UnRegisterNodeRequest,
+  CommonReply,
+  UnRegisterNode
+);
+generate_placement_service_call!(
+  placement_list_cluster,
+  ListClusterRequest,
+  ListClusterReply,
+  ListCluster
+);
+generate_placement_service_call!(
+  placement_list_node,
+  ListNodeRequest,
+  ListNodeReply,
+  ListNode
+);
+generate_placement_service_call!(
+  placement_heartbeat,
+  HeartbeatRequest,
+  CommonReply,
+  Heartbeat
+);
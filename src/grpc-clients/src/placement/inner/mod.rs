@@ -1,38 +1,90 @@
 use common_base::error::CommonError;
 use mobc::Manager;
-use protocol::placement_center_server_client::PlacementCenterServerClient;
+use protocol::{
+  CommonReply, HeartbeatRequest, ListClusterReply, ListClusterRequest, ListNodeReply,
+  ListNodeRequest, RegisterNodeRequest, UnRegisterNodeRequest,
+  placement_center_server_client::PlacementCenterServerClient,
+};
 use tonic::transport::Channel;
 
-pub struct PlacementServiceManager{
-    pub addr: String,
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+pub struct PlacementServiceManager {
+  pub addr: String,
 }
 
-impl PlacementServiceManager{
-    pub fn new(addr:String)->Self{
-        Self { addr }
-    }
+impl PlacementServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
 }
 
 #[tonic::async_trait]
 impl Manager for PlacementServiceManager {
-   
-type Connection = PlacementCenterServerClient<Channel>;
-type Error=CommonError;
-
-
-async fn connect(&self) ->  Result<Self::Connection,Self::Error>{
-        match PlacementCenterServerClient::connect(format!("http://{}",self.addr.clone())).await {
-            Ok(client)=>{
-                return Ok(client);
-            }
-            Err(err)=>{
-                return Err(CommonError::CommonError(format!("manager connect error:{},{}",err,self.addr.clone())));
-            }
-        }
-    }
-
+  type Connection = PlacementCenterServerClient<Channel>;
+  type Error = CommonError;
 
-async fn check(&self,conn:Self::Connection) -> Result<Self::Connection,Self::Error>{
-        Ok(conn)
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match PlacementCenterServerClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
     }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
 }
+
+impl_retriable_request!(
+  RegisterNodeRequest,
+  PlacementCenterServerClient<Channel>,
+  CommonReply,
+  placement_center_inner_service_client,
+  register_node,
+  true
+);
+
+impl_retriable_request!(
+  UnRegisterNodeRequest,
+  PlacementCenterServerClient<Channel>,
+  CommonReply,
+  placement_center_inner_service_client,
+  un_register_node,
+  true
+);
+
+impl_retriable_request!(
+  ListClusterRequest,
+  PlacementCenterServerClient<Channel>,
+  ListClusterReply,
+  placement_center_inner_service_client,
+  list_cluster
+);
+
+impl_retriable_request!(
+  ListNodeRequest,
+  PlacementCenterServerClient<Channel>,
+  ListNodeReply,
+  placement_center_inner_service_client,
+  list_node
+);
+
+impl_retriable_request!(
+  HeartbeatRequest,
+  PlacementCenterServerClient<Channel>,
+  CommonReply,
+  placement_center_inner_service_client,
+  heartbeat,
+  true
+);
@@ -0,0 +1,74 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply,
+  journal::{
+    CommitOffsetRequest, CreateShardReply, CreateShardRequest, FetchOffsetReply,
+    FetchOffsetRequest, GetActiveSegmentReply, GetActiveSegmentRequest, ListSegmentReply,
+    ListSegmentRequest, ListShardReply, ListShardRequest, SealSegmentReply, SealSegmentRequest,
+    UpdateSegmentStatusRequest,
+  },
+};
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_journal_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_journal_service_call!(
+  placement_create_shard,
+  CreateShardRequest,
+  CreateShardReply,
+  CreateShard
+);
+generate_journal_service_call!(
+  placement_list_shard,
+  ListShardRequest,
+  ListShardReply,
+  ListShard
+);
+generate_journal_service_call!(
+  placement_list_segment,
+  ListSegmentRequest,
+  ListSegmentReply,
+  ListSegment
+);
+generate_journal_service_call!(
+  placement_get_active_segment,
+  GetActiveSegmentRequest,
+  GetActiveSegmentReply,
+  GetActiveSegment
+);
+generate_journal_service_call!(
+  placement_update_segment_status,
+  UpdateSegmentStatusRequest,
+  CommonReply,
+  UpdateSegmentStatus
+);
+generate_journal_service_call!(
+  placement_seal_segment,
+  SealSegmentRequest,
+  SealSegmentReply,
+  SealSegment
+);
+generate_journal_service_call!(
+  placement_commit_offset,
+  CommitOffsetRequest,
+  CommonReply,
+  CommitOffset
+);
+generate_journal_service_call!(
+  placement_fetch_offset,
+  FetchOffsetRequest,
+  FetchOffsetReply,
+  FetchOffset
+);
@@ -0,0 +1,120 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::{
+  CommonReply,
+  journal::{
+    CommitOffsetRequest, CreateShardReply, CreateShardRequest, FetchOffsetReply,
+    FetchOffsetRequest, GetActiveSegmentReply, GetActiveSegmentRequest, ListSegmentReply,
+    ListSegmentRequest, ListShardReply, ListShardRequest, SealSegmentReply, SealSegmentRequest,
+    UpdateSegmentStatusRequest, journal_service_client::JournalServiceClient,
+  },
+};
+use tonic::transport::Channel;
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct JournalServiceManager {
+  pub addr: String,
+}
+
+impl JournalServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for JournalServiceManager {
+  type Connection = JournalServiceClient<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match JournalServiceClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  CreateShardRequest,
+  JournalServiceClient<Channel>,
+  CreateShardReply,
+  placement_center_journal_service_client,
+  create_shard,
+  true
+);
+
+impl_retriable_request!(
+  ListShardRequest,
+  JournalServiceClient<Channel>,
+  ListShardReply,
+  placement_center_journal_service_client,
+  list_shard
+);
+
+impl_retriable_request!(
+  ListSegmentRequest,
+  JournalServiceClient<Channel>,
+  ListSegmentReply,
+  placement_center_journal_service_client,
+  list_segment
+);
+
+impl_retriable_request!(
+  GetActiveSegmentRequest,
+  JournalServiceClient<Channel>,
+  GetActiveSegmentReply,
+  placement_center_journal_service_client,
+  get_active_segment
+);
+
+impl_retriable_request!(
+  UpdateSegmentStatusRequest,
+  JournalServiceClient<Channel>,
+  CommonReply,
+  placement_center_journal_service_client,
+  update_segment_status,
+  true
+);
+
+impl_retriable_request!(
+  SealSegmentRequest,
+  JournalServiceClient<Channel>,
+  SealSegmentReply,
+  placement_center_journal_service_client,
+  seal_segment,
+  true
+);
+
+impl_retriable_request!(
+  CommitOffsetRequest,
+  JournalServiceClient<Channel>,
+  CommonReply,
+  placement_center_journal_service_client,
+  commit_offset,
+  true
+);
+
+impl_retriable_request!(
+  FetchOffsetRequest,
+  JournalServiceClient<Channel>,
+  FetchOffsetReply,
+  placement_center_journal_service_client,
+  fetch_offset
+);
@@ -1,14 +1,54 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply, CompactRequest, DeleteRequest, ExistsReply, ExistsRequest, GetReply, GetRequest,
+  LeaseGrantReply, LeaseGrantRequest, LeaseRevokeRequest, LeaseTimeToLiveReply,
+  LeaseTimeToLiveRequest, ListByPrefixReply, ListByPrefixRequest, ScanReply, ScanRequest,
+  SetRequest, TxnReply, TxnRequest,
+};
+
+use crate::pool::ClientPool;
+
 #[allow(unused_macros)]
 macro_rules! generate_kv_service_call {
   ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
     pub async fn $fn_name(
       client_pool: &ClientPool,
       addrs: &[impl AsRef<str>],
-      request: &$req_ty,
-    ) -> Result<&$rep_ty, CommonError> {
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
       $crate::utils::retry_call(client_pool, addrs, request).await
     }
   };
 }
 
-generate_kv_service_call!(placement_set, SetRequest, SetReply, Set);
+generate_kv_service_call!(placement_set, SetRequest, CommonReply, Set);
+generate_kv_service_call!(placement_get, GetRequest, GetReply, Get);
+generate_kv_service_call!(placement_delete, DeleteRequest, CommonReply, Delete);
+generate_kv_service_call!(placement_exists, ExistsRequest, ExistsReply, Exists);
+generate_kv_service_call!(
+  placement_list_by_prefix,
+  ListByPrefixRequest,
+  ListByPrefixReply,
+  ListByPrefix
+);
+generate_kv_service_call!(placement_scan, ScanRequest, ScanReply, Scan);
+generate_kv_service_call!(
+  placement_lease_grant,
+  LeaseGrantRequest,
+  LeaseGrantReply,
+  LeaseGrant
+);
+generate_kv_service_call!(
+  placement_lease_revoke,
+  LeaseRevokeRequest,
+  CommonReply,
+  LeaseRevoke
+);
+generate_kv_service_call!(
+  placement_lease_time_to_live,
+  LeaseTimeToLiveRequest,
+  LeaseTimeToLiveReply,
+  LeaseTimeToLive
+);
+generate_kv_service_call!(placement_txn, TxnRequest, TxnReply, Txn);
+generate_kv_service_call!(placement_compact, CompactRequest, CommonReply, Compact);
@@ -1,8 +1,15 @@
 use common_base::error::CommonError;
 use mobc::Manager;
-use protocol::kv_service_client::KvServiceClient;
+use protocol::{
+  CommonReply, CompactRequest, DeleteRequest, ExistsReply, ExistsRequest, GetReply, GetRequest,
+  LeaseGrantReply, LeaseGrantRequest, LeaseRevokeRequest, LeaseTimeToLiveReply,
+  LeaseTimeToLiveRequest, ListByPrefixReply, ListByPrefixRequest, ScanReply, ScanRequest,
+  SetRequest, TxnReply, TxnRequest, kv_service_client::KvServiceClient,
+};
 use tonic::transport::Channel;
 
+use crate::macros::impl_retriable_request;
+
 pub mod call;
 
 #[derive(Debug, Clone)]
@@ -40,3 +47,101 @@ impl Manager for KvServiceManager {
     Ok(conn)
   }
 }
+
+impl_retriable_request!(
+  SetRequest,
+  KvServiceClient<Channel>,
+  CommonReply,
+  placement_center_kv_service_client,
+  set,
+  true
+);
+
+impl_retriable_request!(
+  GetRequest,
+  KvServiceClient<Channel>,
+  GetReply,
+  placement_center_kv_service_client,
+  get,
+  read_consistency
+);
+
+impl_retriable_request!(
+  DeleteRequest,
+  KvServiceClient<Channel>,
+  CommonReply,
+  placement_center_kv_service_client,
+  delete,
+  true
+);
+
+impl_retriable_request!(
+  ExistsRequest,
+  KvServiceClient<Channel>,
+  ExistsReply,
+  placement_center_kv_service_client,
+  exists,
+  read_consistency
+);
+
+impl_retriable_request!(
+  ListByPrefixRequest,
+  KvServiceClient<Channel>,
+  ListByPrefixReply,
+  placement_center_kv_service_client,
+  list_by_prefix
+);
+
+impl_retriable_request!(
+  ScanRequest,
+  KvServiceClient<Channel>,
+  ScanReply,
+  placement_center_kv_service_client,
+  scan
+);
+
+impl_retriable_request!(
+  LeaseGrantRequest,
+  KvServiceClient<Channel>,
+  LeaseGrantReply,
+  placement_center_kv_service_client,
+  lease_grant,
+  true
+);
+
+impl_retriable_request!(
+  LeaseRevokeRequest,
+  KvServiceClient<Channel>,
+  CommonReply,
+  placement_center_kv_service_client,
+  lease_revoke,
+  true
+);
+
+// 租约的剩余时间只在 Leader 上维护，直接发送到 Leader
+impl_retriable_request!(
+  LeaseTimeToLiveRequest,
+  KvServiceClient<Channel>,
+  LeaseTimeToLiveReply,
+  placement_center_kv_service_client,
+  lease_time_to_live,
+  true
+);
+
+impl_retriable_request!(
+  TxnRequest,
+  KvServiceClient<Channel>,
+  TxnReply,
+  placement_center_kv_service_client,
+  txn,
+  true
+);
+
+impl_retriable_request!(
+  CompactRequest,
+  KvServiceClient<Channel>,
+  CommonReply,
+  placement_center_kv_service_client,
+  compact,
+  true
+);
@@ -0,0 +1,36 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply,
+  kv_v2::{
+    DeleteRequest, ExistsReply, ExistsRequest, GetReply, GetRequest, ListByPrefixReply,
+    ListByPrefixRequest, ScanReply, ScanRequest, SetRequest, TxnReply, TxnRequest,
+  },
+};
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_kv_v2_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_kv_v2_service_call!(placement_set_v2, SetRequest, CommonReply, Set);
+generate_kv_v2_service_call!(placement_get_v2, GetRequest, GetReply, Get);
+generate_kv_v2_service_call!(placement_delete_v2, DeleteRequest, CommonReply, Delete);
+generate_kv_v2_service_call!(placement_exists_v2, ExistsRequest, ExistsReply, Exists);
+generate_kv_v2_service_call!(
+  placement_list_by_prefix_v2,
+  ListByPrefixRequest,
+  ListByPrefixReply,
+  ListByPrefix
+);
+generate_kv_v2_service_call!(placement_scan_v2, ScanRequest, ScanReply, Scan);
+generate_kv_v2_service_call!(placement_txn_v2, TxnRequest, TxnReply, Txn);
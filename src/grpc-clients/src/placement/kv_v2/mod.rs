@@ -0,0 +1,112 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::{
+  CommonReply,
+  kv_v2::{
+    DeleteRequest, ExistsReply, ExistsRequest, GetReply, GetRequest, ListByPrefixReply,
+    ListByPrefixRequest, ScanReply, ScanRequest, SetRequest, TxnReply, TxnRequest,
+    kv_service_v2_client::KvServiceV2Client,
+  },
+};
+use tonic::transport::Channel;
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct KvServiceV2Manager {
+  pub addr: String,
+}
+
+impl KvServiceV2Manager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for KvServiceV2Manager {
+  type Connection = KvServiceV2Client<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match KvServiceV2Client::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  SetRequest,
+  KvServiceV2Client<Channel>,
+  CommonReply,
+  placement_center_kv_v2_service_client,
+  set,
+  true
+);
+
+impl_retriable_request!(
+  GetRequest,
+  KvServiceV2Client<Channel>,
+  GetReply,
+  placement_center_kv_v2_service_client,
+  get,
+  read_consistency
+);
+
+impl_retriable_request!(
+  DeleteRequest,
+  KvServiceV2Client<Channel>,
+  CommonReply,
+  placement_center_kv_v2_service_client,
+  delete,
+  true
+);
+
+impl_retriable_request!(
+  ExistsRequest,
+  KvServiceV2Client<Channel>,
+  ExistsReply,
+  placement_center_kv_v2_service_client,
+  exists,
+  read_consistency
+);
+
+impl_retriable_request!(
+  ListByPrefixRequest,
+  KvServiceV2Client<Channel>,
+  ListByPrefixReply,
+  placement_center_kv_v2_service_client,
+  list_by_prefix
+);
+
+impl_retriable_request!(
+  ScanRequest,
+  KvServiceV2Client<Channel>,
+  ScanReply,
+  placement_center_kv_v2_service_client,
+  scan
+);
+
+impl_retriable_request!(
+  TxnRequest,
+  KvServiceV2Client<Channel>,
+  TxnReply,
+  placement_center_kv_v2_service_client,
+  txn,
+  true
+);
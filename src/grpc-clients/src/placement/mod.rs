@@ -1,6 +1,14 @@
+pub mod amqp;
+pub mod config;
+pub mod id;
 pub mod inner;
+pub mod journal;
 pub mod kv;
+pub mod kv_v2;
+pub mod mqtt;
+pub mod mqtt_acl;
 pub mod openraft;
+pub mod schema;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PlacementCenterInterface {
@@ -9,16 +17,94 @@ pub enum PlacementCenterInterface {
   Get,
   Delete,
   Exists,
+  ListByPrefix,
+  Scan,
+  LeaseGrant,
+  LeaseRevoke,
+  LeaseTimeToLive,
+  Txn,
+  Compact,
 
   // placement inner interface
   ClusterStatus,
+  ListCluster,
   ListNode,
   RegisterNode,
+  UnRegisterNode,
+  #[deprecated(note = "use PlacementCenterInterface::UnRegisterNode instead")]
   UnRegisterNOde,
   Heartbeat,
   SendRaftMessage,
   SendRaftConfChange,
 
+  // mqtt interface
+  CreateUser,
+  DeleteUser,
+  ListUser,
+  CreateTopic,
+  DeleteTopic,
+  ListTopic,
+  SetTopicRetainMessage,
+  SaveSession,
+  GetSession,
+  DeleteSession,
+  ListSession,
+
+  // mqtt acl interface
+  CreateAcl,
+  DeleteAcl,
+  ListAcl,
+  CreateBlacklist,
+  DeleteBlacklist,
+  ListBlacklist,
+
+  // journal interface
+  CreateShard,
+  ListShard,
+  ListSegment,
+  GetActiveSegment,
+  UpdateSegmentStatus,
+  SealSegment,
+  CommitOffset,
+  FetchOffset,
+
+  // amqp interface
+  CreateVhost,
+  DeleteVhost,
+  ListVhost,
+  CreateExchange,
+  DeleteExchange,
+  ListExchange,
+  CreateQueue,
+  DeleteQueue,
+  ListQueue,
+  CreateBinding,
+  DeleteBinding,
+  ListBinding,
+
+  // schema interface
+  RegisterSchema,
+  GetSchema,
+  ListSchema,
+  DeleteSchema,
+  SetCompatibility,
+  BindSchema,
+  UnbindSchema,
+  ListSchemaBinding,
+  GetBoundSchema,
+  ValidatePayload,
+
+  // config interface
+  SetClusterConfig,
+  GetClusterConfig,
+  DeleteClusterConfig,
+  ListClusterConfig,
+  WatchClusterConfig,
+
+  // id interface
+  AllocateIds,
+  GetNextId,
+
   // open raft
   Vote,
   Append,
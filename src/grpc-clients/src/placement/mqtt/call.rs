@@ -0,0 +1,92 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply,
+  mqtt::{
+    CreateTopicRequest, CreateUserRequest, DeleteSessionRequest, DeleteTopicRequest,
+    DeleteUserRequest, GetSessionReply, GetSessionRequest, ListSessionReply, ListSessionRequest,
+    ListTopicReply, ListTopicRequest, ListUserReply, ListUserRequest, SaveSessionRequest,
+    SetTopicRetainMessageRequest,
+  },
+};
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_mqtt_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_mqtt_service_call!(
+  placement_create_user,
+  CreateUserRequest,
+  CommonReply,
+  CreateUser
+);
+generate_mqtt_service_call!(
+  placement_delete_user,
+  DeleteUserRequest,
+  CommonReply,
+  DeleteUser
+);
+generate_mqtt_service_call!(
+  placement_list_user,
+  ListUserRequest,
+  ListUserReply,
+  ListUser
+);
+generate_mqtt_service_call!(
+  placement_create_topic,
+  CreateTopicRequest,
+  CommonReply,
+  CreateTopic
+);
+generate_mqtt_service_call!(
+  placement_delete_topic,
+  DeleteTopicRequest,
+  CommonReply,
+  DeleteTopic
+);
+generate_mqtt_service_call!(
+  placement_list_topic,
+  ListTopicRequest,
+  ListTopicReply,
+  ListTopic
+);
+generate_mqtt_service_call!(
+  placement_set_topic_retain_message,
+  SetTopicRetainMessageRequest,
+  CommonReply,
+  SetTopicRetainMessage
+);
+generate_mqtt_service_call!(
+  placement_save_session,
+  SaveSessionRequest,
+  CommonReply,
+  SaveSession
+);
+generate_mqtt_service_call!(
+  placement_get_session,
+  GetSessionRequest,
+  GetSessionReply,
+  GetSession
+);
+generate_mqtt_service_call!(
+  placement_delete_session,
+  DeleteSessionRequest,
+  CommonReply,
+  DeleteSession
+);
+generate_mqtt_service_call!(
+  placement_list_session,
+  ListSessionRequest,
+  ListSessionReply,
+  ListSession
+);
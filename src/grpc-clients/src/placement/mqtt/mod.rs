@@ -0,0 +1,147 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::{
+  CommonReply,
+  mqtt::{
+    CreateTopicRequest, CreateUserRequest, DeleteSessionRequest, DeleteTopicRequest,
+    DeleteUserRequest, GetSessionReply, GetSessionRequest, ListSessionReply, ListSessionRequest,
+    ListTopicReply, ListTopicRequest, ListUserReply, ListUserRequest, SaveSessionRequest,
+    SetTopicRetainMessageRequest, mqtt_service_client::MqttServiceClient,
+  },
+};
+use tonic::transport::Channel;
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct MqttServiceManager {
+  pub addr: String,
+}
+
+impl MqttServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for MqttServiceManager {
+  type Connection = MqttServiceClient<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match MqttServiceClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  CreateUserRequest,
+  MqttServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_service_client,
+  create_user,
+  true
+);
+
+impl_retriable_request!(
+  DeleteUserRequest,
+  MqttServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_service_client,
+  delete_user,
+  true
+);
+
+impl_retriable_request!(
+  ListUserRequest,
+  MqttServiceClient<Channel>,
+  ListUserReply,
+  placement_center_mqtt_service_client,
+  list_user
+);
+
+impl_retriable_request!(
+  CreateTopicRequest,
+  MqttServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_service_client,
+  create_topic,
+  true
+);
+
+impl_retriable_request!(
+  DeleteTopicRequest,
+  MqttServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_service_client,
+  delete_topic,
+  true
+);
+
+impl_retriable_request!(
+  ListTopicRequest,
+  MqttServiceClient<Channel>,
+  ListTopicReply,
+  placement_center_mqtt_service_client,
+  list_topic
+);
+
+impl_retriable_request!(
+  SetTopicRetainMessageRequest,
+  MqttServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_service_client,
+  set_topic_retain_message,
+  true
+);
+
+impl_retriable_request!(
+  SaveSessionRequest,
+  MqttServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_service_client,
+  save_session,
+  true
+);
+
+impl_retriable_request!(
+  GetSessionRequest,
+  MqttServiceClient<Channel>,
+  GetSessionReply,
+  placement_center_mqtt_service_client,
+  get_session
+);
+
+impl_retriable_request!(
+  DeleteSessionRequest,
+  MqttServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_service_client,
+  delete_session,
+  true
+);
+
+impl_retriable_request!(
+  ListSessionRequest,
+  MqttServiceClient<Channel>,
+  ListSessionReply,
+  placement_center_mqtt_service_client,
+  list_session
+);
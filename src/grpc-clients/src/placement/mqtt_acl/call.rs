@@ -0,0 +1,55 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply,
+  mqtt::{
+    CreateAclRequest, CreateBlacklistRequest, DeleteAclRequest, DeleteBlacklistRequest,
+    ListAclReply, ListAclRequest, ListBlacklistReply, ListBlacklistRequest,
+  },
+};
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_mqtt_acl_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_mqtt_acl_service_call!(
+  placement_create_acl,
+  CreateAclRequest,
+  CommonReply,
+  CreateAcl
+);
+generate_mqtt_acl_service_call!(
+  placement_delete_acl,
+  DeleteAclRequest,
+  CommonReply,
+  DeleteAcl
+);
+generate_mqtt_acl_service_call!(placement_list_acl, ListAclRequest, ListAclReply, ListAcl);
+generate_mqtt_acl_service_call!(
+  placement_create_blacklist,
+  CreateBlacklistRequest,
+  CommonReply,
+  CreateBlacklist
+);
+generate_mqtt_acl_service_call!(
+  placement_delete_blacklist,
+  DeleteBlacklistRequest,
+  CommonReply,
+  DeleteBlacklist
+);
+generate_mqtt_acl_service_call!(
+  placement_list_blacklist,
+  ListBlacklistRequest,
+  ListBlacklistReply,
+  ListBlacklist
+);
@@ -0,0 +1,103 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::{
+  CommonReply,
+  mqtt::{
+    CreateAclRequest, CreateBlacklistRequest, DeleteAclRequest, DeleteBlacklistRequest,
+    ListAclReply, ListAclRequest, ListBlacklistReply, ListBlacklistRequest,
+    mqtt_acl_service_client::MqttAclServiceClient,
+  },
+};
+use tonic::transport::Channel;
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct MqttAclServiceManager {
+  pub addr: String,
+}
+
+impl MqttAclServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for MqttAclServiceManager {
+  type Connection = MqttAclServiceClient<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match MqttAclServiceClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  CreateAclRequest,
+  MqttAclServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_acl_service_client,
+  create_acl,
+  true
+);
+
+impl_retriable_request!(
+  DeleteAclRequest,
+  MqttAclServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_acl_service_client,
+  delete_acl,
+  true
+);
+
+impl_retriable_request!(
+  ListAclRequest,
+  MqttAclServiceClient<Channel>,
+  ListAclReply,
+  placement_center_mqtt_acl_service_client,
+  list_acl
+);
+
+impl_retriable_request!(
+  CreateBlacklistRequest,
+  MqttAclServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_acl_service_client,
+  create_blacklist,
+  true
+);
+
+impl_retriable_request!(
+  DeleteBlacklistRequest,
+  MqttAclServiceClient<Channel>,
+  CommonReply,
+  placement_center_mqtt_acl_service_client,
+  delete_blacklist,
+  true
+);
+
+impl_retriable_request!(
+  ListBlacklistRequest,
+  MqttAclServiceClient<Channel>,
+  ListBlacklistReply,
+  placement_center_mqtt_acl_service_client,
+  list_blacklist
+);
@@ -0,0 +1,86 @@
+use common_base::error::CommonError;
+use protocol::{
+  CommonReply,
+  schema::{
+    BindSchemaRequest, DeleteSchemaRequest, GetBoundSchemaRequest, GetSchemaReply,
+    GetSchemaRequest, ListSchemaBindingReply, ListSchemaBindingRequest, ListSchemaReply,
+    ListSchemaRequest, RegisterSchemaReply, RegisterSchemaRequest, SetCompatibilityRequest,
+    UnbindSchemaRequest, ValidatePayloadReply, ValidatePayloadRequest,
+  },
+};
+
+use crate::pool::ClientPool;
+
+#[allow(unused_macros)]
+macro_rules! generate_schema_service_call {
+  ($fn_name:ident, $req_ty:ty, $rep_ty:ty, $variant:ident) => {
+    pub async fn $fn_name(
+      client_pool: &ClientPool,
+      addrs: &[impl AsRef<str>],
+      request: $req_ty,
+    ) -> Result<$rep_ty, CommonError> {
+      $crate::utils::retry_call(client_pool, addrs, request).await
+    }
+  };
+}
+
+generate_schema_service_call!(
+  placement_register_schema,
+  RegisterSchemaRequest,
+  RegisterSchemaReply,
+  RegisterSchema
+);
+generate_schema_service_call!(
+  placement_get_schema,
+  GetSchemaRequest,
+  GetSchemaReply,
+  GetSchema
+);
+generate_schema_service_call!(
+  placement_list_schema,
+  ListSchemaRequest,
+  ListSchemaReply,
+  ListSchema
+);
+generate_schema_service_call!(
+  placement_delete_schema,
+  DeleteSchemaRequest,
+  CommonReply,
+  DeleteSchema
+);
+generate_schema_service_call!(
+  placement_set_compatibility,
+  SetCompatibilityRequest,
+  CommonReply,
+  SetCompatibility
+);
+generate_schema_service_call!(
+  placement_bind_schema,
+  BindSchemaRequest,
+  CommonReply,
+  BindSchema
+);
+generate_schema_service_call!(
+  placement_unbind_schema,
+  UnbindSchemaRequest,
+  CommonReply,
+  UnbindSchema
+);
+generate_schema_service_call!(
+  placement_list_schema_binding,
+  ListSchemaBindingRequest,
+  ListSchemaBindingReply,
+  ListSchemaBinding
+);
+generate_schema_service_call!(
+  placement_get_bound_schema,
+  GetBoundSchemaRequest,
+  GetSchemaReply,
+  GetBoundSchema
+);
+generate_schema_service_call!(
+  placement_validate_payload,
+  ValidatePayloadRequest,
+  ValidatePayloadReply,
+  ValidatePayload
+);
@@ -0,0 +1,138 @@
+use common_base::error::CommonError;
+use mobc::Manager;
+use protocol::{
+  CommonReply,
+  schema::{
+    BindSchemaRequest, DeleteSchemaRequest, GetBoundSchemaRequest, GetSchemaReply,
+    GetSchemaRequest, ListSchemaBindingReply, ListSchemaBindingRequest, ListSchemaReply,
+    ListSchemaRequest, RegisterSchemaReply, RegisterSchemaRequest, SetCompatibilityRequest,
+    UnbindSchemaRequest, ValidatePayloadReply, ValidatePayloadRequest,
+    schema_service_client::SchemaServiceClient,
+  },
+};
+use tonic::transport::Channel;
+
+use crate::macros::impl_retriable_request;
+
+pub mod call;
+
+#[derive(Debug, Clone)]
+pub struct SchemaServiceManager {
+  pub addr: String,
+}
+
+impl SchemaServiceManager {
+  pub fn new(addr: String) -> Self {
+    Self { addr }
+  }
+}
+
+#[tonic::async_trait]
+impl Manager for SchemaServiceManager {
+  type Connection = SchemaServiceClient<Channel>;
+  type Error = CommonError;
+
+  async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+    match SchemaServiceClient::connect(format!("http://{}", self.addr.clone())).await {
+      Ok(client) => {
+        return Ok(client);
+      }
+      Err(err) => {
+        return Err(CommonError::CommonError(format!(
+          "manager connect error:{},{}",
+          err,
+          self.addr.clone()
+        )));
+      }
+    }
+  }
+
+  async fn check(&self, conn: Self::Connection) -> Result<Self::Connection, Self::Error> {
+    Ok(conn)
+  }
+}
+
+impl_retriable_request!(
+  RegisterSchemaRequest,
+  SchemaServiceClient<Channel>,
+  RegisterSchemaReply,
+  placement_center_schema_service_client,
+  register_schema,
+  true
+);
+
+impl_retriable_request!(
+  GetSchemaRequest,
+  SchemaServiceClient<Channel>,
+  GetSchemaReply,
+  placement_center_schema_service_client,
+  get_schema
+);
+
+impl_retriable_request!(
+  ListSchemaRequest,
+  SchemaServiceClient<Channel>,
+  ListSchemaReply,
+  placement_center_schema_service_client,
+  list_schema
+);
+
+impl_retriable_request!(
+  DeleteSchemaRequest,
+  SchemaServiceClient<Channel>,
+  CommonReply,
+  placement_center_schema_service_client,
+  delete_schema,
+  true
+);
+
+impl_retriable_request!(
+  SetCompatibilityRequest,
+  SchemaServiceClient<Channel>,
+  CommonReply,
+  placement_center_schema_service_client,
+  set_compatibility,
+  true
+);
+
+impl_retriable_request!(
+  BindSchemaRequest,
+  SchemaServiceClient<Channel>,
+  CommonReply,
+  placement_center_schema_service_client,
+  bind_schema,
+  true
+);
+
+impl_retriable_request!(
+  UnbindSchemaRequest,
+  SchemaServiceClient<Channel>,
+  CommonReply,
+  placement_center_schema_service_client,
+  unbind_schema,
+  true
+);
+
+impl_retriable_request!(
+  ListSchemaBindingRequest,
+  SchemaServiceClient<Channel>,
+  ListSchemaBindingReply,
+  placement_center_schema_service_client,
+  list_schema_binding
+);
+
+impl_retriable_request!(
+  GetBoundSchemaRequest,
+  SchemaServiceClient<Channel>,
+  GetSchemaReply,
+  placement_center_schema_service_client,
+  get_bound_schema
+);
+
+impl_retriable_request!(
+  ValidatePayloadRequest,
+  SchemaServiceClient<Channel>,
+  ValidatePayloadReply,
+  placement_center_schema_service_client,
+  validate_payload
+);
@@ -2,7 +2,12 @@ use common_base::error::CommonError;
 use dashmap::{DashMap, mapref::one::Ref};
 use mobc::{Connection, Pool};
 
-use crate::placement::{inner::PlacementServiceManager, openraft::OpenRaftServiceManager};
+use crate::placement::{
+  amqp::AmqpServiceManager, config::ClusterConfigServiceManager, id::IdServiceManager,
+  inner::PlacementServiceManager, journal::JournalServiceManager, kv::KvServiceManager,
+  kv_v2::KvServiceV2Manager, mqtt::MqttServiceManager, mqtt_acl::MqttAclServiceManager,
+  openraft::OpenRaftServiceManager, schema::SchemaServiceManager,
+};
 
 #[derive(Clone, Debug)]
 pub struct ClientPool {
@@ -10,8 +15,15 @@ pub struct ClientPool {
   placement_center_leader_addr_caches: DashMap<String, String>,
   placement_center_inner_pools: DashMap<String, Pool<PlacementServiceManager>>,
   placement_center_openraft_service_pools: DashMap<String, Pool<OpenRaftServiceManager>>,
-  // placement_center_journal_service_pools:DashMap<String,Pool<JournalServiceManager>>,
+  placement_center_journal_service_pools: DashMap<String, Pool<JournalServiceManager>>,
   placement_center_kv_service_pools: DashMap<String, Pool<KvServiceManager>>,
+  placement_center_kv_v2_service_pools: DashMap<String, Pool<KvServiceV2Manager>>,
+  placement_center_mqtt_service_pools: DashMap<String, Pool<MqttServiceManager>>,
+  placement_center_mqtt_acl_service_pools: DashMap<String, Pool<MqttAclServiceManager>>,
+  placement_center_amqp_service_pools: DashMap<String, Pool<AmqpServiceManager>>,
+  placement_center_schema_service_pools: DashMap<String, Pool<SchemaServiceManager>>,
+  placement_center_config_service_pools: DashMap<String, Pool<ClusterConfigServiceManager>>,
+  placement_center_id_service_pools: DashMap<String, Pool<IdServiceManager>>,
 }
 
 impl ClientPool {
@@ -21,7 +33,15 @@ impl ClientPool {
       placement_center_leader_addr_caches: DashMap::with_capacity(2),
       placement_center_inner_pools: DashMap::with_capacity(2),
       placement_center_openraft_service_pools: DashMap::with_capacity(2),
+      placement_center_journal_service_pools: DashMap::with_capacity(2),
       placement_center_kv_service_pools: DashMap::with_capacity(2),
+      placement_center_kv_v2_service_pools: DashMap::with_capacity(2),
+      placement_center_mqtt_service_pools: DashMap::with_capacity(2),
+      placement_center_mqtt_acl_service_pools: DashMap::with_capacity(2),
+      placement_center_amqp_service_pools: DashMap::with_capacity(2),
+      placement_center_schema_service_pools: DashMap::with_capacity(2),
+      placement_center_config_service_pools: DashMap::with_capacity(2),
+      placement_center_id_service_pools: DashMap::with_capacity(2),
     }
   }
 
@@ -93,6 +113,306 @@ impl ClientPool {
     ))
   }
 
+  pub async fn placement_center_kv_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<KvServiceManager>, CommonError> {
+    if !self.placement_center_kv_service_pools.contains_key(addr) {
+      let manager = KvServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_kv_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_kv_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "KvService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "KvService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_kv_v2_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<KvServiceV2Manager>, CommonError> {
+    if !self.placement_center_kv_v2_service_pools.contains_key(addr) {
+      let manager = KvServiceV2Manager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_kv_v2_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_kv_v2_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "KvServiceV2".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "KvServiceV2".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_mqtt_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<MqttServiceManager>, CommonError> {
+    if !self.placement_center_mqtt_service_pools.contains_key(addr) {
+      let manager = MqttServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_mqtt_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_mqtt_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "MqttService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "MqttService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_mqtt_acl_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<MqttAclServiceManager>, CommonError> {
+    if !self
+      .placement_center_mqtt_acl_service_pools
+      .contains_key(addr)
+    {
+      let manager = MqttAclServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_mqtt_acl_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_mqtt_acl_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "MqttAclService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "MqttAclService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_amqp_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<AmqpServiceManager>, CommonError> {
+    if !self.placement_center_amqp_service_pools.contains_key(addr) {
+      let manager = AmqpServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_amqp_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_amqp_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "AmqpService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "AmqpService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_schema_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<SchemaServiceManager>, CommonError> {
+    if !self
+      .placement_center_schema_service_pools
+      .contains_key(addr)
+    {
+      let manager = SchemaServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_schema_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_schema_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "SchemaService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "SchemaService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_config_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<ClusterConfigServiceManager>, CommonError> {
+    if !self
+      .placement_center_config_service_pools
+      .contains_key(addr)
+    {
+      let manager = ClusterConfigServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_config_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_config_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "ClusterConfigService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "ClusterConfigService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_id_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<IdServiceManager>, CommonError> {
+    if !self.placement_center_id_service_pools.contains_key(addr) {
+      let manager = IdServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_id_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_id_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "IdService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "IdService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
+  pub async fn placement_center_journal_service_client(
+    &self,
+    addr: &str,
+  ) -> Result<Connection<JournalServiceManager>, CommonError> {
+    if !self
+      .placement_center_journal_service_pools
+      .contains_key(addr)
+    {
+      let manager = JournalServiceManager::new(addr.to_owned());
+      let pool = Pool::builder()
+        .max_open(self.max_open_connection)
+        .build(manager);
+      self
+        .placement_center_journal_service_pools
+        .insert(addr.to_owned(), pool);
+    }
+
+    if let Some(pool) = self.placement_center_journal_service_pools.get(addr) {
+      match pool.get().await {
+        Ok(conn) => return Ok(conn),
+        Err(e) => {
+          return Err(CommonError::NoAvailableGrpcConnection(
+            "JournalService".to_string(),
+            e.to_string(),
+          ));
+        }
+      }
+    }
+
+    Err(CommonError::NoAvailableGrpcConnection(
+      "JournalService".to_string(),
+      "conncetion pool is not initialized".to_string(),
+    ))
+  }
+
   pub fn get_leader_addr(&self, addr: &str) -> Option<Ref<'_, String, String>> {
     self.placement_center_leader_addr_caches.get(addr)
   }
@@ -28,6 +28,14 @@ pub(crate) trait RetriableRequest: Clone {
   /// 表示该请求是否为写请求，默认为false
   const IS_WRITE_REQUEST: bool = false;
 
+  /// 请求是否需要直接发送到 Leader
+  ///
+  /// 写请求只能由 Leader 处理；读请求按一致性级别决定，
+  /// 线性一致读和租约读只能由 Leader 处理，允许读旧数据的请求可以由任意节点处理
+  fn is_leader_request(&self) -> bool {
+    Self::IS_WRITE_REQUEST
+  }
+
   /// 从客户端池中获取一个客户端
   ///
   /// # 参数
@@ -94,8 +102,8 @@ where
     let index = times % addrs.len();
     // 获取当前尝试的地址
     let addr = addrs[index].as_ref();
-    // 如果是写请求，获取领导者地址，否则使用当前地址
-    let target_addr = if Req::IS_WRITE_REQUEST {
+    // 如果请求需要由领导者处理，获取领导者地址，否则使用当前地址
+    let target_addr = if request.is_leader_request() {
       client_pool
         .get_leader_addr(addr)
         .map(|leader| leader.value().to_string())
@@ -174,7 +182,25 @@ where
             times += 1;
             // 休眠一段时间
             sleep(Duration::from_secs(retry_sleep_time(times))).await;
+          } else {
+            // 无法从错误信息中解析出转发地址，超过最大重试次数后返回原始错误
+            if times > retry_times() {
+              return Err(err);
+            }
+            // 增加重试次数
+            times += 1;
+            // 休眠一段时间
+            sleep(Duration::from_secs(retry_sleep_time(times))).await;
+          }
+        } else {
+          // 非转发类错误，超过最大重试次数后直接返回错误
+          if times > retry_times() {
+            return Err(err);
           }
+          // 增加重试次数
+          times += 1;
+          // 休眠一段时间
+          sleep(Duration::from_secs(retry_sleep_time(times))).await;
         }
       }
     }
@@ -213,3 +239,107 @@ pub fn get_forward_addr(err: &CommonError) -> Option<String> {
   // 未匹配到，返回None
   None
 }
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    ops::DerefMut,
+    sync::{
+      Arc,
+      atomic::{AtomicUsize, Ordering},
+    },
+  };
+
+  use common_base::error::CommonError;
+
+  use super::{RetriableRequest, retry_call};
+  use crate::{pool::ClientPool, retry_times};
+
+  // 每次调用都返回不带 Leader 地址的转发错误，记录实际调用的次数
+  #[derive(Clone)]
+  struct ForwardWithoutAddrRequest {
+    calls: Arc<AtomicUsize>,
+  }
+
+  impl RetriableRequest for ForwardWithoutAddrRequest {
+    type Client = ();
+    type Response = ();
+    type Error = CommonError;
+
+    async fn get_client<'a>(
+      _pool: &'a ClientPool,
+      _addr: &str,
+    ) -> Result<impl DerefMut<Target = Self::Client> + 'a, Self::Error> {
+      Ok(Box::new(()))
+    }
+
+    async fn call_once(
+      _client: &mut Self::Client,
+      request: Self,
+    ) -> Result<Self::Response, Self::Error> {
+      request.calls.fetch_add(1, Ordering::SeqCst);
+      Err(CommonError::CommonError(
+        "has to forward request to: None".to_string(),
+      ))
+    }
+  }
+
+  // 每次调用都返回非转发类错误的请求，记录实际调用的次数
+  #[derive(Clone)]
+  struct FailingRequest {
+    calls: Arc<AtomicUsize>,
+  }
+
+  impl RetriableRequest for FailingRequest {
+    type Client = ();
+    type Response = ();
+    type Error = CommonError;
+
+    async fn get_client<'a>(
+      _pool: &'a ClientPool,
+      _addr: &str,
+    ) -> Result<impl DerefMut<Target = Self::Client> + 'a, Self::Error> {
+      Ok(Box::new(()))
+    }
+
+    async fn call_once(
+      _client: &mut Self::Client,
+      request: Self,
+    ) -> Result<Self::Response, Self::Error> {
+      request.calls.fetch_add(1, Ordering::SeqCst);
+      Err(CommonError::CommonError("connection refused".to_string()))
+    }
+  }
+
+  #[tokio::test]
+  async fn retry_call_forward_without_addr_test() {
+    let client_pool = ClientPool::new(1);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let request = ForwardWithoutAddrRequest {
+      calls: calls.clone(),
+    };
+
+    // 解析不出转发地址时在超过最大重试次数后返回原始错误，不会一直重试
+    let err = retry_call(&client_pool, &["127.0.0.1:1228"], request)
+      .await
+      .unwrap_err();
+    assert!(err.to_string().contains("forward request to: None"));
+    assert_eq!(calls.load(Ordering::SeqCst), retry_times() + 1);
+  }
+
+  #[tokio::test]
+  async fn retry_call_non_forward_error_test() {
+    let client_pool = ClientPool::new(1);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let request = FailingRequest {
+      calls: calls.clone(),
+    };
+
+    // 非转发类错误在超过最大重试次数后返回原始错误，不会一直重试
+    let err = retry_call(&client_pool, &["127.0.0.1:1228"], request)
+      .await
+      .unwrap_err();
+    assert!(err.to_string().contains("connection refused"));
+    assert_eq!(calls.load(Ordering::SeqCst), retry_times() + 1);
+  }
+}
@@ -0,0 +1,79 @@
+use std::{sync::Arc, time::Duration};
+
+use common_base::tools::now_second;
+use log::{error, info};
+use prost::Message;
+use protocol::mqtt::{BlacklistType, DeleteBlacklistRequest, PurgeBlacklistData};
+use tokio::{select, sync::broadcast, time::sleep};
+
+use crate::{
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+  },
+  storage::{mqtt::blacklist::MqttBlacklistStorage, rocksdb::RocksDBEngine},
+};
+
+// 检查失效黑名单的间隔
+const BLACKLIST_EXPIRE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+// 每条 Raft 日志最多清理的黑名单条数
+const BLACKLIST_PURGE_BATCH_SIZE: usize = 100;
+
+/// 由 Leader 周期性检查已经失效的黑名单，并通过 Raft 日志提交 MqttPurgeBlacklist 将其删除。
+/// 检查时的时间戳随日志一起提交，所有副本按同一个时间判断黑名单是否失效
+pub async fn start_blacklist_expire_check_thread(
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  stop_sx: broadcast::Sender<bool>,
+) {
+  let mut stop_rx = stop_sx.subscribe();
+  loop {
+    select! {
+      val = stop_rx.recv() => {
+        if let Ok(true) = val {
+          info!("Blacklist expire check thread stopped successfully");
+          return;
+        }
+      }
+      _ = sleep(BLACKLIST_EXPIRE_CHECK_INTERVAL) => {
+        if !raft_machine_apply.is_leader() {
+          continue;
+        }
+
+        let now = now_second();
+        let blacklist_storage = MqttBlacklistStorage::new(rocksdb_engine_handler.clone());
+        let expired = match blacklist_storage.list_expired(now) {
+          Ok(expired) => expired,
+          Err(e) => {
+            error!("Failed to list expired blacklists, {}", e);
+            continue;
+          }
+        };
+
+        for chunk in expired.chunks(BLACKLIST_PURGE_BATCH_SIZE) {
+          let data = PurgeBlacklistData {
+            blacklists: chunk
+              .iter()
+              .map(|blacklist| DeleteBlacklistRequest {
+                cluster_name: blacklist.cluster_name.clone(),
+                blacklist_type: BlacklistType::from_str_name(&blacklist.blacklist_type)
+                  .unwrap_or_default()
+                  .into(),
+                resource_name: blacklist.resource_name.clone(),
+              })
+              .collect(),
+            expire_time: now,
+          };
+          let data = StorageData::new(
+            StorageDataType::MqttPurgeBlacklist,
+            PurgeBlacklistData::encode_to_vec(&data),
+          );
+          match raft_machine_apply.client_write(data).await {
+            Ok(_) => info!("Purged {} expired blacklists", chunk.len()),
+            Err(e) => error!("Failed to purge expired blacklists, {}", e),
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,79 @@
+use tokio::sync::broadcast;
+
+use crate::storage::config::ClusterConfig;
+
+// 广播通道容量，订阅者消费过慢超出该容量时会被断开
+const DEFAULT_CONFIG_CHANNEL_CAPACITY: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClusterConfigEventType {
+  Set,
+  Delete,
+}
+
+/// 一次动态配置变更，删除事件中的 `config` 只有集群名称、资源类型和删除时的版本号
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterConfigEvent {
+  pub event_type: ClusterConfigEventType,
+  pub config: ClusterConfig,
+}
+
+/// 动态配置变更的广播中心：状态机应用配置的修改时发布事件，节点的订阅请求接收事件并推送给节点。
+/// 不保留历史变更，订阅时先读取当前的全部配置，节点按版本号丢弃重复或过期的事件
+#[derive(Debug)]
+pub struct ClusterConfigWatchHub {
+  sender: broadcast::Sender<ClusterConfigEvent>,
+}
+
+impl Default for ClusterConfigWatchHub {
+  fn default() -> Self {
+    Self::new(DEFAULT_CONFIG_CHANNEL_CAPACITY)
+  }
+}
+
+impl ClusterConfigWatchHub {
+  pub fn new(channel_capacity: usize) -> Self {
+    let (sender, _) = broadcast::channel(channel_capacity);
+    ClusterConfigWatchHub { sender }
+  }
+
+  pub fn publish(&self, event: ClusterConfigEvent) {
+    // 没有订阅者时发送会返回错误，忽略即可
+    let _ = self.sender.send(event);
+  }
+
+  // 需要在读取当前配置之前订阅，避免读取和订阅之间的变更丢失
+  pub fn subscribe(&self) -> broadcast::Receiver<ClusterConfigEvent> {
+    return self.sender.subscribe();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{ClusterConfigEvent, ClusterConfigEventType, ClusterConfigWatchHub};
+  use crate::storage::config::ClusterConfig;
+
+  #[tokio::test]
+  async fn cluster_config_watch_hub_test() {
+    let hub = ClusterConfigWatchHub::new(16);
+    // 订阅之前发布的事件不会被收到
+    hub.publish(ClusterConfigEvent {
+      event_type: ClusterConfigEventType::Set,
+      config: ClusterConfig {
+        version: 1,
+        ..Default::default()
+      },
+    });
+
+    let mut receiver = hub.subscribe();
+    let event = ClusterConfigEvent {
+      event_type: ClusterConfigEventType::Delete,
+      config: ClusterConfig {
+        version: 2,
+        ..Default::default()
+      },
+    };
+    hub.publish(event.clone());
+    assert_eq!(receiver.recv().await.unwrap(), event);
+  }
+}
@@ -1,4 +1,141 @@
+use common_base::error::CommonError;
 use thiserror::Error;
+use tonic::Status;
 
 #[derive(Debug, Error)]
-pub enum PlacementCenterError {}
+pub enum PlacementCenterError {
+  #[error("{0}")]
+  CommonError(#[from] CommonError),
+  #[error("{0}")]
+  FromDecodeError(#[from] prost::DecodeError),
+  #[error("{0}")]
+  FromSerdeJsonError(#[from] serde_json::Error),
+  #[error("{0}")]
+  OpenRaftError(String),
+  #[error("Parameter cannot be empty, parameter name: {0}")]
+  RequestParamsNotEmpty(String),
+  #[error("Raft log commit timed out after {0} seconds")]
+  RaftLogCommitTimeout(u64),
+  #[error("Raft read index confirmation timed out after {0} seconds")]
+  RaftReadIndexTimeout(u64),
+  #[error("Watch start index {0} has been compacted, compacted index is {1}")]
+  WatchIndexCompacted(u64, u64),
+  #[error("Revision {0} has been compacted, compacted revision is {1}")]
+  KvRevisionCompacted(u64, u64),
+  #[error("Revision {0} is a future revision, current revision is {1}")]
+  KvFutureRevision(u64, u64),
+  #[error("Snapshot id {0} is invalid")]
+  SnapshotIdInvalid(String),
+  #[error("Snapshot {0} expects chunk at offset {1}, but received offset {2}")]
+  SnapshotOffsetMismatch(String, u64, u64),
+  #[error("Snapshot {0} chunk at offset {1} failed checksum verification")]
+  SnapshotChecksumMismatch(String, u64),
+  #[error("Snapshot {0} expects {1} bytes, but received {2} bytes")]
+  SnapshotSizeMismatch(String, u64, u64),
+  #[error("Key {0} does not exist")]
+  KvKeyNotFound(String),
+  #[error("Lease {0} does not exist")]
+  LeaseNotFound(i64),
+  #[error("Lease {0} already exists")]
+  LeaseAlreadyExists(i64),
+  #[error("Lease requests must be sent to the leader, current leader is {0:?}")]
+  LeaseNotLeader(Option<u64>),
+  #[error("Cluster {0} does not exist")]
+  ClusterNotFound(String),
+  #[error("Cluster {0} is of type {1}, cannot be registered as {2}")]
+  ClusterTypeMismatch(String, String, String),
+  #[error("Node {1} does not exist in cluster {0}")]
+  NodeNotFound(String, u64),
+  #[error("Node {1} in cluster {0} has registered again since its heartbeat timed out")]
+  NodeReRegistered(String, u64),
+  #[error("User {1} already exists in cluster {0}")]
+  MqttUserAlreadyExists(String, String),
+  #[error("User {1} does not exist in cluster {0}")]
+  MqttUserNotFound(String, String),
+  #[error("Topic {1} already exists in cluster {0}")]
+  MqttTopicAlreadyExists(String, String),
+  #[error("Topic {1} does not exist in cluster {0}")]
+  MqttTopicNotFound(String, String),
+  #[error("QoS {0} is invalid, QoS must be 0, 1 or 2")]
+  MqttQosInvalid(u32),
+  #[error("Session of client {1} does not exist in cluster {0}")]
+  MqttSessionNotFound(String, String),
+  #[error("Retain handling {0} is invalid, retain handling must be 0, 1 or 2")]
+  MqttRetainHandlingInvalid(u32),
+  #[error("Topic filter {0} is invalid")]
+  MqttTopicFilterInvalid(String),
+  #[error("ACL rule {1} {2} on topic filter {3} does not exist in cluster {0}")]
+  MqttAclNotFound(String, String, String, String),
+  #[error("{2} {1} is not in the blacklist of cluster {0}")]
+  MqttBlacklistNotFound(String, String, String),
+  #[error("Cluster name {0} is invalid, it cannot be empty or contain /")]
+  MqttClusterNameInvalid(String),
+  #[error("Cluster {0} is of type {1}, expected {2}")]
+  ClusterTypeUnexpected(String, String, String),
+  #[error("Name {0} is invalid, it cannot be empty or contain /")]
+  JournalNameInvalid(String),
+  #[error("Cluster {0} needs {1} nodes for segment replicas, but only {2} are available")]
+  JournalNotEnoughNodes(String, u32, usize),
+  #[error("Shard {1} already exists in namespace {0}")]
+  JournalShardAlreadyExists(String, String),
+  #[error("Shard {1} does not exist in namespace {0}")]
+  JournalShardNotFound(String, String),
+  #[error("Segment {2} of shard {1} does not exist in namespace {0}")]
+  JournalSegmentNotFound(String, String, u32),
+  #[error("Segment {1} is not the active segment of shard {0}, active segment is {2}")]
+  JournalSegmentNotActive(String, u32, u32),
+  #[error("Segment {0} cannot change status from {1} to {2}")]
+  JournalSegmentStatusInvalid(u32, String, String),
+  #[error("End offset {1} of segment {0} is less than its start offset {2} minus one")]
+  JournalSegmentEndOffsetInvalid(u32, i64, i64),
+  #[error("Segment {0} with end offset {1} cannot be followed by another segment")]
+  JournalSegmentOverflow(u32, i64),
+  #[error("Vhost {1} already exists in cluster {0}")]
+  AmqpVhostAlreadyExists(String, String),
+  #[error("Vhost {1} does not exist in cluster {0}")]
+  AmqpVhostNotFound(String, String),
+  #[error("Exchange {1} does not exist in vhost {0}")]
+  AmqpExchangeNotFound(String, String),
+  #[error("Exchange {1} already exists in vhost {0} with different properties")]
+  AmqpExchangeMismatch(String, String),
+  #[error("Queue {1} does not exist in vhost {0}")]
+  AmqpQueueNotFound(String, String),
+  #[error("Queue {1} already exists in vhost {0} with different properties")]
+  AmqpQueueMismatch(String, String),
+  #[error("Binding {1} -> {2} with routing key {3} does not exist in vhost {0}")]
+  AmqpBindingNotFound(String, String, String, String),
+  #[error("{0} {1} is invalid, it cannot be empty or contain /")]
+  AmqpNameInvalid(String, String),
+  #[error("Schema definition is invalid: {0}")]
+  SchemaInvalid(String),
+  #[error("Schema is incompatible with the latest version: {0}")]
+  SchemaIncompatible(String),
+  #[error("Subject {0} is of type {1}, cannot register a schema of type {2}")]
+  SchemaTypeMismatch(String, String, String),
+  #[error("Subject {0} does not exist")]
+  SchemaSubjectNotFound(String),
+  #[error("Version {1} of subject {0} does not exist")]
+  SchemaVersionNotFound(String, u32),
+  #[error("Subject {0} is invalid, it cannot be empty or contain /")]
+  SchemaSubjectInvalid(String),
+  #[error("{0} {1} is not bound to any schema")]
+  SchemaBindingNotFound(String, String),
+  #[error("{0} {1} does not exist")]
+  SchemaResourceNotFound(String, String),
+  #[error("Config {0} is at version {2}, expected version {1}")]
+  ClusterConfigVersionMismatch(String, u64, u64),
+  #[error("Config {1} does not exist in cluster {0}")]
+  ClusterConfigNotFound(String, String),
+  #[error("Config {0} is invalid: {1}")]
+  ClusterConfigInvalid(String, String),
+  #[error("Namespace {0} is invalid, it cannot be empty or contain /")]
+  IdNamespaceInvalid(String),
+  #[error("Namespace {0} cannot allocate {1} more ids, next id is {2}")]
+  IdExhausted(String, u64, u64),
+}
+
+impl From<PlacementCenterError> for Status {
+  fn from(value: PlacementCenterError) -> Self {
+    Status::cancelled(value.to_string())
+  }
+}
@@ -0,0 +1,182 @@
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+  time::{Duration, Instant},
+};
+
+use common_base::{config::placement_center::placement_center_conf, error::CommonError};
+use dashmap::DashMap;
+use log::{error, info};
+use prost::Message;
+use protocol::RemoveExpiredNodeData;
+use tokio::{select, sync::broadcast, time::sleep};
+
+use crate::{
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+  },
+  storage::{cluster::ClusterStorage, rocksdb::RocksDBEngine},
+};
+
+/// 节点最近一次心跳的时间只保存在 Leader 的内存中，心跳只发送到 Leader。
+/// 没有心跳记录的节点（刚注册或者刚切主）从第一次检查开始计时，避免切主后节点被立即移除。
+#[derive(Debug, Default)]
+pub struct NodeHeartbeatCache {
+  // (集群名称, 节点 ID) -> 最近一次心跳的时间
+  last_seen: DashMap<(String, u64), Instant>,
+}
+
+impl NodeHeartbeatCache {
+  pub fn report(&self, cluster_name: &str, node_id: u64) {
+    self
+      .last_seen
+      .insert((cluster_name.to_string(), node_id), Instant::now());
+  }
+
+  pub fn remove(&self, cluster_name: &str, node_id: u64) {
+    self.last_seen.remove(&(cluster_name.to_string(), node_id));
+  }
+
+  pub fn clear(&self) {
+    self.last_seen.clear();
+  }
+
+  // 返回 nodes 中超过 timeout 没有心跳的节点，同时清理已经不在 nodes 中的心跳记录
+  pub fn expired(&self, nodes: Vec<(String, u64)>, timeout: Duration) -> Vec<(String, u64)> {
+    let registered: HashSet<(String, u64)> = nodes.iter().cloned().collect();
+    self.last_seen.retain(|node, _| registered.contains(node));
+
+    let now = Instant::now();
+    nodes
+      .into_iter()
+      .filter(|node| {
+        let last_seen = *self.last_seen.entry(node.clone()).or_insert(now);
+        now.duration_since(last_seen) > timeout
+      })
+      .collect()
+  }
+}
+
+/// 由 Leader 周期性检查节点心跳，并通过 Raft 日志提交 ClusterRemoveExpiredNode，将超时的节点移出集群。
+/// 日志中带有检查时节点注册信息的日志索引，节点在检查之后重新注册时不会被移除
+pub async fn start_node_heartbeat_check_thread(
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  heartbeat_cache: Arc<NodeHeartbeatCache>,
+  stop_sx: broadcast::Sender<bool>,
+) {
+  let config = placement_center_conf();
+  let check_interval = Duration::from_millis(config.heartbeat.heartbeat_check_time_ms);
+  let timeout = Duration::from_millis(config.heartbeat.hearbeat_timeout_ms);
+
+  let mut stop_rx = stop_sx.subscribe();
+  let mut is_leader = false;
+  loop {
+    select! {
+      val = stop_rx.recv() => {
+        if let Ok(true) = val {
+          info!("Node heartbeat check thread stopped successfully");
+          return;
+        }
+      }
+      _ = sleep(check_interval) => {
+        let leader = raft_machine_apply.is_leader();
+        // 成为 Leader 之前的心跳记录已经过时，重新开始计时
+        if leader && !is_leader {
+          heartbeat_cache.clear();
+        }
+        is_leader = leader;
+        if !is_leader {
+          continue;
+        }
+
+        let register_indexes = match registered_nodes(&rocksdb_engine_handler) {
+          Ok(nodes) => nodes,
+          Err(e) => {
+            error!("Failed to list registered nodes, {}", e);
+            continue;
+          }
+        };
+        let nodes = register_indexes.keys().cloned().collect();
+
+        for (cluster_name, node_id) in heartbeat_cache.expired(nodes, timeout) {
+          let req = RemoveExpiredNodeData {
+            register_index: register_indexes[&(cluster_name.clone(), node_id)],
+            cluster_name: cluster_name.clone(),
+            node_id,
+          };
+          let data = StorageData::new(
+            StorageDataType::ClusterRemoveExpiredNode,
+            RemoveExpiredNodeData::encode_to_vec(&req),
+          );
+          match raft_machine_apply.client_write(data).await {
+            Ok(reply) => match reply.data {
+              AppResponseData::NotFound => {
+                heartbeat_cache.remove(&cluster_name, node_id);
+                info!(
+                  "Node {} in cluster {} heartbeat timed out but has already been removed",
+                  node_id, cluster_name
+                );
+              }
+              // 节点重新注册，保留心跳记录，下次检查时按新的注册信息判断
+              AppResponseData::Conflict(message) => info!("{}", message),
+              _ => {
+                heartbeat_cache.remove(&cluster_name, node_id);
+                info!(
+                  "Node {} in cluster {} heartbeat timed out and was removed",
+                  node_id, cluster_name
+                );
+              }
+            },
+            Err(e) => error!(
+              "Failed to remove node {} in cluster {} whose heartbeat timed out, {}",
+              node_id, cluster_name, e
+            ),
+          }
+        }
+      }
+    }
+  }
+}
+
+// 返回所有已注册的节点及其最近一次注册的日志索引
+fn registered_nodes(
+  rocksdb_engine_handler: &Arc<RocksDBEngine>,
+) -> Result<HashMap<(String, u64), u64>, CommonError> {
+  let cluster_storage = ClusterStorage::new(rocksdb_engine_handler.clone());
+  let mut nodes = HashMap::new();
+  for cluster in cluster_storage.list_clusters()? {
+    for node in cluster_storage.list_nodes(&cluster.cluster_name)? {
+      nodes.insert((node.cluster_name, node.node_id), node.register_index);
+    }
+  }
+  return Ok(nodes);
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{thread::sleep, time::Duration};
+
+  use super::NodeHeartbeatCache;
+
+  #[test]
+  fn node_heartbeat_expire_test() {
+    let cache = NodeHeartbeatCache::default();
+    let nodes = vec![("mqtt".to_string(), 1), ("mqtt".to_string(), 2)];
+    // 第一次检查只开始计时
+    assert!(cache.expired(nodes.clone(), Duration::ZERO).is_empty());
+
+    sleep(Duration::from_millis(10));
+    cache.report("mqtt", 2);
+    assert_eq!(
+      cache.expired(nodes, Duration::from_millis(5)),
+      vec![("mqtt".to_string(), 1)]
+    );
+
+    // 节点被移除后心跳记录随之清理
+    assert!(cache.expired(Vec::new(), Duration::ZERO).is_empty());
+    assert!(cache.last_seen.is_empty());
+  }
+}
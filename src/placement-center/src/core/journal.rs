@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use protocol::{ClusterType, journal::SegmentStatus};
+
+use crate::{
+  core::error::PlacementCenterError,
+  storage::{
+    cluster::{ClusterStorage, Node},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// Segment 的状态只能向前变化：Write -> PreSeal -> Sealed，Write 也可以直接封存
+pub fn is_valid_segment_status_transition(from: SegmentStatus, to: SegmentStatus) -> bool {
+  matches!(
+    (from, to),
+    (SegmentStatus::Write, SegmentStatus::PreSeal)
+      | (SegmentStatus::Write, SegmentStatus::Sealed)
+      | (SegmentStatus::PreSeal, SegmentStatus::Sealed)
+  )
+}
+
+/// 返回 Journal 集群中当前注册的节点，心跳超时的节点已经被移出集群
+pub fn journal_nodes(
+  rocksdb_engine_handler: &Arc<RocksDBEngine>,
+  cluster_name: &str,
+) -> Result<Vec<Node>, PlacementCenterError> {
+  let cluster_storage = ClusterStorage::new(rocksdb_engine_handler.clone());
+  let cluster = match cluster_storage.get_cluster(cluster_name)? {
+    Some(cluster) => cluster,
+    None => {
+      return Err(PlacementCenterError::ClusterNotFound(
+        cluster_name.to_string(),
+      ));
+    }
+  };
+  let journal_type = ClusterType::JournalServer.as_str_name();
+  if cluster.cluster_type != journal_type {
+    return Err(PlacementCenterError::ClusterTypeUnexpected(
+      cluster.cluster_name,
+      cluster.cluster_type,
+      journal_type.to_string(),
+    ));
+  }
+  return Ok(cluster_storage.list_nodes(cluster_name)?);
+}
+
+#[cfg(test)]
+mod tests {
+  use protocol::journal::SegmentStatus;
+
+  use super::is_valid_segment_status_transition;
+
+  #[test]
+  fn segment_status_transition_test() {
+    assert!(is_valid_segment_status_transition(
+      SegmentStatus::Write,
+      SegmentStatus::PreSeal
+    ));
+    assert!(is_valid_segment_status_transition(
+      SegmentStatus::PreSeal,
+      SegmentStatus::Sealed
+    ));
+    assert!(!is_valid_segment_status_transition(
+      SegmentStatus::Sealed,
+      SegmentStatus::Write
+    ));
+    assert!(!is_valid_segment_status_transition(
+      SegmentStatus::Write,
+      SegmentStatus::Write
+    ));
+  }
+}
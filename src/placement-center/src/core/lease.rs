@@ -0,0 +1,154 @@
+use std::{
+  sync::Arc,
+  time::{Duration, Instant},
+};
+
+use dashmap::DashMap;
+use log::{error, info};
+use prost::Message;
+use protocol::LeaseRevokeRequest;
+use tokio::{select, sync::broadcast, time::sleep};
+
+use crate::{
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+  },
+  storage::lease::Lease,
+};
+
+// Leader 检查租约是否过期的间隔（毫秒）
+const LEASE_CHECK_INTERVAL_MS: u64 = 500;
+
+/// 租约的过期时间只保存在内存中：所有节点在应用 LeaseGrant 时记录过期时间，
+/// 续约只发送到 Leader，因此只有 Leader 的过期时间是准确的，也只由 Leader 发起过期撤销。
+/// 节点成为 Leader 时会将所有租约的过期时间重置为一个完整的 TTL，避免切主后租约被提前回收。
+#[derive(Debug, Default)]
+pub struct LeaseManager {
+  // lease id -> (ttl, 过期时间)
+  deadlines: DashMap<i64, (i64, Instant)>,
+}
+
+impl LeaseManager {
+  // 授予或续约时刷新过期时间
+  pub fn refresh(&self, lease_id: i64, ttl: i64) {
+    let deadline = Instant::now() + Duration::from_secs(ttl.max(0) as u64);
+    self.deadlines.insert(lease_id, (ttl, deadline));
+  }
+
+  pub fn remove(&self, lease_id: i64) {
+    self.deadlines.remove(&lease_id);
+  }
+
+  // 续约并返回租约的 TTL（秒），租约不存在或已经过期时返回 None，等待 Leader 撤销
+  pub fn keep_alive(&self, lease_id: i64) -> Option<i64> {
+    let mut entry = self.deadlines.get_mut(&lease_id)?;
+    let (ttl, deadline) = *entry.value();
+    let now = Instant::now();
+    if deadline <= now {
+      return None;
+    }
+    *entry.value_mut() = (ttl, now + Duration::from_secs(ttl.max(0) as u64));
+    Some(ttl)
+  }
+
+  // 租约的剩余时间（秒），租约不存在时返回 None
+  pub fn remaining(&self, lease_id: i64) -> Option<i64> {
+    self.deadlines.get(&lease_id).map(|entry| {
+      let (_, deadline) = *entry.value();
+      deadline.saturating_duration_since(Instant::now()).as_secs() as i64
+    })
+  }
+
+  pub fn expired(&self) -> Vec<i64> {
+    let now = Instant::now();
+    self
+      .deadlines
+      .iter()
+      .filter(|entry| entry.value().1 <= now)
+      .map(|entry| *entry.key())
+      .collect()
+  }
+
+  // 成为 Leader 时重置所有租约的过期时间
+  pub fn promote(&self) {
+    for mut entry in self.deadlines.iter_mut() {
+      let ttl = entry.value().0;
+      *entry.value_mut() = (ttl, Instant::now() + Duration::from_secs(ttl.max(0) as u64));
+    }
+  }
+
+  // 从快照恢复状态机后，以存储中的租约重建过期时间
+  pub fn reload(&self, leases: Vec<Lease>) {
+    self.deadlines.clear();
+    for lease in leases {
+      self.refresh(lease.id, lease.ttl);
+    }
+  }
+}
+
+/// 由 Leader 周期性检查过期的租约，并通过 Raft 日志提交 LeaseRevoke，保证所有副本一致地删除 key
+pub async fn start_lease_expire_thread(
+  raft_machine_apply: Arc<RaftMachineApply>,
+  lease_manager: Arc<LeaseManager>,
+  stop_sx: broadcast::Sender<bool>,
+) {
+  let mut stop_rx = stop_sx.subscribe();
+  let mut is_leader = false;
+  loop {
+    select! {
+      val = stop_rx.recv() => {
+        if let Ok(true) = val {
+          info!("Lease expire thread stopped successfully");
+          return;
+        }
+      }
+      _ = sleep(Duration::from_millis(LEASE_CHECK_INTERVAL_MS)) => {
+        let leader = raft_machine_apply.is_leader();
+        if leader && !is_leader {
+          lease_manager.promote();
+        }
+        is_leader = leader;
+        if !is_leader {
+          continue;
+        }
+
+        for lease_id in lease_manager.expired() {
+          let req = LeaseRevokeRequest { id: lease_id };
+          let data = StorageData::new(
+            StorageDataType::LeaseRevoke,
+            LeaseRevokeRequest::encode_to_vec(&req),
+          );
+          match raft_machine_apply.client_write(data).await {
+            Ok(reply) if reply.data == AppResponseData::NotFound => {
+              info!("Lease {} expired but has already been revoked", lease_id)
+            }
+            Ok(_) => info!("Lease {} expired and was revoked", lease_id),
+            Err(e) => error!("Failed to revoke expired lease {}, {}", lease_id, e),
+          }
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::LeaseManager;
+
+  #[test]
+  fn lease_manager_expire_test() {
+    let manager = LeaseManager::default();
+    manager.refresh(1, 0);
+    manager.refresh(2, 60);
+    assert_eq!(manager.expired(), vec![1]);
+    assert!(manager.remaining(2).unwrap() > 0);
+    assert_eq!(manager.keep_alive(1), None);
+    assert_eq!(manager.keep_alive(2), Some(60));
+
+    manager.remove(1);
+    assert!(manager.expired().is_empty());
+    assert_eq!(manager.remaining(1), None);
+  }
+}
@@ -1 +1,11 @@
+pub mod blacklist;
+pub mod config;
 pub mod error;
+pub mod heartbeat;
+pub mod journal;
+pub mod lease;
+pub mod mqtt;
+pub mod retain;
+pub mod schema;
+pub mod segment_placement;
+pub mod watch;
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use protocol::ClusterType;
+
+use crate::{
+  core::error::PlacementCenterError,
+  storage::{cluster::ClusterStorage, rocksdb::RocksDBEngine},
+};
+
+/// 校验 MQTT 元数据所属的集群：集群名称是存储 key 的一部分，不能包含 /，
+/// 集群必须已经注册并且类型为 MqttBrokerServer
+pub fn check_mqtt_cluster(
+  rocksdb_engine_handler: &Arc<RocksDBEngine>,
+  cluster_name: &str,
+) -> Result<(), PlacementCenterError> {
+  if cluster_name.is_empty() || cluster_name.contains('/') {
+    return Err(PlacementCenterError::MqttClusterNameInvalid(
+      cluster_name.to_string(),
+    ));
+  }
+
+  let cluster_storage = ClusterStorage::new(rocksdb_engine_handler.clone());
+  let cluster = match cluster_storage.get_cluster(cluster_name)? {
+    Some(cluster) => cluster,
+    None => {
+      return Err(PlacementCenterError::ClusterNotFound(
+        cluster_name.to_string(),
+      ));
+    }
+  };
+  let mqtt_type = ClusterType::MqttBrokerServer.as_str_name();
+  if cluster.cluster_type != mqtt_type {
+    return Err(PlacementCenterError::ClusterTypeUnexpected(
+      cluster.cluster_name,
+      cluster.cluster_type,
+      mqtt_type.to_string(),
+    ));
+  }
+  return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use protocol::ClusterType;
+
+  use super::check_mqtt_cluster;
+  use crate::{
+    core::error::PlacementCenterError,
+    storage::{
+      cluster::{Cluster, ClusterStorage},
+      rocksdb::{RocksDBEngine, test_commit, test_rocksdb_engine},
+    },
+  };
+
+  fn save_cluster(engine: &Arc<RocksDBEngine>, cluster_name: &str, cluster_type: ClusterType) {
+    let cluster_storage = ClusterStorage::new(engine.clone());
+    let cluster = Cluster {
+      cluster_name: cluster_name.to_string(),
+      cluster_type: cluster_type.as_str_name().to_string(),
+      ..Default::default()
+    };
+    test_commit(engine, |batch| {
+      cluster_storage.save_cluster(batch, &cluster)
+    });
+  }
+
+  #[test]
+  fn check_mqtt_cluster_test() {
+    let engine = test_rocksdb_engine("check_mqtt_cluster_test");
+    save_cluster(&engine, "mqtt", ClusterType::MqttBrokerServer);
+    save_cluster(&engine, "journal", ClusterType::JournalServer);
+
+    assert!(check_mqtt_cluster(&engine, "mqtt").is_ok());
+    assert!(matches!(
+      check_mqtt_cluster(&engine, "mqtt/1"),
+      Err(PlacementCenterError::MqttClusterNameInvalid(_))
+    ));
+    assert!(matches!(
+      check_mqtt_cluster(&engine, "unknown"),
+      Err(PlacementCenterError::ClusterNotFound(_))
+    ));
+    assert!(matches!(
+      check_mqtt_cluster(&engine, "journal"),
+      Err(PlacementCenterError::ClusterTypeUnexpected(..))
+    ));
+  }
+}
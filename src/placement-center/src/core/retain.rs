@@ -0,0 +1,76 @@
+use std::{sync::Arc, time::Duration};
+
+use common_base::tools::now_second;
+use log::{error, info};
+use prost::Message;
+use protocol::mqtt::{DeleteTopicRequest, PurgeRetainMessageData};
+use tokio::{select, sync::broadcast, time::sleep};
+
+use crate::{
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+  },
+  storage::{mqtt::topic::MqttTopicStorage, rocksdb::RocksDBEngine},
+};
+
+// 检查过期保留消息的间隔
+const RETAIN_MESSAGE_EXPIRE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+// 每条 Raft 日志最多清除的保留消息条数
+const RETAIN_MESSAGE_PURGE_BATCH_SIZE: usize = 100;
+
+/// 由 Leader 周期性检查已经过期的保留消息，并通过 Raft 日志提交 MqttPurgeRetainMessage 将其清除。
+/// 检查时的时间戳随日志一起提交，所有副本按同一个时间判断保留消息是否过期
+pub async fn start_retain_message_expire_check_thread(
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  stop_sx: broadcast::Sender<bool>,
+) {
+  let mut stop_rx = stop_sx.subscribe();
+  loop {
+    select! {
+      val = stop_rx.recv() => {
+        if let Ok(true) = val {
+          info!("Retain message expire check thread stopped successfully");
+          return;
+        }
+      }
+      _ = sleep(RETAIN_MESSAGE_EXPIRE_CHECK_INTERVAL) => {
+        if !raft_machine_apply.is_leader() {
+          continue;
+        }
+
+        let now = now_second();
+        let topic_storage = MqttTopicStorage::new(rocksdb_engine_handler.clone());
+        let expired = match topic_storage.list_expired_retain(now) {
+          Ok(expired) => expired,
+          Err(e) => {
+            error!("Failed to list topics with expired retain messages, {}", e);
+            continue;
+          }
+        };
+
+        for chunk in expired.chunks(RETAIN_MESSAGE_PURGE_BATCH_SIZE) {
+          let data = PurgeRetainMessageData {
+            topics: chunk
+              .iter()
+              .map(|topic| DeleteTopicRequest {
+                cluster_name: topic.cluster_name.clone(),
+                topic_name: topic.topic_name.clone(),
+              })
+              .collect(),
+            expire_time: now,
+          };
+          let data = StorageData::new(
+            StorageDataType::MqttPurgeRetainMessage,
+            PurgeRetainMessageData::encode_to_vec(&data),
+          );
+          match raft_machine_apply.client_write(data).await {
+            Ok(_) => info!("Purged {} expired retain messages", chunk.len()),
+            Err(e) => error!("Failed to purge expired retain messages, {}", e),
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,279 @@
+use apache_avro::{
+  Schema as AvroSchema, from_avro_datum, schema_compatibility::SchemaCompatibility,
+};
+use protocol::schema::{Compatibility, SchemaType};
+use serde_json::Value;
+use valico::json_schema;
+
+use crate::core::error::PlacementCenterError;
+
+/// 检查 schema 定义能否被解析，JSON Schema 同时检查能否被编译
+pub fn check_schema_definition(
+  schema_type: SchemaType,
+  definition: &str,
+) -> Result<(), PlacementCenterError> {
+  match schema_type {
+    SchemaType::Avro => {
+      parse_avro_schema(definition)?;
+    }
+    SchemaType::Json => {
+      let schema = parse_json_schema(definition)?;
+      let mut scope = json_schema::Scope::new();
+      if let Err(e) = scope.compile_and_return(schema, false) {
+        return Err(PlacementCenterError::SchemaInvalid(format!("{:?}", e)));
+      }
+    }
+  }
+  return Ok(());
+}
+
+/// 按兼容模式检查新版本 current 与上一个版本 previous 是否兼容。
+/// JSON Schema 只检查 type、properties、required、items 和 additionalProperties 这几个关键字。
+pub fn check_schema_compatibility(
+  schema_type: SchemaType,
+  compatibility: Compatibility,
+  previous: &str,
+  current: &str,
+) -> Result<(), PlacementCenterError> {
+  let (backward, forward) = match compatibility {
+    Compatibility::Backward => (true, false),
+    Compatibility::Forward => (false, true),
+    Compatibility::Full => (true, true),
+    Compatibility::None => return Ok(()),
+  };
+
+  // backward: 新版本作为读 schema 读取旧版本写入的数据，forward 则相反
+  let result = match schema_type {
+    SchemaType::Avro => {
+      let previous = parse_avro_schema(previous)?;
+      let current = parse_avro_schema(current)?;
+      let mut result = Ok(());
+      if backward {
+        result = SchemaCompatibility::can_read(&previous, &current).map_err(|e| e.to_string());
+      }
+      if forward && result.is_ok() {
+        result = SchemaCompatibility::can_read(&current, &previous).map_err(|e| e.to_string());
+      }
+      result
+    }
+    SchemaType::Json => {
+      let previous = parse_json_schema(previous)?;
+      let current = parse_json_schema(current)?;
+      let mut result = Ok(());
+      if backward {
+        result = json_can_read(&previous, &current, "$");
+      }
+      if forward && result.is_ok() {
+        result = json_can_read(&current, &previous, "$");
+      }
+      result
+    }
+  };
+  return result.map_err(PlacementCenterError::SchemaIncompatible);
+}
+
+/// 使用 schema 校验消息，返回校验失败的原因，消息合法时返回空列表。
+/// Avro 的消息为不带容器头的二进制数据，JSON Schema 的消息为 UTF-8 编码的 JSON。
+pub fn validate_schema_payload(
+  schema_type: SchemaType,
+  definition: &str,
+  payload: &[u8],
+) -> Result<Vec<String>, PlacementCenterError> {
+  match schema_type {
+    SchemaType::Avro => {
+      let schema = parse_avro_schema(definition)?;
+      let mut reader = payload;
+      if let Err(e) = from_avro_datum(&schema, &mut reader, None) {
+        return Ok(vec![e.to_string()]);
+      }
+      if !reader.is_empty() {
+        return Ok(vec![format!(
+          "{} unexpected bytes after the Avro datum",
+          reader.len()
+        )]);
+      }
+      return Ok(Vec::new());
+    }
+    SchemaType::Json => {
+      let schema = parse_json_schema(definition)?;
+      let instance = match serde_json::from_slice::<Value>(payload) {
+        Ok(instance) => instance,
+        Err(e) => return Ok(vec![e.to_string()]),
+      };
+      let mut scope = json_schema::Scope::new();
+      let schema = match scope.compile_and_return(schema, false) {
+        Ok(schema) => schema,
+        Err(e) => return Err(PlacementCenterError::SchemaInvalid(format!("{:?}", e))),
+      };
+      let state = schema.validate(&instance);
+      return Ok(
+        state
+          .errors
+          .iter()
+          .map(|e| format!("{} {}", e.get_path(), e.get_title()))
+          .collect(),
+      );
+    }
+  }
+}
+
+fn parse_avro_schema(definition: &str) -> Result<AvroSchema, PlacementCenterError> {
+  return AvroSchema::parse_str(definition)
+    .map_err(|e| PlacementCenterError::SchemaInvalid(e.to_string()));
+}
+
+fn parse_json_schema(definition: &str) -> Result<Value, PlacementCenterError> {
+  return serde_json::from_str::<Value>(definition)
+    .map_err(|e| PlacementCenterError::SchemaInvalid(e.to_string()));
+}
+
+// reader 能否读取按 writer 写入的数据，path 为出错字段的位置
+fn json_can_read(writer: &Value, reader: &Value, path: &str) -> Result<(), String> {
+  if let (Some(writer_type), Some(reader_type)) = (writer.get("type"), reader.get("type")) {
+    if writer_type != reader_type {
+      return Err(format!(
+        "{} changed type from {} to {}",
+        path, writer_type, reader_type
+      ));
+    }
+  }
+
+  // writer 中可选的字段在 reader 中不能是必填的
+  let writer_required = json_required(writer);
+  for field in json_required(reader) {
+    if !writer_required.contains(&field) {
+      return Err(format!(
+        "{}.{} is required by the reader but not by the writer",
+        path, field
+      ));
+    }
+  }
+
+  let writer_properties = writer.get("properties").and_then(Value::as_object);
+  let reader_properties = reader.get("properties").and_then(Value::as_object);
+  if let (Some(writer_properties), Some(reader_properties)) = (writer_properties, reader_properties)
+  {
+    for (name, reader_property) in reader_properties {
+      if let Some(writer_property) = writer_properties.get(name) {
+        json_can_read(
+          writer_property,
+          reader_property,
+          &format!("{}.{}", path, name),
+        )?;
+      }
+    }
+  }
+  if reader.get("additionalProperties") == Some(&Value::Bool(false)) {
+    if let Some(writer_properties) = writer_properties {
+      for name in writer_properties.keys() {
+        if !reader_properties.is_some_and(|properties| properties.contains_key(name)) {
+          return Err(format!("{}.{} is not allowed by the reader", path, name));
+        }
+      }
+    }
+  }
+
+  if let (Some(writer_items), Some(reader_items)) = (writer.get("items"), reader.get("items")) {
+    json_can_read(writer_items, reader_items, &format!("{}[]", path))?;
+  }
+  return Ok(());
+}
+
+fn json_required(schema: &Value) -> Vec<&str> {
+  return schema
+    .get("required")
+    .and_then(Value::as_array)
+    .map(|fields| fields.iter().filter_map(Value::as_str).collect())
+    .unwrap_or_default();
+}
+
+#[cfg(test)]
+mod tests {
+  use apache_avro::{Schema as AvroSchema, to_avro_datum, types::Record};
+  use protocol::schema::{Compatibility, SchemaType};
+
+  use super::{check_schema_compatibility, check_schema_definition, validate_schema_payload};
+
+  const AVRO_V1: &str = r#"{"type":"record","name":"User","fields":[
+    {"name":"name","type":"string"}]}"#;
+  // 新增字段带默认值，新旧版本可以互相读取
+  const AVRO_V2: &str = r#"{"type":"record","name":"User","fields":[
+    {"name":"name","type":"string"},{"name":"age","type":"int","default":0}]}"#;
+  // 新增字段没有默认值，新版本无法读取旧版本的数据
+  const AVRO_V3: &str = r#"{"type":"record","name":"User","fields":[
+    {"name":"name","type":"string"},{"name":"email","type":"string"}]}"#;
+
+  const JSON_V1: &str = r#"{"type":"object","properties":{"name":{"type":"string"}},
+    "required":["name"]}"#;
+  const JSON_V2: &str = r#"{"type":"object","properties":{"name":{"type":"string"},
+    "age":{"type":"integer"}},"required":["name","age"]}"#;
+
+  #[test]
+  fn avro_schema_compatibility_test() {
+    let check = |compatibility, previous, current| {
+      check_schema_compatibility(SchemaType::Avro, compatibility, previous, current).is_ok()
+    };
+    assert!(check(Compatibility::Full, AVRO_V1, AVRO_V2));
+    assert!(!check(Compatibility::Backward, AVRO_V1, AVRO_V3));
+    assert!(check(Compatibility::Forward, AVRO_V1, AVRO_V3));
+    assert!(!check(Compatibility::Full, AVRO_V1, AVRO_V3));
+    assert!(check(Compatibility::None, AVRO_V1, AVRO_V3));
+  }
+
+  #[test]
+  fn json_schema_compatibility_test() {
+    let check = |compatibility, previous, current| {
+      check_schema_compatibility(SchemaType::Json, compatibility, previous, current).is_ok()
+    };
+    // 新版本新增了必填字段，旧数据中可能没有这个字段
+    assert!(!check(Compatibility::Backward, JSON_V1, JSON_V2));
+    assert!(check(Compatibility::Forward, JSON_V1, JSON_V2));
+    assert!(check(Compatibility::Backward, JSON_V2, JSON_V1));
+
+    let strict = r#"{"type":"object","properties":{"name":{"type":"string"}},
+      "additionalProperties":false}"#;
+    assert!(!check(Compatibility::Backward, JSON_V2, strict));
+    let renamed = r#"{"type":"object","properties":{"name":{"type":"integer"}}}"#;
+    assert!(!check(Compatibility::Forward, JSON_V1, renamed));
+  }
+
+  #[test]
+  fn validate_schema_payload_test() {
+    let schema = AvroSchema::parse_str(AVRO_V2).unwrap();
+    let mut record = Record::new(&schema).unwrap();
+    record.put("name", "alice");
+    record.put("age", 30);
+    let payload = to_avro_datum(&schema, record).unwrap();
+    assert!(
+      validate_schema_payload(SchemaType::Avro, AVRO_V2, &payload)
+        .unwrap()
+        .is_empty()
+    );
+    assert!(
+      !validate_schema_payload(SchemaType::Avro, AVRO_V2, &[0xff])
+        .unwrap()
+        .is_empty()
+    );
+
+    let valid = br#"{"name":"alice","age":30}"#;
+    let invalid = br#"{"name":1}"#;
+    assert!(
+      validate_schema_payload(SchemaType::Json, JSON_V2, valid)
+        .unwrap()
+        .is_empty()
+    );
+    assert!(
+      !validate_schema_payload(SchemaType::Json, JSON_V2, invalid)
+        .unwrap()
+        .is_empty()
+    );
+    assert!(
+      !validate_schema_payload(SchemaType::Json, JSON_V2, b"not json")
+        .unwrap()
+        .is_empty()
+    );
+
+    assert!(check_schema_definition(SchemaType::Avro, "{").is_err());
+    assert!(check_schema_definition(SchemaType::Json, JSON_V1).is_ok());
+  }
+}
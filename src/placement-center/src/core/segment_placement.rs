@@ -0,0 +1,348 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+  },
+};
+
+use common_base::config::placement_center::{Journal, SegmentPlacementMode};
+use protocol::journal::SegmentStatus;
+
+use crate::{
+  core::error::PlacementCenterError,
+  storage::{cluster::Node, journal::segment::Segment},
+};
+
+/// 节点当前承载的 Segment 数量，`leaders` 只统计未封存的 Segment
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeLoad {
+  pub segments: usize,
+  pub leaders: usize,
+}
+
+/// 一个新 Segment 的放置结果，`leader` 一定是 `replicas` 中的节点
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentPlacement {
+  pub leader: u64,
+  pub replicas: Vec<u64>,
+}
+
+/// Segment 副本的放置策略。
+/// 节点列表和负载只在 Leader 上是最新的，所以只能在 Raft Leader 上调用，
+/// 选择的结果通过 Raft 日志提交，各副本不需要重新计算。
+pub trait SegmentPlacementPolicy: Send + Sync {
+  /// 从 nodes 中选择 replica_num 个不重复的节点，调用方保证节点数量足够
+  fn select_replicas(
+    &self,
+    nodes: &[Node],
+    loads: &HashMap<u64, NodeLoad>,
+    replica_num: usize,
+  ) -> Vec<u64>;
+
+  /// 在选出的副本中选择 Leader，默认选择当前作为 Leader 的 Segment 最少的节点
+  fn select_leader(&self, replicas: &[u64], loads: &HashMap<u64, NodeLoad>) -> u64 {
+    return *replicas
+      .iter()
+      .min_by_key(|node_id| (node_load(loads, **node_id).leaders, **node_id))
+      .unwrap();
+  }
+}
+
+/// 按配置创建放置策略
+pub fn build_segment_placement_policy(config: &Journal) -> Arc<dyn SegmentPlacementPolicy> {
+  match config.segment_placement_mode {
+    SegmentPlacementMode::RoundRobin => Arc::new(RoundRobinPlacement::default()),
+    SegmentPlacementMode::LeastLoaded => Arc::new(LeastLoadedPlacement),
+    SegmentPlacementMode::ZoneAware => Arc::new(ZoneAwarePlacement::new(config.zone_key.clone())),
+  }
+}
+
+/// 为新的 Segment 选择副本和 Leader
+pub fn place_segment(
+  policy: &dyn SegmentPlacementPolicy,
+  cluster_name: &str,
+  nodes: &[Node],
+  segments: &[Segment],
+  replica_num: u32,
+) -> Result<SegmentPlacement, PlacementCenterError> {
+  if replica_num == 0 || nodes.len() < replica_num as usize {
+    return Err(PlacementCenterError::JournalNotEnoughNodes(
+      cluster_name.to_string(),
+      replica_num,
+      nodes.len(),
+    ));
+  }
+  let loads = node_loads(segments);
+  let replicas = policy.select_replicas(nodes, &loads, replica_num as usize);
+  let leader = policy.select_leader(&replicas, &loads);
+  return Ok(SegmentPlacement { leader, replicas });
+}
+
+/// 统计每个节点上的 Segment 数量以及作为 Leader 的未封存 Segment 数量
+pub fn node_loads(segments: &[Segment]) -> HashMap<u64, NodeLoad> {
+  let sealed = SegmentStatus::Sealed.as_str_name();
+  let mut loads: HashMap<u64, NodeLoad> = HashMap::new();
+  for segment in segments {
+    for node_id in segment.replicas.iter() {
+      loads.entry(*node_id).or_default().segments += 1;
+    }
+    if segment.status != sealed {
+      loads.entry(segment.leader).or_default().leaders += 1;
+    }
+  }
+  return loads;
+}
+
+fn node_load(loads: &HashMap<u64, NodeLoad>, node_id: u64) -> NodeLoad {
+  return loads.get(&node_id).copied().unwrap_or_default();
+}
+
+/// 按 node_id 排序后从上一次结束的位置开始连续选择节点。
+/// 游标只保存在内存中，切主后从头开始。
+#[derive(Debug, Default)]
+pub struct RoundRobinPlacement {
+  cursor: AtomicUsize,
+}
+
+impl SegmentPlacementPolicy for RoundRobinPlacement {
+  fn select_replicas(
+    &self,
+    nodes: &[Node],
+    _: &HashMap<u64, NodeLoad>,
+    replica_num: usize,
+  ) -> Vec<u64> {
+    let mut node_ids: Vec<u64> = nodes.iter().map(|node| node.node_id).collect();
+    node_ids.sort();
+    let start = self.cursor.fetch_add(1, Ordering::Relaxed) % node_ids.len();
+    return node_ids
+      .iter()
+      .cycle()
+      .skip(start)
+      .take(replica_num)
+      .copied()
+      .collect();
+  }
+}
+
+/// 选择 Segment 数量最少的节点，数量相同时选择 node_id 较小的节点
+#[derive(Debug, Default)]
+pub struct LeastLoadedPlacement;
+
+impl SegmentPlacementPolicy for LeastLoadedPlacement {
+  fn select_replicas(
+    &self,
+    nodes: &[Node],
+    loads: &HashMap<u64, NodeLoad>,
+    replica_num: usize,
+  ) -> Vec<u64> {
+    let mut node_ids: Vec<u64> = nodes.iter().map(|node| node.node_id).collect();
+    node_ids.sort_by_key(|node_id| (node_load(loads, *node_id).segments, *node_id));
+    node_ids.truncate(replica_num);
+    return node_ids;
+  }
+}
+
+/// 副本优先放到已选副本最少的区域中，同一区域内选择 Segment 数量最少的节点。
+/// 区域从节点 extend_info（JSON）中 `zone_key` 对应的字段读取，没有该字段的节点属于同一个空区域。
+#[derive(Debug)]
+pub struct ZoneAwarePlacement {
+  zone_key: String,
+}
+
+impl ZoneAwarePlacement {
+  pub fn new(zone_key: String) -> Self {
+    return ZoneAwarePlacement { zone_key };
+  }
+
+  fn node_zone(&self, node: &Node) -> String {
+    return serde_json::from_str::<serde_json::Value>(&node.extend_info)
+      .ok()
+      .and_then(|info| {
+        info
+          .get(&self.zone_key)?
+          .as_str()
+          .map(|zone| zone.to_string())
+      })
+      .unwrap_or_default();
+  }
+}
+
+impl SegmentPlacementPolicy for ZoneAwarePlacement {
+  fn select_replicas(
+    &self,
+    nodes: &[Node],
+    loads: &HashMap<u64, NodeLoad>,
+    replica_num: usize,
+  ) -> Vec<u64> {
+    // 区域 -> 按负载排好序的候选节点，使用 BTreeMap 保证区域的遍历顺序固定
+    let mut zones: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    for node in nodes {
+      zones
+        .entry(self.node_zone(node))
+        .or_default()
+        .push(node.node_id);
+    }
+    for candidates in zones.values_mut() {
+      candidates.sort_by_key(|node_id| (node_load(loads, *node_id).segments, *node_id));
+      candidates.reverse();
+    }
+
+    let mut chosen_by_zone: HashMap<String, usize> = HashMap::new();
+    let mut replicas = Vec::with_capacity(replica_num);
+    while replicas.len() < replica_num {
+      // 已选副本最少的区域优先，其次是候选节点负载最低的区域
+      let zone = zones
+        .iter()
+        .filter_map(|(zone, candidates)| {
+          let node_id = *candidates.last()?;
+          let chosen = chosen_by_zone.get(zone).copied().unwrap_or_default();
+          Some((
+            chosen,
+            node_load(loads, node_id).segments,
+            node_id,
+            zone.clone(),
+          ))
+        })
+        .min()
+        .map(|(_, _, _, zone)| zone)
+        .unwrap();
+      replicas.push(zones.get_mut(&zone).unwrap().pop().unwrap());
+      *chosen_by_zone.entry(zone).or_default() += 1;
+    }
+    return replicas;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::{
+    LeastLoadedPlacement, NodeLoad, RoundRobinPlacement, SegmentPlacement, SegmentPlacementPolicy,
+    ZoneAwarePlacement, node_loads, place_segment,
+  };
+  use crate::storage::{cluster::Node, journal::segment::Segment};
+
+  fn nodes(node_ids: &[u64]) -> Vec<Node> {
+    return node_ids
+      .iter()
+      .map(|node_id| Node {
+        node_id: *node_id,
+        ..Default::default()
+      })
+      .collect();
+  }
+
+  fn zone_node(node_id: u64, zone: &str) -> Node {
+    return Node {
+      node_id,
+      extend_info: format!("{{\"zone\":\"{}\"}}", zone),
+      ..Default::default()
+    };
+  }
+
+  fn segment(leader: u64, replicas: Vec<u64>, status: &str) -> Segment {
+    return Segment {
+      leader,
+      replicas,
+      status: status.to_string(),
+      ..Default::default()
+    };
+  }
+
+  #[test]
+  fn node_loads_test() {
+    let loads = node_loads(&[
+      segment(1, vec![1, 2], "SEGMENT_STATUS_WRITE"),
+      segment(2, vec![2, 3], "SEGMENT_STATUS_SEALED"),
+    ]);
+    assert_eq!(
+      loads[&1],
+      NodeLoad {
+        segments: 1,
+        leaders: 1
+      }
+    );
+    // 已封存的 Segment 不计入 Leader 数量
+    assert_eq!(
+      loads[&2],
+      NodeLoad {
+        segments: 2,
+        leaders: 0
+      }
+    );
+  }
+
+  #[test]
+  fn round_robin_placement_test() {
+    let policy = RoundRobinPlacement::default();
+    let nodes = nodes(&[3, 1, 2]);
+    let loads = HashMap::new();
+    assert_eq!(policy.select_replicas(&nodes, &loads, 2), vec![1, 2]);
+    assert_eq!(policy.select_replicas(&nodes, &loads, 2), vec![2, 3]);
+    assert_eq!(policy.select_replicas(&nodes, &loads, 2), vec![3, 1]);
+    assert_eq!(policy.select_replicas(&nodes, &loads, 3), vec![1, 2, 3]);
+  }
+
+  #[test]
+  fn least_loaded_placement_test() {
+    let segments = vec![
+      segment(1, vec![1, 2], "SEGMENT_STATUS_WRITE"),
+      segment(1, vec![1, 3], "SEGMENT_STATUS_SEALED"),
+    ];
+    let placement = place_segment(
+      &LeastLoadedPlacement,
+      "journal",
+      &nodes(&[1, 2, 3, 4]),
+      &segments,
+      3,
+    )
+    .unwrap();
+    // 节点 4 没有 Segment，节点 2、3 各一个，节点 1 有两个
+    assert_eq!(
+      placement,
+      SegmentPlacement {
+        leader: 2,
+        replicas: vec![4, 2, 3],
+      }
+    );
+  }
+
+  #[test]
+  fn zone_aware_placement_test() {
+    let policy = ZoneAwarePlacement::new("zone".to_string());
+    let nodes = vec![
+      zone_node(1, "a"),
+      zone_node(2, "a"),
+      zone_node(3, "a"),
+      zone_node(4, "b"),
+      zone_node(5, "c"),
+    ];
+    let loads = node_loads(&[segment(4, vec![4], "SEGMENT_STATUS_WRITE")]);
+    // 每个区域先选一个节点，区域 b 中的节点负载最高，最后选择
+    assert_eq!(policy.select_replicas(&nodes, &loads, 3), vec![1, 5, 4]);
+    // 区域数量不足时再回到已选过的区域
+    assert_eq!(
+      policy.select_replicas(&nodes, &loads, 5),
+      vec![1, 5, 4, 2, 3]
+    );
+  }
+
+  #[test]
+  fn select_leader_test() {
+    let loads = node_loads(&[
+      segment(1, vec![1, 2], "SEGMENT_STATUS_WRITE"),
+      segment(2, vec![1, 2], "SEGMENT_STATUS_WRITE"),
+      segment(3, vec![3], "SEGMENT_STATUS_SEALED"),
+    ]);
+    assert_eq!(LeastLoadedPlacement.select_leader(&[1, 2, 3], &loads), 3);
+    assert_eq!(LeastLoadedPlacement.select_leader(&[2, 1], &loads), 1);
+  }
+
+  #[test]
+  fn place_segment_not_enough_nodes_test() {
+    assert!(place_segment(&LeastLoadedPlacement, "journal", &nodes(&[1, 2]), &[], 3).is_err());
+    assert!(place_segment(&LeastLoadedPlacement, "journal", &nodes(&[1, 2]), &[], 0).is_err());
+  }
+}
@@ -0,0 +1,156 @@
+use std::{
+  collections::VecDeque,
+  sync::{
+    RwLock,
+    atomic::{AtomicU64, Ordering},
+  },
+};
+
+use tokio::sync::broadcast;
+
+use super::error::PlacementCenterError;
+
+// 保留的历史变更条数，续订的起始索引早于保留范围时需要客户端重新全量读取
+const DEFAULT_WATCH_HISTORY_CAPACITY: usize = 10000;
+// 广播通道容量，订阅者消费过慢超出该容量时会被断开
+const DEFAULT_WATCH_CHANNEL_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KvWatchEventType {
+  Put,
+  Delete,
+}
+
+/// 一次 KV 变更事件，`index` 为产生该变更的 Raft 日志索引
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KvWatchEvent {
+  pub event_type: KvWatchEventType,
+  pub key: Vec<u8>,
+  pub value: Vec<u8>,
+  pub index: u64,
+}
+
+/// KV 变更的广播中心：状态机应用 KvSet/KvDelete 时发布事件，Watch 请求订阅事件。
+/// 同时保留最近的一段历史变更，订阅时可以从指定的日志索引开始补发。
+#[derive(Debug)]
+pub struct KvWatchHub {
+  sender: broadcast::Sender<KvWatchEvent>,
+  history: RwLock<VecDeque<KvWatchEvent>>,
+  history_capacity: usize,
+  // 小于等于该索引的变更已经不在历史中
+  compacted_index: AtomicU64,
+}
+
+impl Default for KvWatchHub {
+  fn default() -> Self {
+    Self::new(
+      DEFAULT_WATCH_HISTORY_CAPACITY,
+      DEFAULT_WATCH_CHANNEL_CAPACITY,
+    )
+  }
+}
+
+impl KvWatchHub {
+  pub fn new(history_capacity: usize, channel_capacity: usize) -> Self {
+    let (sender, _) = broadcast::channel(channel_capacity);
+    KvWatchHub {
+      sender,
+      history: RwLock::new(VecDeque::with_capacity(history_capacity)),
+      history_capacity,
+      compacted_index: AtomicU64::new(0),
+    }
+  }
+
+  // 发布变更事件，写历史和广播在同一把锁内完成，保证订阅时读取的历史与广播之间没有间隙
+  pub fn publish(&self, event: KvWatchEvent) {
+    let mut history = self.history.write().unwrap();
+    if history.len() >= self.history_capacity {
+      if let Some(evicted) = history.pop_front() {
+        self.compacted_index.store(evicted.index, Ordering::SeqCst);
+      }
+    }
+    history.push_back(event.clone());
+    // 没有订阅者时发送会返回错误，忽略即可
+    let _ = self.sender.send(event);
+  }
+
+  // 重启或从快照恢复状态机后，已经应用到数据中的变更不在内存历史中，无法补发
+  pub fn reset(&self, compacted_index: u64) {
+    let mut history = self.history.write().unwrap();
+    history.clear();
+    self
+      .compacted_index
+      .store(compacted_index, Ordering::SeqCst);
+  }
+
+  /// 订阅变更事件，返回需要补发的历史事件和后续事件的接收端。
+  /// `start_index` 为 0 表示只订阅新的变更。
+  pub fn subscribe(
+    &self,
+    start_index: u64,
+  ) -> Result<(Vec<KvWatchEvent>, broadcast::Receiver<KvWatchEvent>), PlacementCenterError> {
+    let history = self.history.read().unwrap();
+    let receiver = self.sender.subscribe();
+    if start_index == 0 {
+      return Ok((Vec::new(), receiver));
+    }
+
+    let compacted_index = self.compacted_index.load(Ordering::SeqCst);
+    if start_index <= compacted_index {
+      return Err(PlacementCenterError::WatchIndexCompacted(
+        start_index,
+        compacted_index,
+      ));
+    }
+
+    let events = history
+      .iter()
+      .filter(|event| event.index >= start_index)
+      .cloned()
+      .collect();
+    Ok((events, receiver))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{KvWatchEvent, KvWatchEventType, KvWatchHub};
+
+  fn put(key: &str, index: u64) -> KvWatchEvent {
+    KvWatchEvent {
+      event_type: KvWatchEventType::Put,
+      key: key.as_bytes().to_vec(),
+      value: b"v".to_vec(),
+      index,
+    }
+  }
+
+  #[tokio::test]
+  async fn watch_hub_resume_test() {
+    let hub = KvWatchHub::new(2, 16);
+    hub.publish(put("/a", 1));
+    hub.publish(put("/b", 2));
+    hub.publish(put("/c", 3));
+
+    // 索引 1 已经被淘汰出历史
+    assert!(hub.subscribe(1).is_err());
+
+    let (events, mut receiver) = hub.subscribe(3).unwrap();
+    assert_eq!(events, vec![put("/c", 3)]);
+
+    hub.publish(put("/d", 4));
+    assert_eq!(receiver.recv().await.unwrap(), put("/d", 4));
+  }
+
+  #[test]
+  fn watch_hub_reset_test() {
+    let hub = KvWatchHub::default();
+    // 重启后数据目录中已经应用到索引 5，之前的变更无法补发
+    hub.reset(5);
+    assert!(hub.subscribe(3).is_err());
+    assert!(hub.subscribe(5).is_err());
+
+    let (events, _receiver) = hub.subscribe(6).unwrap();
+    assert!(events.is_empty());
+  }
+}
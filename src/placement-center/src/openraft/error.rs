@@ -1,10 +1,14 @@
 use std::error::Error;
 
-use common_base::errors::NezaMQError;
-use openraft::error::{RPCError, Unreachable};
+use common_base::error::CommonError;
+use openraft::error::{RPCError, StreamingError, Unreachable};
 
 use super::typeconfig::TypeConfig;
 
-pub fn to_error<E: Error + 'static + Clone>(e: NezaMQError) -> RPCError<TypeConfig, E> {
+pub fn to_error<E: Error + 'static + Clone>(e: CommonError) -> RPCError<TypeConfig, E> {
   RPCError::Unreachable(Unreachable::new(&e))
 }
+
+pub fn to_streaming_error(e: CommonError) -> StreamingError<TypeConfig> {
+  StreamingError::Unreachable(Unreachable::new(&e))
+}
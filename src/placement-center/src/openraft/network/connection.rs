@@ -1,23 +1,37 @@
+use std::future::Future;
 use std::sync::Arc;
 
 use bincode::{deserialize, serialize};
 use common_base::error::CommonError;
 use grpc_clients::placement::openraft::OpenRaftServiceManager;
 use grpc_clients::pool::ClientPool;
+use log::error;
 use mobc::Connection;
-use openraft::RaftNetwork;
-use openraft::error::{RPCError, RaftError};
+use openraft::error::{Fatal, RPCError, RaftError, RemoteError, ReplicationClosed, StreamingError};
 use openraft::network::RPCOption;
-use openraft::raft::{AppendEntriesRequest, AppendEntriesResponse};
-use protocol::AppendRequest;
+use openraft::network::v2::RaftNetworkV2;
+use openraft::raft::{
+  AppendEntriesRequest, AppendEntriesResponse, SnapshotResponse, VoteRequest, VoteResponse,
+};
+use openraft::{OptionalSend, Snapshot, Vote};
+use protocol::{
+  AppendRequest, SnapshotChunk, SnapshotOffsetRequest, VoteRequest as GrpcVoteRequest,
+};
+use serde::de::DeserializeOwned;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 
-use crate::openraft::error::to_error;
+use crate::core::error::PlacementCenterError;
+use crate::openraft::error::{to_error, to_streaming_error};
+use crate::openraft::store::snapshot::{SnapshotFile, chunk_checksum};
 use crate::openraft::typeconfig::TypeConfig;
 
 /// 表示网络连接的结构体。
-/// 它包含一个地址和一个客户端池。
+/// 它包含目标节点 ID、地址和一个客户端池。
 #[derive(Debug)]
 pub struct NetworkConnection {
+  // 目标节点的 ID，用于构造远端返回的错误
+  target: u64,
   // 连接的地址，以字符串形式存储
   addr: String,
   // 客户端池的共享智能指针，用于管理客户端连接
@@ -28,33 +42,53 @@ impl NetworkConnection {
   /// 创建一个新的 `NetworkConnection` 实例。
   ///
   /// # 参数
+  /// - `target`: 目标节点的 ID。
   /// - `addr`: 连接的地址，以字符串形式传入。
   /// - `client_pool`: 客户端池的共享智能指针。
   ///
   /// # 返回值
   /// 一个新的 `NetworkConnection` 实例。
-  pub fn new(addr: String, client_pool: Arc<ClientPool>) -> Self {
-    // 使用传入的地址和客户端池创建一个新的实例
-    Self { addr, client_pool }
+  pub fn new(target: u64, addr: String, client_pool: Arc<ClientPool>) -> Self {
+    Self {
+      target,
+      addr,
+      client_pool,
+    }
   }
 
-  // 异步方法，用于获取一个连接。
-  // 此方法尚未实现，返回值类型为 `Result<Connection<OpenRaftServiceManager>>`
+  // 从客户端池中获取到目标节点的连接
   async fn c(&mut self) -> Result<Connection<OpenRaftServiceManager>, CommonError> {
     self
       .client_pool
       .placement_center_openraft_service_client(&self.addr)
       .await
   }
+
+  // 服务端返回的是 Raft 接口调用结果序列化后的数据，
+  // 远端 Raft 返回的错误需要原样交给 openraft 处理，例如日志冲突时回退重新发送
+  fn decode_reply<Resp>(&self, value: &[u8]) -> Result<Resp, RPCError<TypeConfig>>
+  where
+    Resp: DeserializeOwned,
+  {
+    let result: Result<Resp, RaftError<TypeConfig>> = match deserialize(value) {
+      Ok(data) => data,
+      Err(e) => return Err(to_error(e.into())),
+    };
+
+    match result {
+      Ok(data) => Ok(data),
+      Err(e) => Err(RPCError::RemoteError(RemoteError::new(self.target, e))),
+    }
+  }
 }
 
 #[allow(clippy::blocks_in_conditions)]
-impl RaftNetwork<TypeConfig> for NetworkConnection {
+impl RaftNetworkV2<TypeConfig> for NetworkConnection {
   async fn append_entries(
     &mut self,
     req: AppendEntriesRequest<TypeConfig>,
-    option: RPCOption,
-  ) -> Result<AppendEntriesResponse<TypeConfig>, RPCError<TypeConfig, RaftError<TypeConfig>>> {
+    _option: RPCOption,
+  ) -> Result<AppendEntriesResponse<TypeConfig>, RPCError<TypeConfig>> {
     let mut c = match self.c().await {
       Ok(conn) => conn,
       Err(e) => return Err(to_error(e)),
@@ -62,44 +96,132 @@ impl RaftNetwork<TypeConfig> for NetworkConnection {
 
     let value = match serialize(&req) {
       Ok(data) => data,
-      Err(e) => return Err(to_error(e)),
+      Err(e) => return Err(to_error(e.into())),
     };
 
-    let request = AppendRequest { value };
-    let reply = match c.append(request).await {
+    let reply = match c.append(AppendRequest { value }).await {
       Ok(reply) => reply.into_inner(),
-      Err(e) => return Err(to_error(CommonError::CommonError(r.to_string()))),
+      Err(e) => return Err(to_error(e.into())),
+    };
+
+    self.decode_reply(&reply.value)
+  }
+
+  async fn vote(
+    &mut self,
+    req: VoteRequest<TypeConfig>,
+    _option: RPCOption,
+  ) -> Result<VoteResponse<TypeConfig>, RPCError<TypeConfig>> {
+    let mut c = match self.c().await {
+      Ok(conn) => conn,
+      Err(e) => return Err(to_error(e)),
     };
 
-    let result = match deserialize(&reply.value) {
+    let value = match serialize(&req) {
       Ok(data) => data,
-      Err(e) => return Err(to_error(CommonError::CommonError(e.to_string()))),
+      Err(e) => return Err(to_error(e.into())),
+    };
+
+    let reply = match c.vote(GrpcVoteRequest { value }).await {
+      Ok(reply) => reply.into_inner(),
+      Err(e) => return Err(to_error(e.into())),
     };
 
-    Ok(result)
+    self.decode_reply(&reply.value)
   }
 
-  #[doc = " Send an InstallSnapshot RPC to the target."]
-  fn install_snapshot(
+  // 快照文件按固定大小分块通过客户端流发送，发送前先查询接收方已经收到的长度，
+  // 上一次传输中断时从该位置继续发送
+  async fn full_snapshot(
     &mut self,
-    _rpc: crate::raft::InstallSnapshotRequest<C>,
+    vote: Vote<TypeConfig>,
+    snapshot: Snapshot<TypeConfig>,
+    cancel: impl Future<Output = ReplicationClosed> + OptionalSend + 'static,
     _option: RPCOption,
-  ) -> impl std::future::Future<
-    Output = Result<
-      crate::raft::InstallSnapshotResponse<C>,
-      RPCError<C, RaftError<C, crate::error::InstallSnapshotError>>,
-    >,
-  > + Send {
-    todo!()
+  ) -> Result<SnapshotResponse<TypeConfig>, StreamingError<TypeConfig>> {
+    let mut c = match self.c().await {
+      Ok(conn) => conn,
+      Err(e) => return Err(to_streaming_error(e)),
+    };
+
+    let snapshot_id = snapshot.meta.snapshot_id.clone();
+    let offset = match c
+      .snapshot_offset(SnapshotOffsetRequest {
+        snapshot_id: snapshot_id.clone(),
+      })
+      .await
+    {
+      Ok(reply) => reply.into_inner().offset,
+      Err(e) => return Err(to_streaming_error(e.into())),
+    };
+
+    let meta = match serialize(&(vote, snapshot.meta.clone())) {
+      Ok(data) => data,
+      Err(e) => return Err(to_streaming_error(e.into())),
+    };
+
+    let (chunk_sx, chunk_rx) = mpsc::channel(4);
+    let file = *snapshot.snapshot;
+    tokio::task::spawn_blocking(move || {
+      if let Err(e) = send_snapshot_chunks(&file, &snapshot_id, offset, meta, &chunk_sx) {
+        error!(
+          "Failed to read snapshot file {:?}, snapshot id:{}, {}",
+          file.path,
+          snapshot_id,
+          e
+        );
+      }
+    });
+
+    let reply = tokio::select! {
+      closed = cancel => return Err(StreamingError::Closed(closed)),
+      reply = c.snapshot(ReceiverStream::new(chunk_rx)) => match reply {
+        Ok(reply) => reply.into_inner(),
+        Err(e) => return Err(to_streaming_error(e.into())),
+      },
+    };
+
+    let result: Result<SnapshotResponse<TypeConfig>, Fatal<TypeConfig>> =
+      match deserialize(&reply.value) {
+        Ok(data) => data,
+        Err(e) => return Err(to_streaming_error(e.into())),
+      };
+    result.map_err(|e| to_streaming_error(CommonError::CommonError(e.to_string())))
   }
+}
 
-  #[doc = " Send a RequestVote RPC to the target."]
-  fn vote(
-    &mut self,
-    rpc: VoteRequest<C>,
-    option: RPCOption,
-  ) -> impl std::future::Future<Output = Result<VoteResponse<C>, RPCError<C, RaftError<C>>>> + Send
-  {
-    todo!()
+// 从 offset 开始读取快照文件并逐个发送分块，最后一个分块携带 vote 和快照元数据。
+// 读取失败时直接结束流，接收方会因为长度不一致而拒绝本次传输
+fn send_snapshot_chunks(
+  file: &SnapshotFile,
+  snapshot_id: &str,
+  mut offset: u64,
+  meta: Vec<u8>,
+  chunk_sx: &mpsc::Sender<SnapshotChunk>,
+) -> Result<(), PlacementCenterError> {
+  let total_size = file.size()?;
+  loop {
+    let data = if offset < total_size {
+      file.read_chunk(offset)?
+    } else {
+      Vec::new()
+    };
+    let next_offset = offset + data.len() as u64;
+    let done = next_offset >= total_size;
+    let chunk = SnapshotChunk {
+      snapshot_id: snapshot_id.to_string(),
+      offset,
+      checksum: chunk_checksum(&data),
+      data,
+      done,
+      total_size,
+      meta: if done { meta.clone() } else { Vec::new() },
+    };
+
+    // 接收端已经关闭时停止读取
+    if chunk_sx.blocking_send(chunk).is_err() || done {
+      return Ok(());
+    }
+    offset = next_offset;
   }
 }
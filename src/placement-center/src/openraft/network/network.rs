@@ -3,7 +3,7 @@ use std::sync::Arc;
 use grpc_clients::pool::ClientPool;
 use openraft::RaftNetworkFactory;
 
-use crate::openraft::typeconfig::TypeConfig;
+use crate::openraft::{raft_node::Node, typeconfig::TypeConfig};
 
 use super::connection::NetworkConnection;
 
@@ -20,12 +20,9 @@ impl Network {
 impl RaftNetworkFactory<TypeConfig> for Network {
   type Network = NetworkConnection;
 
+  // 连接在每次发送请求时从客户端池中获取，这里只记录目标节点的地址
   #[tracing::instrument(level = "debug", skip_all)]
-  fn new_client(
-    &mut self,
-    target: C::NodeId,
-    node: &C::Node,
-  ) -> impl std::future::Future<Output = Self::Network> + Send {
-    todo!()
+  async fn new_client(&mut self, target: u64, node: &Node) -> Self::Network {
+    NetworkConnection::new(target, node.rpc_addr.clone(), self.client_pool.clone())
   }
 }
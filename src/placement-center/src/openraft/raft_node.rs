@@ -1,8 +1,17 @@
-use std::{collections::BTreeMap, fmt::Display};
+use std::{collections::BTreeMap, fmt::Display, sync::Arc};
 
 use common_base::config::placement_center::placement_center_conf;
+use grpc_clients::pool::ClientPool;
+use log::{error, info};
+use openraft::{Config, Raft};
 
-use super::typeconfig::TypeConfig;
+use crate::route::DataRoute;
+
+use super::{
+  network::network::Network,
+  store::{new_storage, snapshot::snapshot_dir},
+  typeconfig::TypeConfig,
+};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Default)]
 pub struct Node {
@@ -26,8 +35,89 @@ pub mod typ {
   pub type Entry = openraft::Entry<TypeConfig>;
 }
 
+/// 创建 Raft 节点：Raft 日志与状态机元数据存放在独立的 RocksDB 实例中，
+/// 业务数据由状态机通过 `DataRoute` 写入 `RocksDBEngine`
+pub async fn create_raft_node(
+  client_pool: Arc<ClientPool>,
+  route: Arc<DataRoute>,
+) -> Raft<TypeConfig> {
+  let config = Config {
+    heartbeat_interval: 250,
+    election_timeout_min: 299,
+    ..Default::default()
+  };
+  let config = Arc::new(config.validate().unwrap());
+
+  let conf = placement_center_conf();
+  let path = format!("{}/_raft_rocksdb", conf.rocksdb.data_path);
+  let (log_store, state_machine_store) =
+    new_storage(&path, snapshot_dir(&conf.rocksdb.data_path), route).await;
+
+  let network = Network::new(client_pool);
+
+  match Raft::new(
+    conf.node.node_id,
+    config,
+    network,
+    log_store,
+    state_machine_store,
+  )
+  .await
+  {
+    Ok(raft) => raft,
+    Err(e) => {
+      panic!("Failed to initialize raft node, error message:{}", e);
+    }
+  }
+}
+
+/// 根据配置中的节点列表初始化集群，只由节点 ID 最小的节点执行初始化
 pub async fn start_openraft_node(raft_node: Raft<TypeConfig>) {
   let conf = placement_center_conf();
   let mut nodes = BTreeMap::new();
-  for (node_id, addr) in conf.node.nodes.clone() {}
+  for (node_id, addr) in conf.node.nodes.clone() {
+    let node_id: u64 = match node_id.parse() {
+      Ok(id) => id,
+      Err(e) => {
+        error!("Invalid node id {} in configuration, {}", node_id, e);
+        continue;
+      }
+    };
+    let rpc_addr = match addr.as_str() {
+      Some(addr) => addr.to_string(),
+      None => addr.to_string().replace("\"", ""),
+    };
+    nodes.insert(node_id, Node { node_id, rpc_addr });
+  }
+
+  let init_node_id = match nodes.keys().min() {
+    Some(id) => *id,
+    None => {
+      error!("No placement center nodes are configured, raft cluster can not be initialized");
+      return;
+    }
+  };
+
+  if init_node_id != conf.node.node_id {
+    return;
+  }
+
+  // 已经初始化过的集群再次初始化会返回 NotAllowed，直接忽略即可
+  match raft_node.is_initialized().await {
+    Ok(true) => return,
+    Ok(false) => {}
+    Err(e) => {
+      error!("Failed to check raft initialization state, {}", e);
+      return;
+    }
+  }
+
+  match raft_node.initialize(nodes.clone()).await {
+    Ok(_) => {
+      info!("Raft cluster was initialized successfully, nodes:{:?}", nodes);
+    }
+    Err(e) => {
+      error!("Failed to initialize raft cluster, {}", e);
+    }
+  }
 }
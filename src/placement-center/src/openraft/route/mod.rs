@@ -1,6 +1,21 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct AppResponseData {
-  pub value: Option<Vec<u8>>,
+use crate::route::data::StorageData;
+
+/// 写入 Raft 日志的业务数据，由状态机通过 `DataRoute` 分发处理
+pub type AppRequestData = StorageData;
+
+/// 状态机应用一条日志的结果，通过 `client_write` 的响应返回给提交写请求的 gRPC 接口
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AppResponseData {
+  /// 应用成功，没有需要返回的数据。空日志和成员变更日志也返回该结果
+  Ok,
+  /// 应用成功，携带写请求的响应数据，例如 protobuf 编码后的 `TxnReply`
+  Value(Vec<u8>),
+  /// 写入成功，携带 key 写入之前的值，key 原本不存在时为 None
+  PrevValue(Option<Vec<u8>>),
+  /// 要修改的数据不存在，日志没有产生任何修改
+  NotFound,
+  /// 写入与已经应用的数据冲突，日志没有产生任何修改，例如绑定的租约已经被撤销
+  Conflict(String),
 }
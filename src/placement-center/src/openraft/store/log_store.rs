@@ -1,8 +1,8 @@
-use std::{ops::RangeBounds, sync::Arc};
+use std::{fmt::Debug, ops::RangeBounds, sync::Arc};
 
 use openraft::{
-  AnyError, Entry, ErrorSubject, ErrorVerb, LogId, LogState, NodeId, RaftLogReader, StorageError,
-  Vote,
+  AnyError, Entry, ErrorSubject, ErrorVerb, LogId, LogState, NodeId, OptionalSend, RaftLogReader,
+  StorageError, Vote,
   storage::{IOFlushed, RaftLogStorage},
 };
 use rocksdb::{ColumnFamily, DB, Direction};
@@ -228,12 +228,12 @@ impl RaftLogReader<TypeConfig> for LogStore {
   /// - `range`: 日志条目的范围，实现了 `RangeBounds<u64>` 特征。
   ///
   /// # 返回值
-  /// - `impl StorageResult<Vec<Entry<TypeConfig>>>`: 包含日志条目的 `Vec` 的结果。
+  /// - `StorageResult<Vec<Entry<TypeConfig>>>`: 包含日志条目的 `Vec` 的结果。
   /// 如果读取过程中发生错误，返回相应的 `StorageError`。
   async fn try_get_log_entries<RB: RangeBounds<u64> + Clone + Debug + OptionalSend>(
     &mut self,
     range: RB,
-  ) -> impl StorageResult<Vec<Entry<TypeConfig>>> {
+  ) -> StorageResult<Vec<Entry<TypeConfig>>> {
     // 根据范围的起始边界确定起始ID
     let start = match range.start_bound() {
       // 如果范围是包含起始值的，将起始值转换为二进制
@@ -421,7 +421,7 @@ impl RaftLogStorage<TypeConfig> for LogStore {
     // 将指定日志ID的索引转换为二进制格式，作为删除范围的起始点
     let from = id_to_bin(log_id.index);
     // 将一个较大的数值转换为二进制格式，作为删除范围的结束点
-    let to = id_to_bin(Oxff_ff_ff_ff_ff_ff_ff_ff);
+    let to = id_to_bin(0xff_ff_ff_ff_ff_ff_ff_ff);
 
     // 在 "_raft_logs" 列族中删除指定范围的日志条目
     // 如果删除过程中发生错误，将其转换为 `StorageError` 并返回
@@ -444,7 +444,7 @@ impl RaftLogStorage<TypeConfig> for LogStore {
   #[tracing::instrument(level = "debug", skip(self))]
   async fn purge(&mut self, log_id: LogId<NodeId>) -> StorageResult<()> {
     // 记录调试日志，指示要删除的日志范围
-    tracing::debug!("delete_log: [0,{:?]}]", log_id);
+    tracing::debug!("delete_log: [0,{:?}]", log_id);
 
     // 设置最后一次清除的日志ID
     self.set_last_purged_(log_id)?;
@@ -1,14 +1,21 @@
-use std::{path::Path, sync::Arc};
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
-use bincode::config::BigEndian;
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use log_store::LogStore;
-use openraft::{SnapshotMeta, StorageError};
+use openraft::{LogId, NodeId, SnapshotMeta, StorageError, StoredMembership};
+use rocksdb::{ColumnFamilyDescriptor, DB, Options};
 use serde::{Deserialize, Serialize};
-use tokio::io::AsyncReadExt;
+use state_machine_store::StateMachineStore;
+
+use crate::route::DataRoute;
 
 use super::typeconfig::TypeConfig;
 
 pub mod log_store;
+pub mod snapshot;
 pub mod state_machine_store;
 
 /// 储存返回结果
@@ -19,8 +26,16 @@ type StorageResult<T> = Result<T, StorageError<TypeConfig>>;
 pub struct StoredSnapshot {
   pub meta: SnapshotMeta<TypeConfig>,
 
-  /// 快照数据
-  pub data: Vec<u8>,
+  /// 快照数据所在的文件
+  pub file: PathBuf,
+}
+
+/// 状态机的应用进度，保存在业务数据所在的 RocksDB 中。
+/// 重启或安装快照后，不大于 `last_applied_log_id` 的业务日志已经写入数据目录，不会再次应用
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StoredAppliedState {
+  pub last_applied_log_id: Option<LogId<NodeId>>,
+  pub last_membership: StoredMembership<TypeConfig>,
 }
 
 /// 大端字节序（Big Endian），也称为网络字节序，是一种在多字节数据存储和传输时，将最高有效字节（MSB, Most Significant Byte）存放在最低内存地址，
@@ -63,10 +78,28 @@ fn bin_to_id(buf: &[u8]) -> u64 {
   (&buf[0..8]).read_u64::<BigEndian>().unwrap()
 }
 
+/// 创建 Raft 的日志存储与状态机存储，两者共用同一个 RocksDB 实例的不同列族
 pub(crate) async fn new_storage<P: AsRef<Path>>(
   db_path: P,
+  snapshot_dir: PathBuf,
   route: Arc<DataRoute>,
 ) -> (LogStore, StateMachineStore) {
+  let mut db_opts = Options::default();
+  db_opts.create_missing_column_families(true);
+  db_opts.create_if_missing(true);
+
+  let store = ColumnFamilyDescriptor::new(cf_raft_store(), Options::default());
+  let logs = ColumnFamilyDescriptor::new(cf_raft_logs(), Options::default());
+
+  let db = DB::open_cf_descriptors(&db_opts, db_path, vec![store, logs]).unwrap();
+  let db = Arc::new(db);
+
+  let log_store = LogStore { db: db.clone() };
+  let sm_store = StateMachineStore::new(db, snapshot_dir, route)
+    .await
+    .unwrap();
+
+  (log_store, sm_store)
 }
 
 /// 返回Raft存储的列族名称
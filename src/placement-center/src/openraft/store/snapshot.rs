@@ -0,0 +1,249 @@
+use std::{
+  fs::{self, File, OpenOptions},
+  io::{Read, Seek, SeekFrom, Write},
+  path::{Path, PathBuf},
+};
+
+use protocol::SnapshotChunk;
+
+use crate::core::error::PlacementCenterError;
+
+/// 每个快照分块的大小，需要小于 gRPC 默认 4MB 的消息大小限制
+pub const SNAPSHOT_CHUNK_SIZE: usize = 1024 * 1024;
+
+const SNAPSHOT_FILE_SUFFIX: &str = "snap";
+const RECEIVING_FILE_SUFFIX: &str = "part";
+
+/// 保存在本地文件中的快照数据
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotFile {
+  pub path: PathBuf,
+}
+
+impl SnapshotFile {
+  pub fn new(path: PathBuf) -> Self {
+    SnapshotFile { path }
+  }
+
+  pub fn size(&self) -> Result<u64, PlacementCenterError> {
+    Ok(fs::metadata(&self.path).map_err(common_error)?.len())
+  }
+
+  pub fn read(&self) -> Result<Vec<u8>, PlacementCenterError> {
+    fs::read(&self.path).map_err(common_error)
+  }
+
+  // 读取从 offset 开始的一个分块，返回空数据表示已经读到文件末尾
+  pub fn read_chunk(&self, offset: u64) -> Result<Vec<u8>, PlacementCenterError> {
+    let mut file = File::open(&self.path).map_err(common_error)?;
+    file.seek(SeekFrom::Start(offset)).map_err(common_error)?;
+    let mut data = Vec::with_capacity(SNAPSHOT_CHUNK_SIZE);
+    file
+      .take(SNAPSHOT_CHUNK_SIZE as u64)
+      .read_to_end(&mut data)
+      .map_err(common_error)?;
+    Ok(data)
+  }
+}
+
+/// Raft 快照文件所在的目录
+pub fn snapshot_dir(data_path: &str) -> PathBuf {
+  Path::new(data_path).join("_raft_snapshot")
+}
+
+pub fn snapshot_file_path(dir: &Path, snapshot_id: &str) -> PathBuf {
+  dir.join(format!("{}.{}", snapshot_id, SNAPSHOT_FILE_SUFFIX))
+}
+
+// 由 write 写入临时文件后再重命名，避免进程崩溃时留下不完整的快照文件
+pub fn write_snapshot_file<F>(
+  dir: &Path,
+  snapshot_id: &str,
+  write: F,
+) -> Result<SnapshotFile, PlacementCenterError>
+where
+  F: FnOnce(&Path) -> Result<(), PlacementCenterError>,
+{
+  fs::create_dir_all(dir).map_err(common_error)?;
+  let path = snapshot_file_path(dir, snapshot_id);
+  let tmp_path = path.with_extension("tmp");
+  write(&tmp_path)?;
+  fs::rename(&tmp_path, &path).map_err(common_error)?;
+  Ok(SnapshotFile::new(path))
+}
+
+// 创建一个空的快照文件用于接收快照数据，同一时间只会接收一个快照
+pub fn create_receiving_file(dir: &Path) -> Result<SnapshotFile, PlacementCenterError> {
+  fs::create_dir_all(dir).map_err(common_error)?;
+  let path = dir.join(format!("receiving.{}", RECEIVING_FILE_SUFFIX));
+  File::create(&path).map_err(common_error)?;
+  Ok(SnapshotFile::new(path))
+}
+
+// 删除当前快照之外的快照文件，正在接收的临时文件由 SnapshotReceiver 负责清理
+pub fn remove_snapshot_files_except(dir: &Path, keep: &Path) -> Result<(), PlacementCenterError> {
+  for entry in fs::read_dir(dir).map_err(common_error)? {
+    let path = entry.map_err(common_error)?.path();
+    let is_snapshot = path
+      .extension()
+      .is_some_and(|ext| ext == SNAPSHOT_FILE_SUFFIX);
+    if is_snapshot && path != keep {
+      fs::remove_file(&path).map_err(common_error)?;
+    }
+  }
+  Ok(())
+}
+
+pub fn chunk_checksum(data: &[u8]) -> u32 {
+  crc32fast::hash(data)
+}
+
+/// 接收方按快照 ID 将分块追加写入临时文件，传输中断后发送方可以从已写入的长度继续发送
+pub struct SnapshotReceiver {
+  dir: PathBuf,
+}
+
+impl SnapshotReceiver {
+  pub fn new(dir: PathBuf) -> Self {
+    SnapshotReceiver { dir }
+  }
+
+  // 返回已经收到的数据长度，同时清理其它快照遗留的临时文件
+  pub fn offset(&self, snapshot_id: &str) -> Result<u64, PlacementCenterError> {
+    let part_path = self.part_path(snapshot_id)?;
+    fs::create_dir_all(&self.dir).map_err(common_error)?;
+    for entry in fs::read_dir(&self.dir).map_err(common_error)? {
+      let path = entry.map_err(common_error)?.path();
+      let is_part = path
+        .extension()
+        .is_some_and(|ext| ext == RECEIVING_FILE_SUFFIX);
+      if is_part && path != part_path {
+        fs::remove_file(&path).map_err(common_error)?;
+      }
+    }
+
+    match fs::metadata(&part_path) {
+      Ok(metadata) => Ok(metadata.len()),
+      Err(_) => Ok(0),
+    }
+  }
+
+  // 分块必须从已写入的长度处开始，校验失败的分块不会写入文件
+  pub fn write_chunk(&self, chunk: &SnapshotChunk) -> Result<(), PlacementCenterError> {
+    let part_path = self.part_path(&chunk.snapshot_id)?;
+    if chunk_checksum(&chunk.data) != chunk.checksum {
+      return Err(PlacementCenterError::SnapshotChecksumMismatch(
+        chunk.snapshot_id.clone(),
+        chunk.offset,
+      ));
+    }
+
+    fs::create_dir_all(&self.dir).map_err(common_error)?;
+    let mut file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&part_path)
+      .map_err(common_error)?;
+    let received = file.metadata().map_err(common_error)?.len();
+    if received != chunk.offset {
+      return Err(PlacementCenterError::SnapshotOffsetMismatch(
+        chunk.snapshot_id.clone(),
+        received,
+        chunk.offset,
+      ));
+    }
+
+    file.write_all(&chunk.data).map_err(common_error)?;
+    file.sync_data().map_err(common_error)?;
+    Ok(())
+  }
+
+  // 所有分块接收完成，校验长度后移动为正式的快照文件
+  pub fn finish(
+    &self,
+    snapshot_id: &str,
+    total_size: u64,
+  ) -> Result<SnapshotFile, PlacementCenterError> {
+    let part_path = self.part_path(snapshot_id)?;
+    let received = match fs::metadata(&part_path) {
+      Ok(metadata) => metadata.len(),
+      Err(_) => 0,
+    };
+    if received != total_size {
+      return Err(PlacementCenterError::SnapshotSizeMismatch(
+        snapshot_id.to_string(),
+        total_size,
+        received,
+      ));
+    }
+
+    let path = snapshot_file_path(&self.dir, snapshot_id);
+    fs::rename(&part_path, &path).map_err(common_error)?;
+    Ok(SnapshotFile::new(path))
+  }
+
+  fn part_path(&self, snapshot_id: &str) -> Result<PathBuf, PlacementCenterError> {
+    // 快照 ID 来自其它节点，用作文件名之前需要检查，避免写到快照目录之外
+    if snapshot_id.is_empty()
+      || snapshot_id.starts_with('.')
+      || snapshot_id.contains(['/', '\\'])
+    {
+      return Err(PlacementCenterError::SnapshotIdInvalid(
+        snapshot_id.to_string(),
+      ));
+    }
+    Ok(
+      self
+        .dir
+        .join(format!("{}.{}", snapshot_id, RECEIVING_FILE_SUFFIX)),
+    )
+  }
+}
+
+fn common_error(e: std::io::Error) -> PlacementCenterError {
+  PlacementCenterError::CommonError(e.into())
+}
+
+#[cfg(test)]
+mod tests {
+  use protocol::SnapshotChunk;
+
+  use super::{SnapshotReceiver, chunk_checksum};
+
+  fn chunk(offset: u64, data: &[u8]) -> SnapshotChunk {
+    SnapshotChunk {
+      snapshot_id: "1-10-0".to_string(),
+      offset,
+      data: data.to_vec(),
+      checksum: chunk_checksum(data),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn snapshot_receiver_resume_test() {
+    let dir = std::env::temp_dir().join("nezamq-test/snapshot_receiver_resume_test");
+    let _ = std::fs::remove_dir_all(&dir);
+    let receiver = SnapshotReceiver::new(dir.clone());
+
+    assert_eq!(receiver.offset("1-10-0").unwrap(), 0);
+    receiver.write_chunk(&chunk(0, b"hello ")).unwrap();
+
+    // 传输中断后从已收到的长度继续发送，重复或跳过的分块会被拒绝
+    assert_eq!(receiver.offset("1-10-0").unwrap(), 6);
+    assert!(receiver.write_chunk(&chunk(0, b"hello ")).is_err());
+    assert!(receiver.write_chunk(&chunk(8, b"world")).is_err());
+
+    // 校验和不一致的分块不会写入
+    let mut broken = chunk(6, b"world");
+    broken.checksum += 1;
+    assert!(receiver.write_chunk(&broken).is_err());
+    assert_eq!(receiver.offset("1-10-0").unwrap(), 6);
+
+    receiver.write_chunk(&chunk(6, b"world")).unwrap();
+    assert!(receiver.finish("1-10-0", 12).is_err());
+    let file = receiver.finish("1-10-0", 11).unwrap();
+    assert_eq!(file.read().unwrap(), b"hello world".to_vec());
+    assert!(receiver.offset("../1-10-0").is_err());
+  }
+}
@@ -1,17 +1,26 @@
-use std::sync::Arc;
+use std::{fs, path::PathBuf, sync::Arc};
 
+use log::warn;
 use openraft::{
-  AnyError, ErrorSubject, ErrorVerb, LogId, NodeId, RaftSnapshotBuilder, Snapshot, SnapshotMeta,
-  StorageError, StoredMembership, storage::RaftStateMachine,
+  AnyError, EntryPayload, ErrorSubject, ErrorVerb, LogId, NodeId, OptionalSend,
+  RaftSnapshotBuilder, Snapshot, SnapshotMeta, StorageError, StoredMembership,
+  storage::RaftStateMachine,
 };
-use rocksdb::{BoundColumnFamily, DB};
+use rocksdb::{ColumnFamily, DB};
 
 use crate::{
-  openraft::{route::AppResponseData, typeconfig::TypeConfig},
+  openraft::{raft_node::typ, route::AppResponseData, typeconfig::TypeConfig},
   route::DataRoute,
+  storage::batch::StorageBatch,
 };
 
-use super::{StorageResult, StoredSnapshot, cf_raft_store};
+use super::{
+  StorageResult, StoredAppliedState, StoredSnapshot, cf_raft_store,
+  snapshot::{
+    SnapshotFile, create_receiving_file, remove_snapshot_files_except, snapshot_file_path,
+    write_snapshot_file,
+  },
+};
 
 /// 表示状态机存储的结构体，用于存储状态机的相关数据。
 ///
@@ -19,6 +28,8 @@ use super::{StorageResult, StoredSnapshot, cf_raft_store};
 /// - `data`: 状态机的数据，包含最后应用的日志ID、最后成员信息和数据路由。
 /// - `snapshot_idx`: 快照的索引，用于跟踪快照的版本。
 /// - `db`: 一个指向RocksDB数据库的原子引用计数指针，用于持久化存储。
+/// - `snapshot_dir`: 快照文件所在的目录。
+/// - `data_applied_index`: 数据目录中已经应用到的日志索引。
 #[derive(Debug, Clone)]
 pub struct StateMachineStore {
   // 状态机的数据，包含最后应用的日志ID、最后成员信息和数据路由
@@ -27,6 +38,10 @@ pub struct StateMachineStore {
   snapshot_idx: u64,
   // 一个指向RocksDB数据库的原子引用计数指针，用于持久化存储
   db: Arc<DB>,
+  // 快照文件所在的目录，RocksDB 中只保存快照元数据和文件路径
+  snapshot_dir: PathBuf,
+  // 数据目录中已经应用到的日志索引，不大于该索引的业务日志不再重复应用
+  data_applied_index: u64,
 }
 
 /// 表示状态机数据的结构体，包含状态机所需的核心信息。
@@ -50,8 +65,7 @@ impl RaftSnapshotBuilder<TypeConfig> for StateMachineStore {
     let last_applied_log = self.data.last_applied_log_id;
     let last_membership = self.data.last_membership.clone();
 
-    let kv_json = self.data.route.build_snapshot();
-
+    self.snapshot_idx += 1;
     let snapshot_id = if let Some(last) = last_applied_log {
       format!("{}-{}-{}", last.leader_id, last.index, self.snapshot_idx)
     } else {
@@ -64,16 +78,23 @@ impl RaftSnapshotBuilder<TypeConfig> for StateMachineStore {
       snapshot_id,
     };
 
+    // 快照数据写入独立的文件，发送给其它节点时按分块读取
+    let file = write_snapshot_file(&self.snapshot_dir, &meta.snapshot_id, |path| {
+      self.data.route.build_snapshot(path)
+    })
+    .map_err(|e| StorageError::write_snapshot(Some(meta.signature()), &e))?;
+
     let snapshot = StoredSnapshot {
       meta: meta.clone(),
-      data: kv_json.clone(),
+      file: file.path.clone(),
     };
 
     self.set_current_snapshot_(snapshot)?;
+    self.remove_stale_snapshots_(&meta, &file);
 
     Ok(Snapshot {
       meta,
-      snapshot: Box::new(kv_json),
+      snapshot: Box::new(file),
     })
   }
 }
@@ -81,6 +102,7 @@ impl RaftSnapshotBuilder<TypeConfig> for StateMachineStore {
 impl StateMachineStore {
   pub async fn new(
     db: Arc<DB>,
+    snapshot_dir: PathBuf,
     route: Arc<DataRoute>,
   ) -> Result<StateMachineStore, StorageError<TypeConfig>> {
     let mut sm = Self {
@@ -91,11 +113,21 @@ impl StateMachineStore {
       },
       snapshot_idx: 0,
       db,
+      snapshot_dir,
+      data_applied_index: 0,
     };
 
-    let snapshot = sm.get_current_snapshot_()?;
-    if let Some(snap) = snapshot {
-      sm.update_state_machine_(snap).await?;
+    // 业务数据直接写入持久化的 RocksDB，重启后从数据目录中恢复应用进度，
+    // 避免 openraft 将已经应用的日志再次应用到数据上
+    sm.load_applied_state_()?;
+
+    // 数据目录的应用进度落后于快照时才需要使用快照恢复，否则恢复快照会丢弃更新的数据，
+    // 还需要重新应用快照之后的全部日志
+    match sm.get_current_snapshot_()? {
+      Some(snap) if snap.meta.last_log_id > sm.data.last_applied_log_id => {
+        sm.update_state_machine_(snap).await?;
+      }
+      _ => {}
     }
 
     Ok(sm)
@@ -108,28 +140,104 @@ impl StateMachineStore {
     self.data.last_applied_log_id = snapshot.meta.last_log_id;
     self.data.last_membership = snapshot.meta.last_membership.clone();
 
-    match self.data.route.recover_snapshot(snapshot.data) {
-      Ok(_) => Ok(()),
+    let last_index = snapshot
+      .meta
+      .last_log_id
+      .map(|log_id| log_id.index)
+      .unwrap_or(0);
+    if let Err(e) = self.data.route.recover_snapshot(&snapshot.file, last_index) {
+      return Err(StorageError::read(&e));
+    }
+
+    // 快照数据生成时状态机可能还在继续应用日志，数据中记录的应用进度可能比快照元数据更新，
+    // openraft 之后会从快照元数据的位置重新应用日志，已经包含在数据中的日志需要跳过
+    let applied = self.read_applied_state_()?;
+    let applied_index = applied
+      .last_applied_log_id
+      .map(|log_id| log_id.index)
+      .unwrap_or(0);
+    self.data_applied_index = applied_index.max(last_index);
+    self
+      .data
+      .route
+      .restore_applied_index(self.data_applied_index);
+    Ok(())
+  }
+
+  fn read_applied_state_(&self) -> StorageResult<StoredAppliedState> {
+    match self.data.route.applied_state() {
+      Ok(Some(data)) => serde_json::from_slice(&data).map_err(|e| StorageError::read(&e)),
+      Ok(None) => Ok(StoredAppliedState::default()),
       Err(e) => Err(StorageError::read(&e)),
     }
   }
 
+  fn load_applied_state_(&mut self) -> StorageResult<()> {
+    let applied = self.read_applied_state_()?;
+    self.data_applied_index = applied
+      .last_applied_log_id
+      .map(|log_id| log_id.index)
+      .unwrap_or(0);
+    self.data.last_applied_log_id = applied.last_applied_log_id;
+    self.data.last_membership = applied.last_membership;
+    // Watch 历史只保存在内存中，重启前已经应用的变更无法补发
+    self
+      .data
+      .route
+      .restore_applied_index(self.data_applied_index);
+    Ok(())
+  }
+
+  // 应用进度与这条日志产生的业务数据在同一个 WriteBatch 中提交
+  fn save_applied_state_(&mut self, batch: StorageBatch) -> StorageResult<()> {
+    let applied = StoredAppliedState {
+      last_applied_log_id: self.data.last_applied_log_id,
+      last_membership: self.data.last_membership.clone(),
+    };
+    let data = serde_json::to_vec(&applied).map_err(|e| StorageError::write(&e))?;
+    if let Err(e) = self.data.route.commit_batch(batch, data) {
+      return Err(StorageError::write(&e));
+    }
+    if let Some(log_id) = self.data.last_applied_log_id {
+      self.data_applied_index = log_id.index;
+    }
+    Ok(())
+  }
+
+  // 当前快照已经持久化，旧的快照文件清理失败不影响快照本身，下次生成快照时会再次清理
+  fn remove_stale_snapshots_(&self, meta: &SnapshotMeta<TypeConfig>, file: &SnapshotFile) {
+    if let Err(e) = remove_snapshot_files_except(&self.snapshot_dir, &file.path) {
+      warn!(
+        "Failed to remove stale snapshot files, snapshot id:{}, {}",
+        meta.snapshot_id, e
+      );
+    }
+  }
+
   fn get_current_snapshot_(&self) -> StorageResult<Option<StoredSnapshot>> {
     Ok(
       self
         .db
-        .get_cf(&self.store, b"snapshot")
+        .get_cf(self.store(), b"snapshot")
         .map_err(|e| StorageError::read(&e))?
         .and_then(|v| serde_json::from_slice(&v).ok()),
     )
   }
 
   fn set_current_snapshot_(&self, snap: StoredSnapshot) -> StorageResult<()> {
-    self.db.put_cf(
-      &self.store(),
-      b"snapshot",
-      serde_json::to_vec(&snap).unwrap().as_slice(),
-    )
+    self
+      .db
+      .put_cf(
+        self.store(),
+        b"snapshot",
+        serde_json::to_vec(&snap).unwrap().as_slice(),
+      )
+      .map_err(|e| StorageError::write_snapshot(Some(snap.meta.signature()), &e))?;
+    self.flush(
+      ErrorSubject::Snapshot(Some(snap.meta.signature())),
+      ErrorVerb::Write,
+    )?;
+    Ok(())
   }
 
   fn flush(
@@ -144,7 +252,7 @@ impl StateMachineStore {
     Ok(())
   }
 
-  fn store(&self) -> Arc<BoundColumnFamily> {
+  fn store(&self) -> &ColumnFamily {
     self.db.cf_handle(&cf_raft_store()).unwrap()
   }
 }
@@ -166,32 +274,98 @@ impl RaftStateMachine<TypeConfig> for StateMachineStore {
     I: IntoIterator<Item = typ::Entry> + OptionalSend,
     I::IntoIter: OptionalSend,
   {
-    todo!()
+    let entries = entries.into_iter();
+    let mut replies = Vec::with_capacity(entries.size_hint().0);
+
+    for ent in entries {
+      // 记录最后应用的日志ID
+      self.data.last_applied_log_id = Some(ent.log_id);
+      // 已经写入数据目录的日志不再应用，例如重启或安装快照后 openraft 重新应用的日志
+      let applied = ent.log_id.index <= self.data_applied_index;
+      let mut batch = StorageBatch::new();
+
+      let reply = match ent.payload {
+        EntryPayload::Blank => AppResponseData::Ok,
+        EntryPayload::Normal(_) if applied => AppResponseData::Ok,
+        // 业务数据交给 DataRoute 按数据类型分发到对应的存储模块，应用结果返回给提交日志的请求
+        EntryPayload::Normal(req) => self
+          .data
+          .route
+          .route(req, ent.log_id.index, &mut batch)
+          .map_err(|e| {
+            StorageError::new(
+              ErrorSubject::StateMachine,
+              ErrorVerb::Write,
+              AnyError::new(&e),
+            )
+          })?,
+        // 成员变更日志，更新最后的成员信息
+        EntryPayload::Membership(mem) => {
+          self.data.last_membership = StoredMembership::new(Some(ent.log_id), mem);
+          AppResponseData::Ok
+        }
+      };
+
+      // 业务数据和应用进度一起提交，进程在任何时刻退出都不会出现数据已经写入而应用进度没有推进的情况，
+      // 重启后不会重复应用同一条日志。跳过的日志不记录，避免数据目录中的应用进度回退
+      if !applied {
+        self.save_applied_state_(batch)?;
+      }
+      replies.push(reply);
+    }
+
+    Ok(replies)
   }
 
-  fn get_snapshot_builder(
-    &mut self,
-  ) -> impl std::future::Future<Output = Self::SnapshotBuilder> + Send {
-    todo!()
+  async fn get_snapshot_builder(&mut self) -> Self::SnapshotBuilder {
+    self.clone()
   }
 
-  fn begin_receiving_snapshot(
+  // 按分块接收快照时先写入一个空的临时文件，接收完成后再由 install_snapshot 安装
+  async fn begin_receiving_snapshot(
     &mut self,
-  ) -> impl std::future::Future<Output = Result<C::SnapshotData, StorageError<C>>> + Send {
-    todo!()
+  ) -> Result<Box<SnapshotFile>, StorageError<TypeConfig>> {
+    let file = create_receiving_file(&self.snapshot_dir)
+      .map_err(|e| StorageError::write_snapshot(None, &e))?;
+    Ok(Box::new(file))
   }
 
-  fn install_snapshot(
+  async fn install_snapshot(
     &mut self,
-    meta: &SnapshotMeta<C>,
-    snapshot: C::SnapshotData,
-  ) -> impl std::future::Future<Output = Result<(), StorageError<C>>> + Send {
-    todo!()
+    meta: &SnapshotMeta<TypeConfig>,
+    snapshot: Box<SnapshotFile>,
+  ) -> Result<(), StorageError<TypeConfig>> {
+    // 接收到的快照文件移动到以快照 ID 命名的位置，与本节点生成的快照保持一致
+    let path = snapshot_file_path(&self.snapshot_dir, &meta.snapshot_id);
+    if snapshot.path != path {
+      fs::rename(&snapshot.path, &path)
+        .map_err(|e| StorageError::write_snapshot(Some(meta.signature()), &e))?;
+    }
+    let file = SnapshotFile::new(path);
+
+    let new_snapshot = StoredSnapshot {
+      meta: meta.clone(),
+      file: file.path.clone(),
+    };
+
+    self.update_state_machine_(new_snapshot.clone()).await?;
+    self.set_current_snapshot_(new_snapshot)?;
+    self.remove_stale_snapshots_(meta, &file);
+
+    Ok(())
   }
 
-  fn get_current_snapshot(
+  async fn get_current_snapshot(
     &mut self,
-  ) -> impl std::future::Future<Output = Result<Option<Snapshot<C>>, StorageError<C>>> + Send {
-    todo!()
+  ) -> Result<Option<Snapshot<TypeConfig>>, StorageError<TypeConfig>> {
+    let snapshot = match self.get_current_snapshot_()? {
+      Some(snapshot) => snapshot,
+      None => return Ok(None),
+    };
+
+    Ok(Some(Snapshot {
+      meta: snapshot.meta,
+      snapshot: Box::new(SnapshotFile::new(snapshot.file)),
+    }))
   }
 }
@@ -0,0 +1,343 @@
+use std::sync::Arc;
+
+use prost::Message;
+use protocol::amqp::{
+  AmqpBinding, AmqpExchange, AmqpQueue, AmqpVhost, DeleteBindingRequest, DeleteExchangeRequest,
+  DeleteQueueRequest, DeleteVhostRequest, ExchangeType,
+};
+
+use crate::{
+  core::error::PlacementCenterError,
+  openraft::route::AppResponseData,
+  storage::{
+    amqp::{
+      binding::{AmqpBindingStorage, Binding},
+      exchange::{AmqpExchangeStorage, Exchange},
+      queue::{AmqpQueueStorage, Queue},
+      vhost::{AmqpVhostStorage, Vhost},
+    },
+    batch::StorageBatch,
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// AMQP Broker 共享的拓扑信息，创建时间由提交请求的节点填写，保证所有副本写入的数据一致
+#[derive(Debug, Clone)]
+pub struct DataRouteAmqp {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl DataRouteAmqp {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    DataRouteAmqp {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn create_vhost(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = AmqpVhost::decode(value.as_ref())?;
+    let vhost_storage = AmqpVhostStorage::new(self.rocksdb_engine_handler.clone());
+    if vhost_storage.get(&req.cluster_name, &req.vhost)?.is_some() {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::AmqpVhostAlreadyExists(req.cluster_name, req.vhost).to_string(),
+      ));
+    }
+
+    vhost_storage.save(
+      batch,
+      &Vhost {
+        cluster_name: req.cluster_name,
+        vhost: req.vhost,
+        create_time: req.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 删除 vhost 以及其中的绑定、exchange 和 queue，所有删除在同一个批次中提交
+  pub fn delete_vhost(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteVhostRequest::decode(value.as_ref())?;
+    let vhost_storage = AmqpVhostStorage::new(self.rocksdb_engine_handler.clone());
+    if vhost_storage.get(&req.cluster_name, &req.vhost)?.is_none() {
+      return Ok(AppResponseData::NotFound);
+    }
+
+    let binding_storage = AmqpBindingStorage::new(self.rocksdb_engine_handler.clone());
+    for binding in binding_storage.list(&req.cluster_name, &req.vhost, "", "")? {
+      binding_storage.delete(batch, &binding)?;
+    }
+    let exchange_storage = AmqpExchangeStorage::new(self.rocksdb_engine_handler.clone());
+    for exchange in exchange_storage.list(&req.cluster_name, &req.vhost)? {
+      exchange_storage.delete(
+        batch,
+        &req.cluster_name,
+        &req.vhost,
+        &exchange.exchange_name,
+      )?;
+    }
+    let queue_storage = AmqpQueueStorage::new(self.rocksdb_engine_handler.clone());
+    for queue in queue_storage.list(&req.cluster_name, &req.vhost)? {
+      queue_storage.delete(batch, &req.cluster_name, &req.vhost, &queue.queue_name)?;
+    }
+    vhost_storage.delete(batch, &req.cluster_name, &req.vhost)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 与 exchange.declare 一致，重复声明时属性必须与已有的 exchange 相同
+  pub fn create_exchange(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = AmqpExchange::decode(value.as_ref())?;
+    let vhost_storage = AmqpVhostStorage::new(self.rocksdb_engine_handler.clone());
+    if vhost_storage.get(&req.cluster_name, &req.vhost)?.is_none() {
+      return Ok(AppResponseData::NotFound);
+    }
+
+    let exchange_storage = AmqpExchangeStorage::new(self.rocksdb_engine_handler.clone());
+    let existing = exchange_storage.get(&req.cluster_name, &req.vhost, &req.exchange_name)?;
+    let mut exchange = Exchange {
+      exchange_type: req.exchange_type().as_str_name().to_string(),
+      cluster_name: req.cluster_name,
+      vhost: req.vhost,
+      exchange_name: req.exchange_name,
+      durable: req.durable,
+      auto_delete: req.auto_delete,
+      internal: req.internal,
+      arguments: req.arguments.into_iter().collect(),
+      create_time: req.create_time,
+    };
+    if let Some(existing) = existing {
+      exchange.create_time = existing.create_time;
+      if existing != exchange {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::AmqpExchangeMismatch(exchange.vhost, exchange.exchange_name)
+            .to_string(),
+        ));
+      }
+      return Ok(AppResponseData::Ok);
+    }
+
+    exchange_storage.save(batch, &exchange)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // exchange 以及它的所有绑定在同一个批次中删除
+  pub fn delete_exchange(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteExchangeRequest::decode(value.as_ref())?;
+    let exchange_storage = AmqpExchangeStorage::new(self.rocksdb_engine_handler.clone());
+    if exchange_storage
+      .get(&req.cluster_name, &req.vhost, &req.exchange_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+
+    let binding_storage = AmqpBindingStorage::new(self.rocksdb_engine_handler.clone());
+    for binding in binding_storage.list(&req.cluster_name, &req.vhost, &req.exchange_name, "")? {
+      binding_storage.delete(batch, &binding)?;
+    }
+    exchange_storage.delete(batch, &req.cluster_name, &req.vhost, &req.exchange_name)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 与 queue.declare 一致，重复声明时属性必须与已有的 queue 相同
+  pub fn create_queue(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = AmqpQueue::decode(value.as_ref())?;
+    let vhost_storage = AmqpVhostStorage::new(self.rocksdb_engine_handler.clone());
+    if vhost_storage.get(&req.cluster_name, &req.vhost)?.is_none() {
+      return Ok(AppResponseData::NotFound);
+    }
+
+    let queue_storage = AmqpQueueStorage::new(self.rocksdb_engine_handler.clone());
+    let existing = queue_storage.get(&req.cluster_name, &req.vhost, &req.queue_name)?;
+    let mut queue = Queue {
+      cluster_name: req.cluster_name,
+      vhost: req.vhost,
+      queue_name: req.queue_name,
+      durable: req.durable,
+      exclusive: req.exclusive,
+      auto_delete: req.auto_delete,
+      arguments: req.arguments.into_iter().collect(),
+      create_time: req.create_time,
+    };
+    if let Some(existing) = existing {
+      queue.create_time = existing.create_time;
+      if existing != queue {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::AmqpQueueMismatch(queue.vhost, queue.queue_name).to_string(),
+        ));
+      }
+      return Ok(AppResponseData::Ok);
+    }
+
+    queue_storage.save(batch, &queue)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // queue 以及它的所有绑定在同一个批次中删除
+  pub fn delete_queue(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteQueueRequest::decode(value.as_ref())?;
+    let queue_storage = AmqpQueueStorage::new(self.rocksdb_engine_handler.clone());
+    if queue_storage
+      .get(&req.cluster_name, &req.vhost, &req.queue_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+
+    let binding_storage = AmqpBindingStorage::new(self.rocksdb_engine_handler.clone());
+    for binding in binding_storage.list(&req.cluster_name, &req.vhost, "", &req.queue_name)? {
+      binding_storage.delete(batch, &binding)?;
+    }
+    queue_storage.delete(batch, &req.cluster_name, &req.vhost, &req.queue_name)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // exchange 或 queue 不存在时返回 Conflict，绑定已经存在时保留原有的创建时间
+  pub fn create_binding(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = AmqpBinding::decode(value.as_ref())?;
+    let vhost_storage = AmqpVhostStorage::new(self.rocksdb_engine_handler.clone());
+    if vhost_storage.get(&req.cluster_name, &req.vhost)?.is_none() {
+      return Ok(AppResponseData::NotFound);
+    }
+    let exchange_storage = AmqpExchangeStorage::new(self.rocksdb_engine_handler.clone());
+    if exchange_storage
+      .get(&req.cluster_name, &req.vhost, &req.exchange_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::AmqpExchangeNotFound(req.vhost, req.exchange_name).to_string(),
+      ));
+    }
+    let queue_storage = AmqpQueueStorage::new(self.rocksdb_engine_handler.clone());
+    if queue_storage
+      .get(&req.cluster_name, &req.vhost, &req.queue_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::AmqpQueueNotFound(req.vhost, req.queue_name).to_string(),
+      ));
+    }
+
+    let binding_storage = AmqpBindingStorage::new(self.rocksdb_engine_handler.clone());
+    let create_time = match binding_storage.get(
+      &req.cluster_name,
+      &req.vhost,
+      &req.exchange_name,
+      &req.queue_name,
+      &req.routing_key,
+    )? {
+      Some(binding) => binding.create_time,
+      None => req.create_time,
+    };
+    binding_storage.save(
+      batch,
+      &Binding {
+        cluster_name: req.cluster_name,
+        vhost: req.vhost,
+        exchange_name: req.exchange_name,
+        queue_name: req.queue_name,
+        routing_key: req.routing_key,
+        arguments: req.arguments.into_iter().collect(),
+        create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_binding(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteBindingRequest::decode(value.as_ref())?;
+    let binding_storage = AmqpBindingStorage::new(self.rocksdb_engine_handler.clone());
+    let binding = match binding_storage.get(
+      &req.cluster_name,
+      &req.vhost,
+      &req.exchange_name,
+      &req.queue_name,
+      &req.routing_key,
+    )? {
+      Some(binding) => binding,
+      None => return Ok(AppResponseData::NotFound),
+    };
+    binding_storage.delete(batch, &binding)?;
+    Ok(AppResponseData::Ok)
+  }
+}
+
+pub fn vhost_info(vhost: Vhost) -> AmqpVhost {
+  AmqpVhost {
+    cluster_name: vhost.cluster_name,
+    vhost: vhost.vhost,
+    create_time: vhost.create_time,
+  }
+}
+
+pub fn exchange_info(exchange: Exchange) -> AmqpExchange {
+  AmqpExchange {
+    cluster_name: exchange.cluster_name,
+    vhost: exchange.vhost,
+    exchange_name: exchange.exchange_name,
+    exchange_type: ExchangeType::from_str_name(&exchange.exchange_type)
+      .unwrap_or_default()
+      .into(),
+    durable: exchange.durable,
+    auto_delete: exchange.auto_delete,
+    internal: exchange.internal,
+    arguments: exchange.arguments.into_iter().collect(),
+    create_time: exchange.create_time,
+  }
+}
+
+pub fn queue_info(queue: Queue) -> AmqpQueue {
+  AmqpQueue {
+    cluster_name: queue.cluster_name,
+    vhost: queue.vhost,
+    queue_name: queue.queue_name,
+    durable: queue.durable,
+    exclusive: queue.exclusive,
+    auto_delete: queue.auto_delete,
+    arguments: queue.arguments.into_iter().collect(),
+    create_time: queue.create_time,
+  }
+}
+
+pub fn binding_info(binding: Binding) -> AmqpBinding {
+  AmqpBinding {
+    cluster_name: binding.cluster_name,
+    vhost: binding.vhost,
+    exchange_name: binding.exchange_name,
+    queue_name: binding.queue_name,
+    routing_key: binding.routing_key,
+    arguments: binding.arguments.into_iter().collect(),
+    create_time: binding.create_time,
+  }
+}
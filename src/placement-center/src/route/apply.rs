@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use openraft::{
+  Raft,
+  error::ForwardToLeader,
+  raft::{ClientWriteResponse, ReadPolicy},
+};
+use protocol::ReadConsistency;
+use tokio::time::timeout;
+
+use crate::{core::error::PlacementCenterError, openraft::typeconfig::TypeConfig};
+
+use super::data::StorageData;
+
+// 写请求等待 Raft 日志提交并应用到状态机的超时时间（秒）
+const RAFT_WRITE_TIMEOUT_SEC: u64 = 10;
+
+// 线性一致读等待 Leader 确认并且本地状态机追上读索引的超时时间（秒）
+const RAFT_READ_TIMEOUT_SEC: u64 = 5;
+
+/// 写请求的统一入口：将数据作为 Raft 日志提交，等待多数派确认并应用到状态机
+#[derive(Clone)]
+pub struct RaftMachineApply {
+  pub openraft_node: Raft<TypeConfig>,
+}
+
+impl RaftMachineApply {
+  pub fn new(openraft_node: Raft<TypeConfig>) -> Self {
+    RaftMachineApply { openraft_node }
+  }
+
+  pub async fn client_write(
+    &self,
+    data: StorageData,
+  ) -> Result<ClientWriteResponse<TypeConfig>, PlacementCenterError> {
+    match timeout(
+      Duration::from_secs(RAFT_WRITE_TIMEOUT_SEC),
+      self.openraft_node.client_write(data),
+    )
+    .await
+    {
+      Ok(Ok(reply)) => Ok(reply),
+      Ok(Err(e)) => Err(PlacementCenterError::OpenRaftError(e.to_string())),
+      Err(_) => Err(PlacementCenterError::RaftLogCommitTimeout(
+        RAFT_WRITE_TIMEOUT_SEC,
+      )),
+    }
+  }
+
+  /// 读取本地状态机之前按一致性级别进行确认，返回后本地状态机至少已经应用到读索引。
+  /// 当前节点不是 Leader 时返回的错误中包含 Leader 地址，客户端据此重定向请求
+  pub async fn ensure_read_consistency(
+    &self,
+    consistency: ReadConsistency,
+  ) -> Result<(), PlacementCenterError> {
+    let read_policy = match consistency {
+      ReadConsistency::Linearizable => ReadPolicy::ReadIndex,
+      ReadConsistency::Lease => ReadPolicy::LeaseRead,
+      ReadConsistency::Stale => return Ok(()),
+    };
+
+    match timeout(
+      Duration::from_secs(RAFT_READ_TIMEOUT_SEC),
+      self.openraft_node.ensure_linearizable(read_policy),
+    )
+    .await
+    {
+      Ok(Ok(_)) => Ok(()),
+      Ok(Err(e)) => Err(PlacementCenterError::OpenRaftError(e.to_string())),
+      Err(_) => Err(PlacementCenterError::RaftReadIndexTimeout(
+        RAFT_READ_TIMEOUT_SEC,
+      )),
+    }
+  }
+
+  // 当前节点是否为 Leader
+  pub fn is_leader(&self) -> bool {
+    let metrics = self.openraft_node.metrics().borrow().clone();
+    metrics.current_leader == Some(metrics.id)
+  }
+
+  // 状态机已经应用到的日志索引，即 KV 的当前版本号
+  pub fn last_applied_index(&self) -> u64 {
+    let metrics = self.openraft_node.metrics().borrow().clone();
+    metrics.last_applied.map_or(0, |log_id| log_id.index)
+  }
+
+  /// 只能由 Leader 处理的非写请求使用，当前节点不是 Leader 时返回与写请求相同的转发错误，
+  /// 错误中包含 Leader 地址，客户端据此重定向请求
+  pub fn ensure_leader(&self) -> Result<(), PlacementCenterError> {
+    let metrics = self.openraft_node.metrics().borrow().clone();
+    if metrics.current_leader == Some(metrics.id) {
+      return Ok(());
+    }
+
+    let leader_node = metrics.current_leader.and_then(|leader_id| {
+      metrics
+        .membership_config
+        .membership()
+        .get_node(&leader_id)
+        .cloned()
+    });
+    let forward = ForwardToLeader::<TypeConfig> {
+      leader_id: metrics.current_leader,
+      leader_node,
+    };
+    Err(PlacementCenterError::OpenRaftError(forward.to_string()))
+  }
+
+  pub fn current_leader(&self) -> Option<u64> {
+    self.openraft_node.metrics().borrow().current_leader
+  }
+}
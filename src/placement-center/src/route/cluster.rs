@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+use prost::Message;
+use protocol::{ClusterInfo, ClusterType, NodeInfo, RemoveExpiredNodeData, UnRegisterNodeRequest};
+
+use crate::{
+  core::error::PlacementCenterError,
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    cluster::{Cluster, ClusterStorage, Node},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// 集群和节点的注册信息，创建时间由提交请求的节点填写，保证所有副本写入的数据一致
+#[derive(Debug, Clone)]
+pub struct DataRouteCluster {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl DataRouteCluster {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    DataRouteCluster {
+      rocksdb_engine_handler,
+    }
+  }
+
+  // 集群已经存在时保留原有的创建时间，类型不一致时拒绝
+  pub fn add_cluster(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = ClusterInfo::decode(value.as_ref())?;
+    let cluster_type = req.cluster_type().as_str_name().to_string();
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    if let Some(cluster) = cluster_storage.get_cluster(&req.cluster_name)? {
+      if cluster.cluster_type != cluster_type {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::ClusterTypeMismatch(
+            req.cluster_name,
+            cluster.cluster_type,
+            cluster_type,
+          )
+          .to_string(),
+        ));
+      }
+      return Ok(AppResponseData::Ok);
+    }
+
+    cluster_storage.save_cluster(
+      batch,
+      &Cluster {
+        cluster_name: req.cluster_name,
+        cluster_type,
+        create_time: req.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_cluster(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = ClusterInfo::decode(value.as_ref())?;
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    if cluster_storage.get_cluster(&req.cluster_name)?.is_none() {
+      return Ok(AppResponseData::NotFound);
+    }
+    cluster_storage.delete_cluster(batch, &req.cluster_name)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 节点重复注册时覆盖原有的地址和扩展信息，并记录本次注册的日志索引
+  pub fn add_node(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = NodeInfo::decode(value.as_ref())?;
+    let cluster_type = req.cluster_type().as_str_name().to_string();
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    let cluster = match cluster_storage.get_cluster(&req.cluster_name)? {
+      Some(cluster) => cluster,
+      None => return Ok(AppResponseData::NotFound),
+    };
+    if cluster.cluster_type != cluster_type {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::ClusterTypeMismatch(
+          req.cluster_name,
+          cluster.cluster_type,
+          cluster_type,
+        )
+        .to_string(),
+      ));
+    }
+
+    cluster_storage.save_node(
+      batch,
+      &Node {
+        cluster_name: req.cluster_name,
+        cluster_type,
+        node_id: req.node_id,
+        node_ip: req.node_ip,
+        node_inner_addr: req.node_inner_addr,
+        extend_info: req.extend_info,
+        create_time: req.create_time,
+        register_index: log_index,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_node(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = UnRegisterNodeRequest::decode(value.as_ref())?;
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    if cluster_storage
+      .get_node(&req.cluster_name, req.node_id)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+    cluster_storage.delete_node(batch, &req.cluster_name, req.node_id)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // Leader 检查之后节点重新注册时返回 Conflict，不移除节点
+  pub fn remove_expired_node(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = RemoveExpiredNodeData::decode(value.as_ref())?;
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    let node = match cluster_storage.get_node(&req.cluster_name, req.node_id)? {
+      Some(node) => node,
+      None => return Ok(AppResponseData::NotFound),
+    };
+    if node.register_index != req.register_index {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::NodeReRegistered(req.cluster_name, req.node_id).to_string(),
+      ));
+    }
+    cluster_storage.delete_node(batch, &req.cluster_name, req.node_id)?;
+    Ok(AppResponseData::Ok)
+  }
+}
+
+pub fn cluster_info(cluster: Cluster) -> ClusterInfo {
+  ClusterInfo {
+    cluster_name: cluster.cluster_name,
+    cluster_type: cluster_type(&cluster.cluster_type).into(),
+    create_time: cluster.create_time,
+  }
+}
+
+pub fn node_info(node: Node) -> NodeInfo {
+  NodeInfo {
+    cluster_name: node.cluster_name,
+    cluster_type: cluster_type(&node.cluster_type).into(),
+    node_id: node.node_id,
+    node_ip: node.node_ip,
+    node_inner_addr: node.node_inner_addr,
+    extend_info: node.extend_info,
+    create_time: node.create_time,
+  }
+}
+
+fn cluster_type(name: &str) -> ClusterType {
+  ClusterType::from_str_name(name).unwrap_or_default()
+}
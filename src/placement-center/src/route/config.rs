@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use prost::Message;
+use protocol::config::{
+  ClusterConfigInfo, DeleteClusterConfigRequest, SetClusterConfigReply, SetClusterConfigRequest,
+};
+
+use crate::{
+  core::{
+    config::{ClusterConfigEvent, ClusterConfigEventType, ClusterConfigWatchHub},
+    error::PlacementCenterError,
+  },
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    config::{ClusterConfig, ClusterConfigStorage},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// 集群的动态配置，使用 Raft 日志索引作为配置的版本号，修改后通知订阅了该集群配置的节点
+#[derive(Debug, Clone)]
+pub struct DataRouteConfig {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  config_watch_hub: Arc<ClusterConfigWatchHub>,
+}
+
+impl DataRouteConfig {
+  pub fn new(
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    config_watch_hub: Arc<ClusterConfigWatchHub>,
+  ) -> Self {
+    DataRouteConfig {
+      rocksdb_engine_handler,
+      config_watch_hub,
+    }
+  }
+
+  // expected_version 与当前版本号不一致时返回 Conflict，配置不存在时当前版本号视为 0
+  pub fn set_config(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = SetClusterConfigRequest::decode(value.as_ref())?;
+    let config_storage = ClusterConfigStorage::new(self.rocksdb_engine_handler.clone());
+    if req.expected_version > 0 {
+      let current_version = match config_storage.get(&req.cluster_name, &req.resource_type)? {
+        Some(config) => config.version,
+        None => 0,
+      };
+      if current_version != req.expected_version {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::ClusterConfigVersionMismatch(
+            req.resource_type,
+            req.expected_version,
+            current_version,
+          )
+          .to_string(),
+        ));
+      }
+    }
+
+    let config = ClusterConfig {
+      cluster_name: req.cluster_name,
+      resource_type: req.resource_type,
+      config: req.config,
+      version: log_index,
+      update_time: req.update_time,
+    };
+    config_storage.save(batch, &config)?;
+
+    self.config_watch_hub.publish(ClusterConfigEvent {
+      event_type: ClusterConfigEventType::Set,
+      config,
+    });
+    let reply = SetClusterConfigReply { version: log_index };
+    Ok(AppResponseData::Value(
+      SetClusterConfigReply::encode_to_vec(&reply),
+    ))
+  }
+
+  pub fn delete_config(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteClusterConfigRequest::decode(value.as_ref())?;
+    let config_storage = ClusterConfigStorage::new(self.rocksdb_engine_handler.clone());
+    if config_storage
+      .get(&req.cluster_name, &req.resource_type)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+    config_storage.delete(batch, &req.cluster_name, &req.resource_type)?;
+
+    self.config_watch_hub.publish(ClusterConfigEvent {
+      event_type: ClusterConfigEventType::Delete,
+      config: ClusterConfig {
+        cluster_name: req.cluster_name,
+        resource_type: req.resource_type,
+        version: log_index,
+        ..Default::default()
+      },
+    });
+    Ok(AppResponseData::Ok)
+  }
+}
+
+pub fn cluster_config_info(config: ClusterConfig) -> ClusterConfigInfo {
+  ClusterConfigInfo {
+    cluster_name: config.cluster_name,
+    resource_type: config.resource_type,
+    config: config.config,
+    version: config.version,
+    update_time: config.update_time,
+  }
+}
@@ -2,8 +2,8 @@ use core::fmt;
 
 use serde::{Deserialize, Serialize};
 
-// 为结构体添加派生属性，支持调试输出、序列化和反序列化
-#[derive(Debug, Deserialize, Serialize)]
+// 为结构体添加派生属性，支持调试输出、克隆、序列化和反序列化
+#[derive(Debug, Clone, Deserialize, Serialize)]
 /// 表示存储数据的结构体，包含数据类型和数据值
 pub struct StorageData {
   /// 数据类型，使用 `StorageDataType` 枚举
@@ -42,8 +42,8 @@ impl fmt::Display for StorageData {
   }
 }
 
-// 为枚举添加派生属性，支持调试输出、序列化和反序列化
-#[derive(Debug, Deserialize, Serialize)]
+// 为枚举添加派生属性，支持调试输出、克隆、序列化和反序列化
+#[derive(Debug, Clone, Deserialize, Serialize)]
 /// 表示存储数据的类型的枚举，包含不同的操作类型
 pub enum StorageDataType {
   // KV 操作类型
@@ -51,6 +51,16 @@ pub enum StorageDataType {
   KvSet,
   /// 表示删除键值对的操作
   KvDelete,
+  /// 表示按条件原子地执行多个键值对操作的事务
+  KvTxn,
+  /// 表示压缩键值对历史版本的操作
+  KvCompact,
+
+  // 租约操作类型
+  /// 表示授予租约的操作
+  LeaseGrant,
+  /// 表示撤销租约的操作，绑定在租约上的键值对会被一并删除
+  LeaseRevoke,
 
   // 集群操作类型
   /// 表示向集群中添加节点的操作
@@ -61,4 +71,85 @@ pub enum StorageDataType {
   ClusterAddCluster,
   /// 表示删除一个集群的操作
   ClusterDeleteCluster,
+
+  // MQTT 操作类型
+  /// 表示创建 MQTT 用户的操作
+  MqttCreateUser,
+  /// 表示删除 MQTT 用户的操作
+  MqttDeleteUser,
+  /// 表示创建 MQTT 主题的操作
+  MqttCreateTopic,
+  /// 表示删除 MQTT 主题的操作
+  MqttDeleteTopic,
+  /// 表示设置或清除 MQTT 主题保留消息的操作
+  MqttSetTopicRetainMessage,
+  /// 表示保存 MQTT 持久会话的操作
+  MqttSaveSession,
+  /// 表示删除 MQTT 持久会话的操作
+  MqttDeleteSession,
+  /// 表示创建或覆盖 MQTT ACL 规则的操作
+  MqttCreateAcl,
+  /// 表示删除 MQTT ACL 规则的操作
+  MqttDeleteAcl,
+  /// 表示将对象加入 MQTT 黑名单的操作
+  MqttCreateBlacklist,
+  /// 表示将对象移出 MQTT 黑名单的操作
+  MqttDeleteBlacklist,
+
+  // Journal 操作类型
+  /// 表示创建 Journal 分片以及第一个 Segment 的操作
+  JournalCreateShard,
+  /// 表示修改 Segment 状态的操作
+  JournalUpdateSegmentStatus,
+  /// 表示封存活跃的 Segment 并创建下一个 Segment 的操作
+  JournalSealSegment,
+  /// 表示批量提交消费组 offset 的操作
+  JournalCommitOffset,
+
+  // AMQP 操作类型
+  /// 表示创建 AMQP vhost 的操作
+  AmqpCreateVhost,
+  /// 表示删除 AMQP vhost 以及其中所有对象的操作
+  AmqpDeleteVhost,
+  /// 表示声明 AMQP exchange 的操作
+  AmqpCreateExchange,
+  /// 表示删除 AMQP exchange 以及以它为源的绑定的操作
+  AmqpDeleteExchange,
+  /// 表示声明 AMQP queue 的操作
+  AmqpCreateQueue,
+  /// 表示删除 AMQP queue 以及绑定到它的绑定的操作
+  AmqpDeleteQueue,
+  /// 表示创建或覆盖 AMQP 绑定的操作
+  AmqpCreateBinding,
+  /// 表示删除 AMQP 绑定的操作
+  AmqpDeleteBinding,
+
+  // Schema 操作类型
+  /// 表示注册 schema 新版本的操作，版本号由状态机分配
+  SchemaRegister,
+  /// 表示删除 subject 以及它的所有版本和绑定的操作
+  SchemaDelete,
+  /// 表示修改 subject 兼容模式的操作
+  SchemaSetCompatibility,
+  /// 表示将 MQTT 主题或 Journal 分片绑定到 subject 的操作
+  SchemaBind,
+  /// 表示解除 MQTT 主题或 Journal 分片与 subject 绑定的操作
+  SchemaUnbind,
+
+  // 动态配置操作类型
+  /// 表示设置集群动态配置的操作，版本号为该操作的日志索引
+  ClusterConfigSet,
+  /// 表示删除集群动态配置的操作
+  ClusterConfigDelete,
+
+  // ID 生成操作类型
+  /// 表示从 namespace 中分配一段连续 ID 的操作，区间由状态机分配
+  IdAllocate,
+
+  /// 表示由 Leader 清理已经失效的 MQTT 黑名单的操作
+  MqttPurgeBlacklist,
+  /// 表示由 Leader 移除心跳超时节点的操作，节点在检查之后重新注册时不会被移除
+  ClusterRemoveExpiredNode,
+  /// 表示由 Leader 清除已经过期的 MQTT 保留消息的操作
+  MqttPurgeRetainMessage,
 }
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use prost::Message;
+use protocol::id::{AllocateIdsData, AllocateIdsReply};
+
+use crate::{
+  core::error::PlacementCenterError,
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    id::{IdNamespace, IdStorage},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+// ID 从 1 开始分配，0 留给调用方表示未分配
+pub const FIRST_ID: u64 = 1;
+
+/// 分布式 ID 生成，所有副本按相同的日志顺序推进高水位，分配结果一致
+#[derive(Debug, Clone)]
+pub struct DataRouteId {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl DataRouteId {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    DataRouteId {
+      rocksdb_engine_handler,
+    }
+  }
+
+  // 分配到的区间通过响应数据返回，剩余的 ID 不足时返回 Conflict
+  pub fn allocate(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let data = AllocateIdsData::decode(value.as_ref())?;
+    let req = match data.request {
+      Some(req) => req,
+      // 数据不完整时不能返回错误，否则状态机会停止应用后续的日志
+      None => {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::RequestParamsNotEmpty("request".to_string()).to_string(),
+        ));
+      }
+    };
+    let id_storage = IdStorage::new(self.rocksdb_engine_handler.clone());
+    let start_id = match id_storage.get(&req.namespace)? {
+      Some(namespace) => namespace.next_id,
+      None => FIRST_ID,
+    };
+    let end_id = match start_id.checked_add(req.count) {
+      Some(end_id) => end_id,
+      None => {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::IdExhausted(req.namespace, req.count, start_id).to_string(),
+        ));
+      }
+    };
+
+    id_storage.save(
+      batch,
+      &IdNamespace {
+        namespace: req.namespace,
+        next_id: end_id,
+        update_time: data.update_time,
+      },
+    )?;
+    let reply = AllocateIdsReply { start_id, end_id };
+    Ok(AppResponseData::Value(AllocateIdsReply::encode_to_vec(
+      &reply,
+    )))
+  }
+}
@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use prost::Message;
+use protocol::journal::{
+  CommitOffsetRequest, CreateShardData, SealSegmentData, SegmentInfo, SegmentStatus, ShardInfo,
+  ShardOffsetInfo, UpdateSegmentStatusRequest,
+};
+
+use crate::{
+  core::{error::PlacementCenterError, journal::is_valid_segment_status_transition},
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    journal::{
+      offset::{OffsetStorage, ShardOffset},
+      segment::{Segment, SegmentStorage},
+      shard::{Shard, ShardStorage},
+    },
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// Journal 分片和 Segment 的元数据，新 Segment 的副本由提交请求的 Leader 选择，
+/// 状态机只负责校验状态变化，保证所有副本写入的数据一致
+#[derive(Debug, Clone)]
+pub struct DataRouteJournal {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl DataRouteJournal {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    DataRouteJournal {
+      rocksdb_engine_handler,
+    }
+  }
+
+  // 创建分片以及分片的第一个 Segment
+  pub fn create_shard(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = CreateShardData::decode(value.as_ref())?;
+    let (shard, segment) = match (req.shard, req.segment) {
+      (Some(shard), Some(segment)) => (shard, segment),
+      // 数据不完整时不能返回错误，否则状态机会停止应用后续的日志
+      _ => {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::RequestParamsNotEmpty("shard or segment".to_string()).to_string(),
+        ));
+      }
+    };
+
+    let shard_storage = ShardStorage::new(self.rocksdb_engine_handler.clone());
+    if shard_storage
+      .get(&shard.cluster_name, &shard.namespace, &shard.shard_name)?
+      .is_some()
+    {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::JournalShardAlreadyExists(shard.namespace, shard.shard_name)
+          .to_string(),
+      ));
+    }
+
+    let segment_storage = SegmentStorage::new(self.rocksdb_engine_handler.clone());
+    segment_storage.save(batch, &segment_from(segment))?;
+    shard_storage.save(
+      batch,
+      &Shard {
+        cluster_name: shard.cluster_name,
+        namespace: shard.namespace,
+        shard_name: shard.shard_name,
+        replica_num: shard.replica_num,
+        active_segment_seq: shard.active_segment_seq,
+        create_time: shard.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 封存状态只能通过 seal_segment 设置，这里只处理其它的状态变化
+  pub fn update_segment_status(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = UpdateSegmentStatusRequest::decode(value.as_ref())?;
+    let segment_storage = SegmentStorage::new(self.rocksdb_engine_handler.clone());
+    let mut segment = match segment_storage.get(
+      &req.cluster_name,
+      &req.namespace,
+      &req.shard_name,
+      req.segment_seq,
+    )? {
+      Some(segment) => segment,
+      None => return Ok(AppResponseData::NotFound),
+    };
+
+    let status = segment_status(&segment.status);
+    if !is_valid_segment_status_transition(status, req.status()) {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::JournalSegmentStatusInvalid(
+          req.segment_seq,
+          segment.status,
+          req.status().as_str_name().to_string(),
+        )
+        .to_string(),
+      ));
+    }
+    segment.status = req.status().as_str_name().to_string();
+    segment_storage.save(batch, &segment)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 封存分片当前活跃的 Segment，并创建下一个 Segment 作为新的活跃 Segment，
+  // 封存的 Segment、新的 Segment 和分片在同一个批次中提交
+  pub fn seal_segment(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = SealSegmentData::decode(value.as_ref())?;
+    let (seal, next_segment) = match (req.seal, req.next_segment) {
+      (Some(seal), Some(next_segment)) => (seal, next_segment),
+      // 数据不完整时不能返回错误，否则状态机会停止应用后续的日志
+      _ => {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::RequestParamsNotEmpty("seal or next_segment".to_string())
+            .to_string(),
+        ));
+      }
+    };
+
+    let shard_storage = ShardStorage::new(self.rocksdb_engine_handler.clone());
+    let segment_storage = SegmentStorage::new(self.rocksdb_engine_handler.clone());
+    let mut shard =
+      match shard_storage.get(&seal.cluster_name, &seal.namespace, &seal.shard_name)? {
+        Some(shard) => shard,
+        None => return Ok(AppResponseData::NotFound),
+      };
+    if shard.active_segment_seq != seal.segment_seq {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::JournalSegmentNotActive(
+          seal.shard_name,
+          seal.segment_seq,
+          shard.active_segment_seq,
+        )
+        .to_string(),
+      ));
+    }
+    let mut segment = match segment_storage.get(
+      &seal.cluster_name,
+      &seal.namespace,
+      &seal.shard_name,
+      seal.segment_seq,
+    )? {
+      Some(segment) => segment,
+      None => return Ok(AppResponseData::NotFound),
+    };
+
+    let sealed = SegmentStatus::Sealed.as_str_name();
+    if !is_valid_segment_status_transition(segment_status(&segment.status), SegmentStatus::Sealed) {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::JournalSegmentStatusInvalid(
+          seal.segment_seq,
+          segment.status,
+          sealed.to_string(),
+        )
+        .to_string(),
+      ));
+    }
+    if seal.end_offset < segment.start_offset - 1 {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::JournalSegmentEndOffsetInvalid(
+          seal.segment_seq,
+          seal.end_offset,
+          segment.start_offset,
+        )
+        .to_string(),
+      ));
+    }
+
+    // 新 Segment 的序号和起始 offset 以状态机中的数据为准，溢出时不能在应用日志时 panic
+    let (next_seq, next_start_offset) = match (
+      seal.segment_seq.checked_add(1),
+      seal.end_offset.checked_add(1),
+    ) {
+      (Some(seq), Some(offset)) => (seq, offset),
+      _ => {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::JournalSegmentOverflow(seal.segment_seq, seal.end_offset)
+            .to_string(),
+        ));
+      }
+    };
+
+    segment.status = sealed.to_string();
+    segment.end_offset = seal.end_offset;
+    segment_storage.save(batch, &segment)?;
+
+    let mut next_segment = segment_from(next_segment);
+    next_segment.segment_seq = next_seq;
+    next_segment.start_offset = next_start_offset;
+    segment_storage.save(batch, &next_segment)?;
+
+    shard.active_segment_seq = next_segment.segment_seq;
+    shard_storage.save(batch, &shard)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 覆盖消费组在各个分片上的 offset，commit_time 由提交请求的节点填写，
+  // 同一个请求中的 offset 在同一个批次中提交
+  pub fn commit_offset(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = CommitOffsetRequest::decode(value.as_ref())?;
+    let offset_storage = OffsetStorage::new(self.rocksdb_engine_handler.clone());
+    for offset in req.offsets {
+      offset_storage.save(
+        batch,
+        &ShardOffset {
+          cluster_name: req.cluster_name.clone(),
+          group_name: req.group_name.clone(),
+          namespace: offset.namespace,
+          shard_name: offset.shard_name,
+          offset: offset.offset,
+          metadata: offset.metadata,
+          commit_time: offset.commit_time,
+        },
+      )?;
+    }
+    Ok(AppResponseData::Ok)
+  }
+}
+
+pub fn shard_info(shard: Shard) -> ShardInfo {
+  ShardInfo {
+    cluster_name: shard.cluster_name,
+    namespace: shard.namespace,
+    shard_name: shard.shard_name,
+    replica_num: shard.replica_num,
+    active_segment_seq: shard.active_segment_seq,
+    create_time: shard.create_time,
+  }
+}
+
+pub fn segment_info(segment: Segment) -> SegmentInfo {
+  SegmentInfo {
+    cluster_name: segment.cluster_name,
+    namespace: segment.namespace,
+    shard_name: segment.shard_name,
+    segment_seq: segment.segment_seq,
+    status: segment_status(&segment.status).into(),
+    leader: segment.leader,
+    replicas: segment.replicas,
+    start_offset: segment.start_offset,
+    end_offset: segment.end_offset,
+    create_time: segment.create_time,
+  }
+}
+
+pub fn shard_offset_info(offset: ShardOffset) -> ShardOffsetInfo {
+  ShardOffsetInfo {
+    namespace: offset.namespace,
+    shard_name: offset.shard_name,
+    offset: offset.offset,
+    metadata: offset.metadata,
+    commit_time: offset.commit_time,
+  }
+}
+
+fn segment_from(segment: SegmentInfo) -> Segment {
+  Segment {
+    status: segment.status().as_str_name().to_string(),
+    cluster_name: segment.cluster_name,
+    namespace: segment.namespace,
+    shard_name: segment.shard_name,
+    segment_seq: segment.segment_seq,
+    leader: segment.leader,
+    replicas: segment.replicas,
+    start_offset: segment.start_offset,
+    end_offset: segment.end_offset,
+    create_time: segment.create_time,
+  }
+}
+
+fn segment_status(name: &str) -> SegmentStatus {
+  SegmentStatus::from_str_name(name).unwrap_or_default()
+}
@@ -0,0 +1,293 @@
+use std::{cmp::Ordering, collections::HashMap, sync::Arc};
+
+use log::warn;
+use prost::Message;
+use protocol::{
+  CommonReply, CompactRequest, CompareResult, CompareTarget,
+  kv_v2::{
+    Compare, DeleteRequest, GetReply, ResponseOp, SetRequest, TxnReply, TxnRequest, request_op,
+    response_op,
+  },
+};
+
+use crate::{
+  core::{
+    error::PlacementCenterError,
+    watch::{KvWatchEvent, KvWatchEventType, KvWatchHub},
+  },
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    kv::{KvBatchOp, KvEntry, KvStorage, next_entry},
+    lease::{LeaseBindings, LeaseStorage},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// KV 写请求统一按 kv_v2 中的二进制消息解码，kv 中的同名消息字段编号一致，编码兼容
+#[derive(Debug, Clone)]
+pub struct DataRouteKv {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  kv_watch_hub: Arc<KvWatchHub>,
+}
+
+impl DataRouteKv {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>, kv_watch_hub: Arc<KvWatchHub>) -> Self {
+    DataRouteKv {
+      rocksdb_engine_handler,
+      kv_watch_hub,
+    }
+  }
+
+  pub fn set(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = SetRequest::decode(value.as_ref())?;
+    if !self.lease_exists(&req)? {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::LeaseNotFound(req.lease_id).to_string(),
+      ));
+    }
+
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    let prev_value = kv_storage.get(&req.key)?;
+    kv_storage.set(batch, req.key.clone(), req.value.clone(), log_index)?;
+    self.bind_lease(batch, vec![(req.key.clone(), req.lease_id)])?;
+
+    self.kv_watch_hub.publish(KvWatchEvent {
+      event_type: KvWatchEventType::Put,
+      key: req.key,
+      value: req.value,
+      index: log_index,
+    });
+    Ok(AppResponseData::PrevValue(prev_value))
+  }
+
+  // key 不存在时不写入删除记录，也不通知 Watch
+  pub fn delete(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteRequest::decode(value.as_ref())?;
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    let prev_value = match kv_storage.get(&req.key)? {
+      Some(value) => value,
+      None => return Ok(AppResponseData::NotFound),
+    };
+    kv_storage.delete(batch, req.key.clone(), log_index)?;
+    self.bind_lease(batch, vec![(req.key.clone(), 0)])?;
+
+    self.kv_watch_hub.publish(KvWatchEvent {
+      event_type: KvWatchEventType::Delete,
+      key: req.key,
+      value: Vec::new(),
+      index: log_index,
+    });
+    Ok(AppResponseData::PrevValue(Some(prev_value)))
+  }
+
+  // 事务中的操作按顺序执行，读操作可以读取到同一事务中之前的写操作，写操作在同一个 WriteBatch 中提交。
+  // 任意一个写操作的租约已经不存在时整个事务返回 Conflict，不写入任何数据
+  pub fn txn(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = TxnRequest::decode(value.as_ref())?;
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+
+    let mut succeeded = true;
+    for compare in req.compare.iter() {
+      if !self.compare(&kv_storage, compare)? {
+        succeeded = false;
+        break;
+      }
+    }
+    let ops = if succeeded { req.success } else { req.failure };
+
+    for op in ops.iter() {
+      let set = match &op.request {
+        Some(request_op::Request::Set(set)) => set,
+        _ => continue,
+      };
+      if !self.lease_exists(set)? {
+        return Ok(AppResponseData::Conflict(
+          PlacementCenterError::LeaseNotFound(set.lease_id).to_string(),
+        ));
+      }
+    }
+
+    // 事务中已经写入的 key 的最新状态，None 表示已经删除
+    let mut pending: HashMap<Vec<u8>, Option<KvEntry>> = HashMap::new();
+    let mut kv_ops = Vec::new();
+    let mut bindings = Vec::new();
+    let mut events = Vec::new();
+    let mut responses = Vec::with_capacity(ops.len());
+    for op in ops {
+      let response = match op.request {
+        Some(request_op::Request::Set(set)) => {
+          let prev = txn_entry(&kv_storage, &pending, &set.key)?;
+          let entry = next_entry(prev.as_ref(), set.value.clone(), log_index);
+          pending.insert(set.key.clone(), Some(entry));
+          kv_ops.push(KvBatchOp::Set(set.key.clone(), set.value.clone()));
+          bindings.push((set.key.clone(), set.lease_id));
+          events.push(KvWatchEvent {
+            event_type: KvWatchEventType::Put,
+            key: set.key,
+            value: set.value,
+            index: log_index,
+          });
+          response_op::Response::Set(CommonReply::default())
+        }
+        Some(request_op::Request::Delete(delete)) => {
+          // key 不存在时不产生删除事件
+          if txn_entry(&kv_storage, &pending, &delete.key)?.is_some() {
+            pending.insert(delete.key.clone(), None);
+            kv_ops.push(KvBatchOp::Delete(delete.key.clone()));
+            bindings.push((delete.key.clone(), 0));
+            events.push(KvWatchEvent {
+              event_type: KvWatchEventType::Delete,
+              key: delete.key,
+              value: Vec::new(),
+              index: log_index,
+            });
+          }
+          response_op::Response::Delete(CommonReply::default())
+        }
+        Some(request_op::Request::Get(get)) => {
+          let entry = txn_entry(&kv_storage, &pending, &get.key)?;
+          response_op::Response::Get(get_reply(entry))
+        }
+        None => continue,
+      };
+      responses.push(ResponseOp {
+        response: Some(response),
+      });
+    }
+
+    kv_storage.write(batch, kv_ops, log_index)?;
+    // 事务中全部 key 的租约绑定一起计算，与 key 的写入在同一个批次中提交，
+    // 多个 key 绑定到同一个租约时不会互相覆盖
+    self.bind_lease(batch, bindings)?;
+    for event in events {
+      self.kv_watch_hub.publish(event);
+    }
+
+    let reply = TxnReply {
+      succeeded,
+      responses,
+      revision: log_index,
+    };
+    Ok(AppResponseData::Value(TxnReply::encode_to_vec(&reply)))
+  }
+
+  pub fn compact(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = CompactRequest::decode(value.as_ref())?;
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    // 并发提交的压缩请求可能晚于更大版本的压缩被应用，不再重复压缩
+    let compacted = kv_storage.compact_revision()?;
+    if req.revision <= compacted {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::KvRevisionCompacted(req.revision, compacted).to_string(),
+      ));
+    }
+    kv_storage.compact(batch, req.revision)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  fn compare(
+    &self,
+    kv_storage: &KvStorage,
+    compare: &Compare,
+  ) -> Result<bool, PlacementCenterError> {
+    let entry = kv_storage.get_entry(&compare.key)?;
+    let ordering = match compare.target() {
+      CompareTarget::Value => match entry {
+        Some(entry) => entry.value.cmp(&compare.value),
+        None => return Ok(false),
+      },
+      CompareTarget::Exists => entry.is_some().cmp(&compare.exists),
+      CompareTarget::ModRevision => entry
+        .map_or(0, |entry| entry.mod_revision)
+        .cmp(&compare.mod_revision),
+    };
+
+    Ok(match compare.result() {
+      CompareResult::Equal => ordering == Ordering::Equal,
+      CompareResult::NotEqual => ordering != Ordering::Equal,
+      CompareResult::Greater => ordering == Ordering::Greater,
+      CompareResult::Less => ordering == Ordering::Less,
+    })
+  }
+
+  // 提交前已经校验过租约，租约在提交过程中被撤销时拒绝该写入，由调用方返回 Conflict
+  fn lease_exists(&self, req: &SetRequest) -> Result<bool, PlacementCenterError> {
+    if req.lease_id <= 0 {
+      return Ok(true);
+    }
+
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    if lease_storage.get(req.lease_id)?.is_some() {
+      return Ok(true);
+    }
+    warn!(
+      "Lease {} does not exist, set request for key {} is rejected",
+      req.lease_id,
+      String::from_utf8_lossy(&req.key)
+    );
+    Ok(false)
+  }
+
+  // 更新 key 与租约的绑定关系，lease_id 为 0 时解除绑定。绑定关系与 key 的写入在同一个批次中提交
+  fn bind_lease(
+    &self,
+    batch: &mut StorageBatch,
+    bindings: Vec<(Vec<u8>, i64)>,
+  ) -> Result<(), PlacementCenterError> {
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    let mut lease_bindings = LeaseBindings::new();
+    for (key, lease_id) in bindings {
+      if lease_id <= 0 {
+        lease_storage.detach_key(&mut lease_bindings, &key)?;
+      } else {
+        lease_storage.attach_key(&mut lease_bindings, &key, lease_id)?;
+      }
+    }
+    lease_storage.save_bindings(batch, lease_bindings)?;
+    Ok(())
+  }
+}
+
+// 读取事务执行过程中 key 的状态，事务中已经修改过的 key 以事务内的状态为准
+fn txn_entry(
+  kv_storage: &KvStorage,
+  pending: &HashMap<Vec<u8>, Option<KvEntry>>,
+  key: &[u8],
+) -> Result<Option<KvEntry>, PlacementCenterError> {
+  match pending.get(key) {
+    Some(entry) => Ok(entry.clone()),
+    None => Ok(kv_storage.get_entry(key)?),
+  }
+}
+
+pub fn get_reply(entry: Option<KvEntry>) -> GetReply {
+  match entry {
+    Some(entry) => GetReply {
+      value: entry.value,
+      create_revision: entry.create_revision,
+      mod_revision: entry.mod_revision,
+      version: entry.version,
+    },
+    None => GetReply::default(),
+  }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use log::warn;
+use prost::Message;
+use protocol::{LeaseGrantReply, LeaseGrantRequest, LeaseRevokeRequest};
+
+use crate::{
+  core::{
+    error::PlacementCenterError,
+    lease::LeaseManager,
+    watch::{KvWatchEvent, KvWatchEventType, KvWatchHub},
+  },
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    kv::{KvBatchOp, KvStorage},
+    lease::{Lease, LeaseStorage},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+// 服务端分配的租约 ID 从该值开始，客户端指定的租约 ID 必须小于该值，两者不会冲突
+pub const GENERATED_LEASE_ID_BASE: i64 = 1 << 62;
+
+#[derive(Debug, Clone)]
+pub struct DataRouteLease {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  kv_watch_hub: Arc<KvWatchHub>,
+  lease_manager: Arc<LeaseManager>,
+}
+
+impl DataRouteLease {
+  pub fn new(
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    kv_watch_hub: Arc<KvWatchHub>,
+    lease_manager: Arc<LeaseManager>,
+  ) -> Self {
+    DataRouteLease {
+      rocksdb_engine_handler,
+      kv_watch_hub,
+      lease_manager,
+    }
+  }
+
+  // 未指定租约 ID 时基于日志索引分配 ID，保证所有副本分配的 ID 一致。租约已经存在时返回 Conflict
+  pub fn grant(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = LeaseGrantRequest::decode(value.as_ref())?;
+    let lease_id = if req.id > 0 {
+      req.id
+    } else {
+      GENERATED_LEASE_ID_BASE + log_index as i64
+    };
+
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    if lease_storage.get(lease_id)?.is_some() {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::LeaseAlreadyExists(lease_id).to_string(),
+      ));
+    }
+
+    let lease = Lease {
+      id: lease_id,
+      ttl: req.ttl,
+      keys: Vec::new(),
+    };
+    lease_storage.save(batch, &lease)?;
+    self.lease_manager.refresh(lease_id, req.ttl);
+
+    let reply = LeaseGrantReply {
+      id: lease_id,
+      ttl: req.ttl,
+    };
+    Ok(AppResponseData::Value(LeaseGrantReply::encode_to_vec(
+      &reply,
+    )))
+  }
+
+  // 撤销租约并删除绑定在租约上的所有 key
+  pub fn revoke(
+    &self,
+    value: Vec<u8>,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = LeaseRevokeRequest::decode(value.as_ref())?;
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    let lease = match lease_storage.get(req.id)? {
+      Some(lease) => lease,
+      None => {
+        // 客户端撤销和 Leader 过期撤销可能同时提交，后应用的一条直接忽略
+        warn!("Lease {} has already been revoked", req.id);
+        self.lease_manager.remove(req.id);
+        return Ok(AppResponseData::NotFound);
+      }
+    };
+
+    // 删除 key、解除绑定和删除租约在同一个批次中原子完成
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    let ops = lease
+      .keys
+      .iter()
+      .map(|key| KvBatchOp::Delete(key.clone()))
+      .collect();
+    kv_storage.write(batch, ops, log_index)?;
+    lease_storage.revoke(batch, &lease)?;
+    self.lease_manager.remove(req.id);
+
+    for key in lease.keys {
+      self.kv_watch_hub.publish(KvWatchEvent {
+        event_type: KvWatchEventType::Delete,
+        key,
+        value: Vec::new(),
+        index: log_index,
+      });
+    }
+    Ok(AppResponseData::Ok)
+  }
+}
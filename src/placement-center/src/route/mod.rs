@@ -1,10 +1,246 @@
+use std::{path::Path, sync::Arc};
+
+use amqp::DataRouteAmqp;
+use cluster::DataRouteCluster;
+use config::DataRouteConfig;
+use data::{StorageData, StorageDataType};
+use id::DataRouteId;
+use journal::DataRouteJournal;
+use kv::DataRouteKv;
+use lease::DataRouteLease;
+use mqtt::DataRouteMqtt;
+use schema::DataRouteSchema;
+
+use crate::{
+  core::{
+    config::ClusterConfigWatchHub, error::PlacementCenterError, lease::LeaseManager,
+    watch::KvWatchHub,
+  },
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    engine::{engine_get_raw_by_cluster, engine_write_batch_by_cluster},
+    keys::key_name_applied_state,
+    lease::LeaseStorage,
+    rocksdb::RocksDBEngine,
+    snapshot::{build_storage_snapshot, recover_storage_snapshot},
+  },
+};
+
+pub mod amqp;
+pub mod apply;
+pub mod cluster;
+pub mod config;
 pub mod data;
+pub mod id;
+pub mod journal;
+pub mod kv;
+pub mod lease;
+pub mod mqtt;
+pub mod schema;
 
+/// 状态机的数据路由，按照 `StorageDataType` 将 Raft 日志中的数据分发到对应的存储模块
 #[derive(Debug, Clone)]
-pub struct DataRoute {}
+pub struct DataRoute {
+  route_kv: DataRouteKv,
+  route_lease: DataRouteLease,
+  route_cluster: DataRouteCluster,
+  route_mqtt: DataRouteMqtt,
+  route_journal: DataRouteJournal,
+  route_amqp: DataRouteAmqp,
+  route_schema: DataRouteSchema,
+  route_config: DataRouteConfig,
+  route_id: DataRouteId,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  kv_watch_hub: Arc<KvWatchHub>,
+  lease_manager: Arc<LeaseManager>,
+}
 
 impl DataRoute {
-  pub fn build_snapshot(&self) -> Vec<u8> {}
+  pub fn new(
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    kv_watch_hub: Arc<KvWatchHub>,
+    lease_manager: Arc<LeaseManager>,
+    config_watch_hub: Arc<ClusterConfigWatchHub>,
+  ) -> Self {
+    let route_kv = DataRouteKv::new(rocksdb_engine_handler.clone(), kv_watch_hub.clone());
+    let route_lease = DataRouteLease::new(
+      rocksdb_engine_handler.clone(),
+      kv_watch_hub.clone(),
+      lease_manager.clone(),
+    );
+    let route_cluster = DataRouteCluster::new(rocksdb_engine_handler.clone());
+    let route_mqtt = DataRouteMqtt::new(rocksdb_engine_handler.clone());
+    let route_journal = DataRouteJournal::new(rocksdb_engine_handler.clone());
+    let route_amqp = DataRouteAmqp::new(rocksdb_engine_handler.clone());
+    let route_schema = DataRouteSchema::new(rocksdb_engine_handler.clone());
+    let route_config = DataRouteConfig::new(rocksdb_engine_handler.clone(), config_watch_hub);
+    let route_id = DataRouteId::new(rocksdb_engine_handler.clone());
+    DataRoute {
+      route_kv,
+      route_lease,
+      route_cluster,
+      route_mqtt,
+      route_journal,
+      route_amqp,
+      route_schema,
+      route_config,
+      route_id,
+      rocksdb_engine_handler,
+      kv_watch_hub,
+      lease_manager,
+    }
+  }
+
+  /// 应用一条已提交的 Raft 日志数据，`log_index` 为该日志的索引，返回值会作为写请求的响应
+  pub fn route(
+    &self,
+    storage_data: StorageData,
+    log_index: u64,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    match storage_data.data_type {
+      StorageDataType::KvSet => self.route_kv.set(storage_data.value, log_index, batch),
+      StorageDataType::KvDelete => self.route_kv.delete(storage_data.value, log_index, batch),
+      StorageDataType::KvTxn => self.route_kv.txn(storage_data.value, log_index, batch),
+      StorageDataType::KvCompact => self.route_kv.compact(storage_data.value, batch),
+      StorageDataType::LeaseGrant => self.route_lease.grant(storage_data.value, log_index, batch),
+      StorageDataType::LeaseRevoke => self
+        .route_lease
+        .revoke(storage_data.value, log_index, batch),
+      StorageDataType::ClusterAddNode => {
+        self
+          .route_cluster
+          .add_node(storage_data.value, log_index, batch)
+      }
+      StorageDataType::ClusterDeleteNode => {
+        self.route_cluster.delete_node(storage_data.value, batch)
+      }
+      StorageDataType::ClusterAddCluster => {
+        self.route_cluster.add_cluster(storage_data.value, batch)
+      }
+      StorageDataType::ClusterDeleteCluster => {
+        self.route_cluster.delete_cluster(storage_data.value, batch)
+      }
+      StorageDataType::MqttCreateUser => self.route_mqtt.create_user(storage_data.value, batch),
+      StorageDataType::MqttDeleteUser => self.route_mqtt.delete_user(storage_data.value, batch),
+      StorageDataType::MqttCreateTopic => self.route_mqtt.create_topic(storage_data.value, batch),
+      StorageDataType::MqttDeleteTopic => self.route_mqtt.delete_topic(storage_data.value, batch),
+      StorageDataType::MqttSetTopicRetainMessage => self
+        .route_mqtt
+        .set_topic_retain_message(storage_data.value, batch),
+      StorageDataType::MqttSaveSession => self.route_mqtt.save_session(storage_data.value, batch),
+      StorageDataType::MqttDeleteSession => {
+        self.route_mqtt.delete_session(storage_data.value, batch)
+      }
+      StorageDataType::MqttCreateAcl => self.route_mqtt.create_acl(storage_data.value, batch),
+      StorageDataType::MqttDeleteAcl => self.route_mqtt.delete_acl(storage_data.value, batch),
+      StorageDataType::MqttCreateBlacklist => {
+        self.route_mqtt.create_blacklist(storage_data.value, batch)
+      }
+      StorageDataType::MqttDeleteBlacklist => {
+        self.route_mqtt.delete_blacklist(storage_data.value, batch)
+      }
+      StorageDataType::JournalCreateShard => {
+        self.route_journal.create_shard(storage_data.value, batch)
+      }
+      StorageDataType::JournalUpdateSegmentStatus => self
+        .route_journal
+        .update_segment_status(storage_data.value, batch),
+      StorageDataType::JournalSealSegment => {
+        self.route_journal.seal_segment(storage_data.value, batch)
+      }
+      StorageDataType::JournalCommitOffset => {
+        self.route_journal.commit_offset(storage_data.value, batch)
+      }
+      StorageDataType::AmqpCreateVhost => self.route_amqp.create_vhost(storage_data.value, batch),
+      StorageDataType::AmqpDeleteVhost => self.route_amqp.delete_vhost(storage_data.value, batch),
+      StorageDataType::AmqpCreateExchange => {
+        self.route_amqp.create_exchange(storage_data.value, batch)
+      }
+      StorageDataType::AmqpDeleteExchange => {
+        self.route_amqp.delete_exchange(storage_data.value, batch)
+      }
+      StorageDataType::AmqpCreateQueue => self.route_amqp.create_queue(storage_data.value, batch),
+      StorageDataType::AmqpDeleteQueue => self.route_amqp.delete_queue(storage_data.value, batch),
+      StorageDataType::AmqpCreateBinding => {
+        self.route_amqp.create_binding(storage_data.value, batch)
+      }
+      StorageDataType::AmqpDeleteBinding => {
+        self.route_amqp.delete_binding(storage_data.value, batch)
+      }
+      StorageDataType::SchemaRegister => {
+        self.route_schema.register_schema(storage_data.value, batch)
+      }
+      StorageDataType::SchemaDelete => self.route_schema.delete_schema(storage_data.value, batch),
+      StorageDataType::SchemaSetCompatibility => self
+        .route_schema
+        .set_compatibility(storage_data.value, batch),
+      StorageDataType::SchemaBind => self.route_schema.bind_schema(storage_data.value, batch),
+      StorageDataType::SchemaUnbind => self.route_schema.unbind_schema(storage_data.value, batch),
+      StorageDataType::ClusterConfigSet => {
+        self
+          .route_config
+          .set_config(storage_data.value, log_index, batch)
+      }
+      StorageDataType::ClusterConfigDelete => {
+        self
+          .route_config
+          .delete_config(storage_data.value, log_index, batch)
+      }
+      StorageDataType::IdAllocate => self.route_id.allocate(storage_data.value, batch),
+      StorageDataType::MqttPurgeBlacklist => {
+        self.route_mqtt.purge_blacklist(storage_data.value, batch)
+      }
+      StorageDataType::MqttPurgeRetainMessage => self
+        .route_mqtt
+        .purge_retain_message(storage_data.value, batch),
+      StorageDataType::ClusterRemoveExpiredNode => self
+        .route_cluster
+        .remove_expired_node(storage_data.value, batch),
+    }
+  }
+
+  /// 读取数据目录中记录的状态机应用进度，由状态机负责序列化
+  pub fn applied_state(&self) -> Result<Option<Vec<u8>>, PlacementCenterError> {
+    let key = key_name_applied_state();
+    Ok(engine_get_raw_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      key.as_bytes(),
+    )?)
+  }
+
+  /// 状态机启动或恢复快照后，按数据中的应用进度初始化 Watch 历史，
+  /// 小于等于 `applied_index` 的变更已经写入数据，续订时需要客户端重新读取
+  pub fn restore_applied_index(&self, applied_index: u64) {
+    self.kv_watch_hub.reset(applied_index);
+  }
+
+  /// 将一条日志应用产生的写操作与状态机的应用进度在同一个 WriteBatch 中提交
+  pub fn commit_batch(
+    &self,
+    mut batch: StorageBatch,
+    applied_state: Vec<u8>,
+  ) -> Result<(), PlacementCenterError> {
+    batch.put_raw(key_name_applied_state().into_bytes(), applied_state);
+    engine_write_batch_by_cluster(self.rocksdb_engine_handler.clone(), batch)?;
+    Ok(())
+  }
+
+  /// 将 cluster 列蔟中的数据写入快照文件，快照格式由配置的快照模式决定
+  pub fn build_snapshot(&self, path: &Path) -> Result<(), PlacementCenterError> {
+    build_storage_snapshot(&self.rocksdb_engine_handler, path)?;
+    Ok(())
+  }
 
-  pub fn recover_snapshot(&self, data: Vec<u8>) -> Result<(), Place> {}
+  /// 使用快照文件恢复 cluster 列蔟，`last_index` 为快照包含的最后一条日志索引
+  pub fn recover_snapshot(&self, path: &Path, last_index: u64) -> Result<(), PlacementCenterError> {
+    recover_storage_snapshot(&self.rocksdb_engine_handler, path)?;
+    // 快照覆盖的变更无法再通过 Watch 补发
+    self.kv_watch_hub.reset(last_index);
+    // 租约的过期时间只保存在内存中，需要按快照中的租约重建
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    self.lease_manager.reload(lease_storage.list()?);
+    Ok(())
+  }
 }
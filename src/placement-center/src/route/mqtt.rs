@@ -0,0 +1,471 @@
+use std::sync::Arc;
+
+use log::warn;
+use prost::Message;
+use protocol::mqtt::{
+  AclAction, AclPermission, AclResourceType, BlacklistType, DeleteAclRequest,
+  DeleteBlacklistRequest, DeleteSessionRequest, DeleteTopicRequest, DeleteUserRequest,
+  LastWillMessage, MqttAcl, MqttBlacklist, MqttSession, MqttTopic, MqttUser, PurgeBlacklistData,
+  PurgeRetainMessageData,
+};
+
+use crate::{
+  core::{error::PlacementCenterError, mqtt::check_mqtt_cluster},
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    mqtt::{
+      acl::{Acl, MqttAclStorage},
+      blacklist::{Blacklist, MqttBlacklistStorage},
+      session::{LastWill, MqttSessionStorage, Session, Subscription},
+      topic::{MqttTopicStorage, RetainMessage, Topic},
+      user::{MqttUserStorage, User},
+    },
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// MQTT Broker 共享的元数据，创建时间由提交请求的节点填写，保证所有副本写入的数据一致
+#[derive(Debug, Clone)]
+pub struct DataRouteMqtt {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl DataRouteMqtt {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    DataRouteMqtt {
+      rocksdb_engine_handler,
+    }
+  }
+
+  // 同名用户已经存在时拒绝创建，避免覆盖其它 Broker 设置的密码
+  pub fn create_user(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = MqttUser::decode(value.as_ref())?;
+    if !self.cluster_valid(&req.cluster_name)? {
+      return Ok(AppResponseData::NotFound);
+    }
+    let user_storage = MqttUserStorage::new(self.rocksdb_engine_handler.clone());
+    if user_storage
+      .get(&req.cluster_name, &req.user_name)?
+      .is_some()
+    {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::MqttUserAlreadyExists(req.cluster_name, req.user_name).to_string(),
+      ));
+    }
+
+    user_storage.save(
+      batch,
+      &User {
+        cluster_name: req.cluster_name,
+        user_name: req.user_name,
+        password_hash: req.password_hash,
+        is_superuser: req.is_superuser,
+        create_time: req.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_user(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteUserRequest::decode(value.as_ref())?;
+    let user_storage = MqttUserStorage::new(self.rocksdb_engine_handler.clone());
+    if user_storage
+      .get(&req.cluster_name, &req.user_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+    user_storage.delete(batch, &req.cluster_name, &req.user_name)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 主题由第一个使用它的 Broker 创建，其它 Broker 重复创建时返回 Conflict
+  pub fn create_topic(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = MqttTopic::decode(value.as_ref())?;
+    if !self.cluster_valid(&req.cluster_name)? {
+      return Ok(AppResponseData::NotFound);
+    }
+    let topic_storage = MqttTopicStorage::new(self.rocksdb_engine_handler.clone());
+    if topic_storage
+      .get(&req.cluster_name, &req.topic_name)?
+      .is_some()
+    {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::MqttTopicAlreadyExists(req.cluster_name, req.topic_name).to_string(),
+      ));
+    }
+
+    topic_storage.save(
+      batch,
+      &Topic {
+        cluster_name: req.cluster_name,
+        topic_name: req.topic_name,
+        retain_message: None,
+        create_time: req.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_topic(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteTopicRequest::decode(value.as_ref())?;
+    let topic_storage = MqttTopicStorage::new(self.rocksdb_engine_handler.clone());
+    if topic_storage
+      .get(&req.cluster_name, &req.topic_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+    topic_storage.delete(batch, &req.cluster_name, &req.topic_name)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // retain_message 为空时清除主题的保留消息，过期时间由提交请求的节点换算为时间戳
+  pub fn set_topic_retain_message(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = MqttTopic::decode(value.as_ref())?;
+    let topic_storage = MqttTopicStorage::new(self.rocksdb_engine_handler.clone());
+    let mut topic = match topic_storage.get(&req.cluster_name, &req.topic_name)? {
+      Some(topic) => topic,
+      None => return Ok(AppResponseData::NotFound),
+    };
+    topic.retain_message = req.retain_message.map(|message| RetainMessage {
+      payload: message.payload,
+      qos: message.qos,
+      expire_at: message.expire_at,
+      create_time: message.create_time,
+    });
+    topic_storage.save(batch, &topic)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 会话整体覆盖，保留第一次保存时的创建时间
+  pub fn save_session(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = MqttSession::decode(value.as_ref())?;
+    if !self.cluster_valid(&req.cluster_name)? {
+      return Ok(AppResponseData::NotFound);
+    }
+    let session_storage = MqttSessionStorage::new(self.rocksdb_engine_handler.clone());
+    let create_time = match session_storage.get(&req.cluster_name, &req.client_id)? {
+      Some(session) => session.create_time,
+      None => req.create_time,
+    };
+
+    session_storage.save(
+      batch,
+      &Session {
+        cluster_name: req.cluster_name,
+        client_id: req.client_id,
+        session_expiry_interval: req.session_expiry_interval,
+        broker_node_id: req.broker_node_id,
+        last_will: req.last_will.map(|last_will| LastWill {
+          topic_name: last_will.topic_name,
+          payload: last_will.payload,
+          qos: last_will.qos,
+          retain: last_will.retain,
+          delay_interval: last_will.delay_interval,
+        }),
+        subscriptions: req
+          .subscriptions
+          .into_iter()
+          .map(|subscription| Subscription {
+            topic_filter: subscription.topic_filter,
+            qos: subscription.qos,
+            no_local: subscription.no_local,
+            retain_as_published: subscription.retain_as_published,
+            retain_handling: subscription.retain_handling,
+          })
+          .collect(),
+        create_time,
+        update_time: req.update_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_session(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteSessionRequest::decode(value.as_ref())?;
+    let session_storage = MqttSessionStorage::new(self.rocksdb_engine_handler.clone());
+    if session_storage
+      .get(&req.cluster_name, &req.client_id)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+    session_storage.delete(batch, &req.cluster_name, &req.client_id)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 相同的规则已经存在时覆盖原有的 permission
+  pub fn create_acl(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = MqttAcl::decode(value.as_ref())?;
+    if !self.cluster_valid(&req.cluster_name)? {
+      return Ok(AppResponseData::NotFound);
+    }
+    let acl_storage = MqttAclStorage::new(self.rocksdb_engine_handler.clone());
+    acl_storage.save(
+      batch,
+      &Acl {
+        resource_type: req.resource_type().as_str_name().to_string(),
+        action: req.action().as_str_name().to_string(),
+        permission: req.permission().as_str_name().to_string(),
+        cluster_name: req.cluster_name,
+        resource_name: req.resource_name,
+        topic_filter: req.topic_filter,
+        create_time: req.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_acl(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteAclRequest::decode(value.as_ref())?;
+    let acl_storage = MqttAclStorage::new(self.rocksdb_engine_handler.clone());
+    let acl = match acl_storage.get(
+      &req.cluster_name,
+      req.resource_type().as_str_name(),
+      &req.resource_name,
+      req.action().as_str_name(),
+      &req.topic_filter,
+    )? {
+      Some(acl) => acl,
+      None => return Ok(AppResponseData::NotFound),
+    };
+    acl_storage.delete(batch, &acl)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 同一个对象已经在黑名单中时覆盖原有的失效时间和描述
+  pub fn create_blacklist(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = MqttBlacklist::decode(value.as_ref())?;
+    if !self.cluster_valid(&req.cluster_name)? {
+      return Ok(AppResponseData::NotFound);
+    }
+    let blacklist_storage = MqttBlacklistStorage::new(self.rocksdb_engine_handler.clone());
+    blacklist_storage.save(
+      batch,
+      &Blacklist {
+        blacklist_type: req.blacklist_type().as_str_name().to_string(),
+        cluster_name: req.cluster_name,
+        resource_name: req.resource_name,
+        end_time: req.end_time,
+        desc: req.desc,
+        create_time: req.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn delete_blacklist(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteBlacklistRequest::decode(value.as_ref())?;
+    let blacklist_type = req.blacklist_type().as_str_name();
+    let blacklist_storage = MqttBlacklistStorage::new(self.rocksdb_engine_handler.clone());
+    if blacklist_storage
+      .get(&req.cluster_name, blacklist_type, &req.resource_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+    blacklist_storage.delete(batch, &req.cluster_name, blacklist_type, &req.resource_name)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 只删除在 expire_time 时仍然失效的黑名单，Leader 检查之后重新加入的黑名单不会被删除。
+  // 一次清理的全部黑名单在同一个批次中删除
+  pub fn purge_blacklist(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = PurgeBlacklistData::decode(value.as_ref())?;
+    let blacklist_storage = MqttBlacklistStorage::new(self.rocksdb_engine_handler.clone());
+    for item in req.blacklists {
+      let blacklist_type = item.blacklist_type().as_str_name();
+      match blacklist_storage.get(&item.cluster_name, blacklist_type, &item.resource_name)? {
+        Some(blacklist) if blacklist.is_expired(req.expire_time) => {
+          blacklist_storage.delete(
+            batch,
+            &item.cluster_name,
+            blacklist_type,
+            &item.resource_name,
+          )?;
+        }
+        _ => {}
+      }
+    }
+    Ok(AppResponseData::Ok)
+  }
+
+  // 只清除在 expire_time 时仍然过期的保留消息，检查之后重新设置的保留消息不会被清除
+  pub fn purge_retain_message(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = PurgeRetainMessageData::decode(value.as_ref())?;
+    let topic_storage = MqttTopicStorage::new(self.rocksdb_engine_handler.clone());
+    for item in req.topics {
+      let mut topic = match topic_storage.get(&item.cluster_name, &item.topic_name)? {
+        Some(topic) => topic,
+        None => continue,
+      };
+      if topic
+        .retain_message
+        .as_ref()
+        .is_some_and(|message| message.is_expired(req.expire_time))
+      {
+        topic.retain_message = None;
+        topic_storage.save(batch, &topic)?;
+      }
+    }
+    Ok(AppResponseData::Ok)
+  }
+
+  // 提交之前已经校验过集群，集群在提交过程中被删除时不写入数据，由调用方返回 NotFound
+  fn cluster_valid(&self, cluster_name: &str) -> Result<bool, PlacementCenterError> {
+    match check_mqtt_cluster(&self.rocksdb_engine_handler, cluster_name) {
+      Ok(()) => Ok(true),
+      Err(
+        e @ (PlacementCenterError::MqttClusterNameInvalid(_)
+        | PlacementCenterError::ClusterNotFound(_)
+        | PlacementCenterError::ClusterTypeUnexpected(..)),
+      ) => {
+        warn!("MQTT metadata write is rejected, {}", e);
+        Ok(false)
+      }
+      Err(e) => Err(e),
+    }
+  }
+}
+
+pub fn mqtt_user(user: User) -> MqttUser {
+  MqttUser {
+    cluster_name: user.cluster_name,
+    user_name: user.user_name,
+    password_hash: user.password_hash,
+    is_superuser: user.is_superuser,
+    create_time: user.create_time,
+  }
+}
+
+// 过期的保留消息不再返回给 Broker
+pub fn mqtt_topic(topic: Topic, now: u64) -> MqttTopic {
+  MqttTopic {
+    cluster_name: topic.cluster_name,
+    topic_name: topic.topic_name,
+    retain_message: topic
+      .retain_message
+      .filter(|message| !message.is_expired(now))
+      .map(|message| protocol::mqtt::RetainMessage {
+        payload: message.payload,
+        qos: message.qos,
+        expire_at: message.expire_at,
+        create_time: message.create_time,
+      }),
+    create_time: topic.create_time,
+  }
+}
+
+pub fn mqtt_session(session: Session) -> MqttSession {
+  MqttSession {
+    cluster_name: session.cluster_name,
+    client_id: session.client_id,
+    session_expiry_interval: session.session_expiry_interval,
+    broker_node_id: session.broker_node_id,
+    last_will: session.last_will.map(|last_will| LastWillMessage {
+      topic_name: last_will.topic_name,
+      payload: last_will.payload,
+      qos: last_will.qos,
+      retain: last_will.retain,
+      delay_interval: last_will.delay_interval,
+    }),
+    subscriptions: session
+      .subscriptions
+      .into_iter()
+      .map(|subscription| protocol::mqtt::Subscription {
+        topic_filter: subscription.topic_filter,
+        qos: subscription.qos,
+        no_local: subscription.no_local,
+        retain_as_published: subscription.retain_as_published,
+        retain_handling: subscription.retain_handling,
+      })
+      .collect(),
+    create_time: session.create_time,
+    update_time: session.update_time,
+  }
+}
+
+pub fn mqtt_acl(acl: Acl) -> MqttAcl {
+  MqttAcl {
+    cluster_name: acl.cluster_name,
+    resource_type: AclResourceType::from_str_name(&acl.resource_type)
+      .unwrap_or_default()
+      .into(),
+    resource_name: acl.resource_name,
+    topic_filter: acl.topic_filter,
+    action: AclAction::from_str_name(&acl.action)
+      .unwrap_or_default()
+      .into(),
+    permission: AclPermission::from_str_name(&acl.permission)
+      .unwrap_or_default()
+      .into(),
+    create_time: acl.create_time,
+  }
+}
+
+pub fn mqtt_blacklist(blacklist: Blacklist) -> MqttBlacklist {
+  MqttBlacklist {
+    cluster_name: blacklist.cluster_name,
+    blacklist_type: BlacklistType::from_str_name(&blacklist.blacklist_type)
+      .unwrap_or_default()
+      .into(),
+    resource_name: blacklist.resource_name,
+    end_time: blacklist.end_time,
+    desc: blacklist.desc,
+    create_time: blacklist.create_time,
+  }
+}
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use prost::Message;
+use protocol::schema::{
+  Compatibility, DeleteSchemaRequest, RegisterSchemaReply, SchemaBindingInfo, SchemaInfo,
+  SchemaResourceType, SchemaType, SetCompatibilityRequest, UnbindSchemaRequest,
+};
+
+use crate::{
+  core::{error::PlacementCenterError, schema::check_schema_compatibility},
+  openraft::route::AppResponseData,
+  storage::{
+    batch::StorageBatch,
+    journal::shard::ShardStorage,
+    mqtt::topic::MqttTopicStorage,
+    rocksdb::RocksDBEngine,
+    schema::{
+      binding::{SchemaBinding, SchemaBindingStorage},
+      subject::{SchemaStorage, SchemaSubject, SchemaVersion},
+    },
+  },
+};
+
+/// Schema 注册中心的数据，版本号由状态机按 subject 递增分配，创建时间由提交请求的节点填写
+#[derive(Debug, Clone)]
+pub struct DataRouteSchema {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl DataRouteSchema {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    DataRouteSchema {
+      rocksdb_engine_handler,
+    }
+  }
+
+  // 与最新版本相同的定义不会生成新版本，直接返回最新的版本号，
+  // 新版本和更新后的 subject 在同一个批次中提交
+  pub fn register_schema(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = SchemaInfo::decode(value.as_ref())?;
+    let schema_storage = SchemaStorage::new(self.rocksdb_engine_handler.clone());
+    let schema_type = req.schema_type().as_str_name().to_string();
+    let mut subject = match schema_storage.get_subject(&req.cluster_name, &req.subject)? {
+      Some(subject) => subject,
+      None => SchemaSubject {
+        cluster_name: req.cluster_name.clone(),
+        subject: req.subject.clone(),
+        schema_type: schema_type.clone(),
+        compatibility: Compatibility::Backward.as_str_name().to_string(),
+        latest_version: 0,
+        create_time: req.create_time,
+      },
+    };
+    if subject.schema_type != schema_type {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::SchemaTypeMismatch(req.subject, subject.schema_type, schema_type)
+          .to_string(),
+      ));
+    }
+
+    if let Some(latest) =
+      schema_storage.get_version(&req.cluster_name, &req.subject, subject.latest_version)?
+    {
+      if latest.definition == req.definition {
+        let reply = RegisterSchemaReply {
+          version: latest.version,
+        };
+        return Ok(AppResponseData::Value(RegisterSchemaReply::encode_to_vec(
+          &reply,
+        )));
+      }
+      let compatibility = Compatibility::from_str_name(&subject.compatibility).unwrap_or_default();
+      if let Err(e) = check_schema_compatibility(
+        req.schema_type(),
+        compatibility,
+        &latest.definition,
+        &req.definition,
+      ) {
+        return Ok(AppResponseData::Conflict(e.to_string()));
+      }
+    }
+
+    subject.latest_version += 1;
+    schema_storage.save_version(
+      batch,
+      &SchemaVersion {
+        cluster_name: req.cluster_name,
+        subject: req.subject,
+        version: subject.latest_version,
+        schema_type,
+        definition: req.definition,
+        create_time: req.create_time,
+      },
+    )?;
+    schema_storage.save_subject(batch, &subject)?;
+
+    let reply = RegisterSchemaReply {
+      version: subject.latest_version,
+    };
+    Ok(AppResponseData::Value(RegisterSchemaReply::encode_to_vec(
+      &reply,
+    )))
+  }
+
+  // 删除绑定到 subject 的主题和分片，以及 subject 和它的所有版本，
+  // 所有删除在同一个批次中提交
+  pub fn delete_schema(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = DeleteSchemaRequest::decode(value.as_ref())?;
+    let schema_storage = SchemaStorage::new(self.rocksdb_engine_handler.clone());
+    if schema_storage
+      .get_subject(&req.cluster_name, &req.subject)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+
+    let binding_storage = SchemaBindingStorage::new(self.rocksdb_engine_handler.clone());
+    for binding in binding_storage.list(&req.cluster_name, &req.subject)? {
+      binding_storage.delete(
+        batch,
+        &binding.cluster_name,
+        &binding.resource_type,
+        &binding.resource_name,
+      )?;
+    }
+    schema_storage.delete_subject(batch, &req.cluster_name, &req.subject)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn set_compatibility(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = SetCompatibilityRequest::decode(value.as_ref())?;
+    let schema_storage = SchemaStorage::new(self.rocksdb_engine_handler.clone());
+    let mut subject = match schema_storage.get_subject(&req.cluster_name, &req.subject)? {
+      Some(subject) => subject,
+      None => return Ok(AppResponseData::NotFound),
+    };
+    subject.compatibility = req.compatibility().as_str_name().to_string();
+    schema_storage.save_subject(batch, &subject)?;
+    Ok(AppResponseData::Ok)
+  }
+
+  // 主题或分片不存在时返回 Conflict，已经绑定的资源会改为绑定到新的 subject
+  pub fn bind_schema(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = SchemaBindingInfo::decode(value.as_ref())?;
+    let schema_storage = SchemaStorage::new(self.rocksdb_engine_handler.clone());
+    if schema_storage
+      .get_subject(&req.cluster_name, &req.subject)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+
+    let resource_type = req.resource_type();
+    let exists = match resource_type {
+      SchemaResourceType::MqttTopic => {
+        let topic_storage = MqttTopicStorage::new(self.rocksdb_engine_handler.clone());
+        topic_storage
+          .get(&req.cluster_name, &req.resource_name)?
+          .is_some()
+      }
+      // Journal 分片的资源名称为 namespace/shard_name
+      SchemaResourceType::JournalShard => match req.resource_name.split_once('/') {
+        Some((namespace, shard_name)) => {
+          let shard_storage = ShardStorage::new(self.rocksdb_engine_handler.clone());
+          shard_storage
+            .get(&req.cluster_name, namespace, shard_name)?
+            .is_some()
+        }
+        None => false,
+      },
+    };
+    if !exists {
+      return Ok(AppResponseData::Conflict(
+        PlacementCenterError::SchemaResourceNotFound(
+          resource_type.as_str_name().to_string(),
+          req.resource_name,
+        )
+        .to_string(),
+      ));
+    }
+
+    let binding_storage = SchemaBindingStorage::new(self.rocksdb_engine_handler.clone());
+    binding_storage.save(
+      batch,
+      &SchemaBinding {
+        cluster_name: req.cluster_name,
+        resource_type: resource_type.as_str_name().to_string(),
+        resource_name: req.resource_name,
+        subject: req.subject,
+        create_time: req.create_time,
+      },
+    )?;
+    Ok(AppResponseData::Ok)
+  }
+
+  pub fn unbind_schema(
+    &self,
+    value: Vec<u8>,
+    batch: &mut StorageBatch,
+  ) -> Result<AppResponseData, PlacementCenterError> {
+    let req = UnbindSchemaRequest::decode(value.as_ref())?;
+    let resource_type = req.resource_type().as_str_name();
+    let binding_storage = SchemaBindingStorage::new(self.rocksdb_engine_handler.clone());
+    if binding_storage
+      .get(&req.cluster_name, resource_type, &req.resource_name)?
+      .is_none()
+    {
+      return Ok(AppResponseData::NotFound);
+    }
+    binding_storage.delete(batch, &req.cluster_name, resource_type, &req.resource_name)?;
+    Ok(AppResponseData::Ok)
+  }
+}
+
+pub fn schema_info(version: SchemaVersion) -> SchemaInfo {
+  SchemaInfo {
+    cluster_name: version.cluster_name,
+    subject: version.subject,
+    version: version.version,
+    schema_type: SchemaType::from_str_name(&version.schema_type)
+      .unwrap_or_default()
+      .into(),
+    definition: version.definition,
+    create_time: version.create_time,
+  }
+}
+
+pub fn binding_info(binding: SchemaBinding) -> SchemaBindingInfo {
+  SchemaBindingInfo {
+    cluster_name: binding.cluster_name,
+    resource_type: SchemaResourceType::from_str_name(&binding.resource_type)
+      .unwrap_or_default()
+      .into(),
+    resource_name: binding.resource_name,
+    subject: binding.subject,
+    create_time: binding.create_time,
+  }
+}
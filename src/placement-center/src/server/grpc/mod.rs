@@ -1,2 +1,12 @@
 pub mod server;
+mod services_amqp;
+mod services_config;
+mod services_id;
+mod services_journal;
 mod services_kv;
+mod services_kv_v2;
+mod services_mqtt;
+mod services_mqtt_acl;
+mod services_openraft;
+mod services_placement;
+mod services_schema;
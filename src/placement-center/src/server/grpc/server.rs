@@ -1,31 +1,151 @@
+use std::sync::Arc;
+
 use common_base::config::placement_center::placement_center_conf;
 use log::info;
-use protocol::kv_service_server::KvServiceServer;
+use protocol::{
+  amqp::amqp_service_server::AmqpServiceServer,
+  config::cluster_config_service_server::ClusterConfigServiceServer,
+  id::id_service_server::IdServiceServer,
+  journal::journal_service_server::JournalServiceServer,
+  kv_service_server::KvServiceServer,
+  kv_v2::kv_service_v2_server::KvServiceV2Server,
+  mqtt::{mqtt_acl_service_server::MqttAclServiceServer, mqtt_service_server::MqttServiceServer},
+  open_raft_service_server::OpenRaftServiceServer,
+  placement_center_server_server::PlacementCenterServerServer,
+  schema::schema_service_server::SchemaServiceServer,
+};
 use tokio::{select, sync::broadcast};
 use tonic::transport::Server;
 
-use crate::server::grpc::services_kv::GrpcBrokerServices;
-
-pub async fn start_grpc_server(stop_sx: broadcast::Sender<bool>) {
+use crate::{
+  core::{
+    config::ClusterConfigWatchHub, heartbeat::NodeHeartbeatCache, lease::LeaseManager,
+    segment_placement::build_segment_placement_policy, watch::KvWatchHub,
+  },
+  openraft::store::snapshot::snapshot_dir,
+  route::apply::RaftMachineApply,
+  server::grpc::{
+    services_amqp::GrpcAmqpServices, services_config::GrpcClusterConfigServices,
+    services_id::GrpcIdServices, services_journal::GrpcJournalServices,
+    services_kv::GrpcBrokerServices, services_mqtt::GrpcMqttServices,
+    services_mqtt_acl::GrpcMqttAclServices, services_openraft::GrpcOpenRaftServices,
+    services_placement::GrpcPlacementServices, services_schema::GrpcSchemaServices,
+  },
+  storage::rocksdb::RocksDBEngine,
+};
+
+pub async fn start_grpc_server(
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  kv_watch_hub: Arc<KvWatchHub>,
+  lease_manager: Arc<LeaseManager>,
+  heartbeat_cache: Arc<NodeHeartbeatCache>,
+  config_watch_hub: Arc<ClusterConfigWatchHub>,
+  stop_sx: broadcast::Sender<bool>,
+) {
   let config = placement_center_conf();
-  let server = GrpcServer::new(config.network.grpc_port);
+  let server = GrpcServer::new(
+    config.network.grpc_port,
+    raft_machine_apply,
+    rocksdb_engine_handler,
+    kv_watch_hub,
+    lease_manager,
+    heartbeat_cache,
+    config_watch_hub,
+  );
   server.start(stop_sx).await;
 }
 
 pub struct GrpcServer {
-  port: usize,
+  port: u32,
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  kv_watch_hub: Arc<KvWatchHub>,
+  lease_manager: Arc<LeaseManager>,
+  heartbeat_cache: Arc<NodeHeartbeatCache>,
+  config_watch_hub: Arc<ClusterConfigWatchHub>,
 }
 
 impl GrpcServer {
-  pub fn new(port: u32) -> Self {
-    return Self { port };
+  pub fn new(
+    port: u32,
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    kv_watch_hub: Arc<KvWatchHub>,
+    lease_manager: Arc<LeaseManager>,
+    heartbeat_cache: Arc<NodeHeartbeatCache>,
+    config_watch_hub: Arc<ClusterConfigWatchHub>,
+  ) -> Self {
+    return Self {
+      port,
+      raft_machine_apply,
+      rocksdb_engine_handler,
+      kv_watch_hub,
+      lease_manager,
+      heartbeat_cache,
+      config_watch_hub,
+    };
   }
 
   pub async fn start(&self, stop_sx: broadcast::Sender<bool>) {
     let addr = format!("0.0.0.0:{}", self.port).parse().unwrap();
     info!("Broker Grpc Server start. port:{}", self.port);
 
-    let kv_service_handler = GrpcBrokerServices::new();
+    let kv_service_handler = GrpcBrokerServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+      self.kv_watch_hub.clone(),
+      self.lease_manager.clone(),
+    );
+
+    let config = placement_center_conf();
+    let openraft_service_handler = GrpcOpenRaftServices::new(
+      self.raft_machine_apply.openraft_node.clone(),
+      snapshot_dir(&config.rocksdb.data_path),
+    );
+
+    let placement_service_handler = GrpcPlacementServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+      self.heartbeat_cache.clone(),
+    );
+
+    let mqtt_service_handler = GrpcMqttServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+    );
+
+    let mqtt_acl_service_handler = GrpcMqttAclServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+    );
+
+    let journal_service_handler = GrpcJournalServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+      build_segment_placement_policy(&config.journal),
+    );
+
+    let amqp_service_handler = GrpcAmqpServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+    );
+
+    let schema_service_handler = GrpcSchemaServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+    );
+
+    let config_service_handler = GrpcClusterConfigServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+      self.config_watch_hub.clone(),
+    );
+
+    let id_service_handler = GrpcIdServices::new(
+      self.raft_machine_apply.clone(),
+      self.rocksdb_engine_handler.clone(),
+    );
 
     let mut stop_rx = stop_sx.subscribe();
 
@@ -34,14 +154,26 @@ impl GrpcServer {
             match val {
                 Ok(flag)=>{
                     if flag{
-                        info!("HTTP Server stopped successfully");
+                        info!("Grpc Server stopped successfully");
                     }
                 }
                 Err(_)=>{}
             }
         },
 
-        val = Server::builder().add_service(KvServiceServer::new(kv_service_handler)).serve(addr)=>{
+        val = Server::builder()
+            .add_service(KvServiceServer::new(kv_service_handler.clone()))
+            .add_service(KvServiceV2Server::new(kv_service_handler))
+            .add_service(OpenRaftServiceServer::new(openraft_service_handler))
+            .add_service(PlacementCenterServerServer::new(placement_service_handler))
+            .add_service(MqttServiceServer::new(mqtt_service_handler))
+            .add_service(MqttAclServiceServer::new(mqtt_acl_service_handler))
+            .add_service(JournalServiceServer::new(journal_service_handler))
+            .add_service(AmqpServiceServer::new(amqp_service_handler))
+            .add_service(SchemaServiceServer::new(schema_service_handler))
+            .add_service(ClusterConfigServiceServer::new(config_service_handler))
+            .add_service(IdServiceServer::new(id_service_handler))
+            .serve(addr)=>{
             match val {
                 Ok(())=>{},
                 Err(e)=>{
@@ -70,22 +202,32 @@ mod tests {
     let request = tonic::Request::new(SetRequest {
       key: key.clone(),
       value: value.clone(),
+      ..Default::default()
     });
 
     let _ = client.set(request).await.unwrap();
 
-    let request = tonic::Request::new(ExistsRequest { key: key.clone() });
+    let request = tonic::Request::new(ExistsRequest {
+      key: key.clone(),
+      ..Default::default()
+    });
     let exist_reply = client.exists(request).await.unwrap().into_inner();
     assert!(exist_reply.flag);
 
-    let request = tonic::Request::new(GetRequest { key: key.clone() });
+    let request = tonic::Request::new(GetRequest {
+      key: key.clone(),
+      ..Default::default()
+    });
     let get_reply = client.get(request).await.unwrap().into_inner();
     assert_eq!(get_reply.value, value);
 
     let request = tonic::Request::new(DeleteRequest { key: key.clone() });
     let _ = client.delete(request).await.unwrap().into_inner();
 
-    let request = tonic::Request::new(ExistsRequest { key: key.clone() });
+    let request = tonic::Request::new(ExistsRequest {
+      key: key.clone(),
+      ..Default::default()
+    });
     let exist_reply = client.exists(request).await.unwrap().into_inner();
     assert!(!exist_reply.flag);
   }
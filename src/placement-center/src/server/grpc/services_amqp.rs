@@ -0,0 +1,388 @@
+use std::sync::Arc;
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::{
+  CommonReply,
+  amqp::{
+    AmqpBinding, AmqpExchange, AmqpQueue, AmqpVhost, CreateBindingRequest, CreateExchangeRequest,
+    CreateQueueRequest, CreateVhostRequest, DeleteBindingRequest, DeleteExchangeRequest,
+    DeleteQueueRequest, DeleteVhostRequest, ExchangeType, ListBindingReply, ListBindingRequest,
+    ListExchangeReply, ListExchangeRequest, ListQueueReply, ListQueueRequest, ListVhostReply,
+    ListVhostRequest, amqp_service_server::AmqpService,
+  },
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+  core::error::PlacementCenterError,
+  openraft::route::AppResponseData,
+  route::{
+    amqp::{binding_info, exchange_info, queue_info, vhost_info},
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+  },
+  storage::{
+    amqp::{
+      binding::AmqpBindingStorage, exchange::AmqpExchangeStorage, queue::AmqpQueueStorage,
+      vhost::AmqpVhostStorage,
+    },
+    rocksdb::RocksDBEngine,
+  },
+};
+
+pub struct GrpcAmqpServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl GrpcAmqpServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+  ) -> Self {
+    return GrpcAmqpServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+    };
+  }
+}
+
+#[tonic::async_trait]
+impl AmqpService for GrpcAmqpServices {
+  async fn create_vhost(
+    &self,
+    request: Request<CreateVhostRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+
+    let vhost = AmqpVhost {
+      cluster_name: req.cluster_name,
+      vhost: req.vhost,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::AmqpCreateVhost,
+      AmqpVhost::encode_to_vec(&vhost),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Conflict(message) => return Err(Status::already_exists(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_vhost(
+    &self,
+    request: Request<DeleteVhostRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+
+    let data = StorageData::new(
+      StorageDataType::AmqpDeleteVhost,
+      DeleteVhostRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(vhost_not_found(req.cluster_name, req.vhost)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_vhost(
+    &self,
+    request: Request<ListVhostRequest>,
+  ) -> Result<Response<ListVhostReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let vhost_storage = AmqpVhostStorage::new(self.rocksdb_engine_handler.clone());
+    match vhost_storage.list(&req.cluster_name) {
+      Ok(vhosts) => {
+        return Ok(Response::new(ListVhostReply {
+          vhosts: vhosts.into_iter().map(vhost_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn create_exchange(
+    &self,
+    request: Request<CreateExchangeRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+    validate_name("Exchange", &req.exchange_name)?;
+    if ExchangeType::try_from(req.exchange_type).is_err() {
+      return Err(Status::invalid_argument(format!(
+        "Unknown exchange type {}",
+        req.exchange_type
+      )));
+    }
+
+    let exchange = AmqpExchange {
+      cluster_name: req.cluster_name,
+      vhost: req.vhost,
+      exchange_name: req.exchange_name,
+      exchange_type: req.exchange_type,
+      durable: req.durable,
+      auto_delete: req.auto_delete,
+      internal: req.internal,
+      arguments: req.arguments,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::AmqpCreateExchange,
+      AmqpExchange::encode_to_vec(&exchange),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(vhost_not_found(exchange.cluster_name, exchange.vhost));
+      }
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_exchange(
+    &self,
+    request: Request<DeleteExchangeRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+    validate_name("Exchange", &req.exchange_name)?;
+
+    let data = StorageData::new(
+      StorageDataType::AmqpDeleteExchange,
+      DeleteExchangeRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::AmqpExchangeNotFound(req.vhost, req.exchange_name).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_exchange(
+    &self,
+    request: Request<ListExchangeRequest>,
+  ) -> Result<Response<ListExchangeReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+
+    let exchange_storage = AmqpExchangeStorage::new(self.rocksdb_engine_handler.clone());
+    match exchange_storage.list(&req.cluster_name, &req.vhost) {
+      Ok(exchanges) => {
+        return Ok(Response::new(ListExchangeReply {
+          exchanges: exchanges.into_iter().map(exchange_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn create_queue(
+    &self,
+    request: Request<CreateQueueRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+    validate_name("Queue", &req.queue_name)?;
+
+    let queue = AmqpQueue {
+      cluster_name: req.cluster_name,
+      vhost: req.vhost,
+      queue_name: req.queue_name,
+      durable: req.durable,
+      exclusive: req.exclusive,
+      auto_delete: req.auto_delete,
+      arguments: req.arguments,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::AmqpCreateQueue,
+      AmqpQueue::encode_to_vec(&queue),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(vhost_not_found(queue.cluster_name, queue.vhost)),
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_queue(
+    &self,
+    request: Request<DeleteQueueRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+    validate_name("Queue", &req.queue_name)?;
+
+    let data = StorageData::new(
+      StorageDataType::AmqpDeleteQueue,
+      DeleteQueueRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::AmqpQueueNotFound(req.vhost, req.queue_name).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_queue(
+    &self,
+    request: Request<ListQueueRequest>,
+  ) -> Result<Response<ListQueueReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+
+    let queue_storage = AmqpQueueStorage::new(self.rocksdb_engine_handler.clone());
+    match queue_storage.list(&req.cluster_name, &req.vhost) {
+      Ok(queues) => {
+        return Ok(Response::new(ListQueueReply {
+          queues: queues.into_iter().map(queue_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn create_binding(
+    &self,
+    request: Request<CreateBindingRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+    validate_name("Exchange", &req.exchange_name)?;
+    validate_name("Queue", &req.queue_name)?;
+    validate_routing_key(&req.routing_key)?;
+
+    let binding = AmqpBinding {
+      cluster_name: req.cluster_name,
+      vhost: req.vhost,
+      exchange_name: req.exchange_name,
+      queue_name: req.queue_name,
+      routing_key: req.routing_key,
+      arguments: req.arguments,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::AmqpCreateBinding,
+      AmqpBinding::encode_to_vec(&binding),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(vhost_not_found(binding.cluster_name, binding.vhost));
+      }
+      // 绑定的 exchange 或 queue 不存在
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_binding(
+    &self,
+    request: Request<DeleteBindingRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+    validate_name("Exchange", &req.exchange_name)?;
+    validate_name("Queue", &req.queue_name)?;
+    validate_routing_key(&req.routing_key)?;
+
+    let data = StorageData::new(
+      StorageDataType::AmqpDeleteBinding,
+      DeleteBindingRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::AmqpBindingNotFound(
+            req.vhost,
+            req.exchange_name,
+            req.queue_name,
+            req.routing_key,
+          )
+          .to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_binding(
+    &self,
+    request: Request<ListBindingRequest>,
+  ) -> Result<Response<ListBindingReply>, Status> {
+    let req = request.into_inner();
+    validate_vhost(&req.cluster_name, &req.vhost)?;
+
+    let binding_storage = AmqpBindingStorage::new(self.rocksdb_engine_handler.clone());
+    match binding_storage.list(
+      &req.cluster_name,
+      &req.vhost,
+      &req.exchange_name,
+      &req.queue_name,
+    ) {
+      Ok(bindings) => {
+        return Ok(Response::new(ListBindingReply {
+          bindings: bindings.into_iter().map(binding_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+}
+
+fn validate_vhost(cluster_name: &str, vhost: &str) -> Result<(), Status> {
+  if cluster_name.is_empty() || vhost.is_empty() {
+    return Err(
+      PlacementCenterError::RequestParamsNotEmpty("cluster_name or vhost".to_string()).into(),
+    );
+  }
+  return Ok(());
+}
+
+// exchange 和 queue 的名称是存储 key 的一部分，不能包含 /
+fn validate_name(kind: &str, name: &str) -> Result<(), Status> {
+  if name.is_empty() || name.contains('/') {
+    return Err(Status::invalid_argument(
+      PlacementCenterError::AmqpNameInvalid(kind.to_string(), name.to_string()).to_string(),
+    ));
+  }
+  return Ok(());
+}
+
+// routing_key 可以为空，但同样不能包含 /
+fn validate_routing_key(routing_key: &str) -> Result<(), Status> {
+  if routing_key.contains('/') {
+    return Err(Status::invalid_argument(format!(
+      "Routing key {} is invalid, it cannot contain /",
+      routing_key
+    )));
+  }
+  return Ok(());
+}
+
+fn vhost_not_found(cluster_name: String, vhost: String) -> Status {
+  return Status::not_found(
+    PlacementCenterError::AmqpVhostNotFound(cluster_name, vhost).to_string(),
+  );
+}
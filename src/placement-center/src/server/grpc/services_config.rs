@@ -0,0 +1,289 @@
+use std::{pin::Pin, sync::Arc};
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::{
+  CommonReply,
+  config::{
+    ClusterConfigEventType as ConfigEventType, DeleteClusterConfigRequest, GetClusterConfigReply,
+    GetClusterConfigRequest, ListClusterConfigReply, ListClusterConfigRequest,
+    SetClusterConfigReply, SetClusterConfigRequest, WatchClusterConfigReply,
+    WatchClusterConfigRequest, cluster_config_service_server::ClusterConfigService,
+  },
+};
+use serde_json::Value;
+use tokio::{
+  select,
+  sync::{broadcast::error::RecvError, mpsc},
+};
+use tokio_stream::{Stream, wrappers::ReceiverStream};
+use tonic::{Request, Response, Status};
+
+use super::services_kv::STREAM_CHANNEL_BUFFER;
+use crate::{
+  core::{
+    config::{ClusterConfigEvent, ClusterConfigEventType, ClusterConfigWatchHub},
+    error::PlacementCenterError,
+  },
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    config::cluster_config_info,
+    data::{StorageData, StorageDataType},
+  },
+  storage::{cluster::ClusterStorage, config::ClusterConfigStorage, rocksdb::RocksDBEngine},
+};
+
+pub struct GrpcClusterConfigServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  config_watch_hub: Arc<ClusterConfigWatchHub>,
+}
+
+impl GrpcClusterConfigServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    config_watch_hub: Arc<ClusterConfigWatchHub>,
+  ) -> Self {
+    return GrpcClusterConfigServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+      config_watch_hub,
+    };
+  }
+}
+
+#[tonic::async_trait]
+impl ClusterConfigService for GrpcClusterConfigServices {
+  type WatchClusterConfigStream =
+    Pin<Box<dyn Stream<Item = Result<WatchClusterConfigReply, Status>> + Send>>;
+
+  async fn set_cluster_config(
+    &self,
+    request: Request<SetClusterConfigRequest>,
+  ) -> Result<Response<SetClusterConfigReply>, Status> {
+    let mut req = request.into_inner();
+    validate_resource_type(&req.cluster_name, &req.resource_type)?;
+    // 配置由 Broker 解析，只接受 JSON 对象
+    match serde_json::from_str::<Value>(&req.config) {
+      Ok(Value::Object(_)) => {}
+      Ok(_) => {
+        return Err(Status::invalid_argument(
+          PlacementCenterError::ClusterConfigInvalid(
+            req.resource_type,
+            "config must be a JSON object".to_string(),
+          )
+          .to_string(),
+        ));
+      }
+      Err(e) => {
+        return Err(Status::invalid_argument(
+          PlacementCenterError::ClusterConfigInvalid(req.resource_type, e.to_string()).to_string(),
+        ));
+      }
+    }
+
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    match cluster_storage.get_cluster(&req.cluster_name) {
+      Ok(Some(_)) => {}
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::ClusterNotFound(req.cluster_name).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+
+    // 版本号为该写请求的日志索引，通过写请求的响应数据返回
+    req.update_time = now_second();
+    let data = StorageData::new(
+      StorageDataType::ClusterConfigSet,
+      SetClusterConfigRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Value(value) => match SetClusterConfigReply::decode(value.as_ref()) {
+        Ok(reply) => return Ok(Response::new(reply)),
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      },
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => {
+        return Err(Status::internal(
+          "Set cluster config returned an empty response",
+        ));
+      }
+    }
+  }
+
+  async fn get_cluster_config(
+    &self,
+    request: Request<GetClusterConfigRequest>,
+  ) -> Result<Response<GetClusterConfigReply>, Status> {
+    let req = request.into_inner();
+    validate_resource_type(&req.cluster_name, &req.resource_type)?;
+
+    let config_storage = ClusterConfigStorage::new(self.rocksdb_engine_handler.clone());
+    match config_storage.get(&req.cluster_name, &req.resource_type) {
+      Ok(Some(config)) => {
+        return Ok(Response::new(GetClusterConfigReply {
+          config: Some(cluster_config_info(config)),
+        }));
+      }
+      Ok(None) => return Err(config_not_found(req.cluster_name, req.resource_type)),
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn delete_cluster_config(
+    &self,
+    request: Request<DeleteClusterConfigRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_resource_type(&req.cluster_name, &req.resource_type)?;
+
+    let data = StorageData::new(
+      StorageDataType::ClusterConfigDelete,
+      DeleteClusterConfigRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(config_not_found(req.cluster_name, req.resource_type));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_cluster_config(
+    &self,
+    request: Request<ListClusterConfigRequest>,
+  ) -> Result<Response<ListClusterConfigReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let config_storage = ClusterConfigStorage::new(self.rocksdb_engine_handler.clone());
+    match config_storage.list(&req.cluster_name) {
+      Ok(configs) => {
+        return Ok(Response::new(ListClusterConfigReply {
+          configs: configs.into_iter().map(cluster_config_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  // 先推送集群当前的全部配置，再推送之后的变更。节点需要记录每类资源已经应用的版本号，
+  // 丢弃版本号不大于已应用版本的事件
+  async fn watch_cluster_config(
+    &self,
+    request: Request<WatchClusterConfigRequest>,
+  ) -> Result<Response<Self::WatchClusterConfigStream>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    match cluster_storage.get_node(&req.cluster_name, req.node_id) {
+      Ok(Some(_)) => {}
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::NodeNotFound(req.cluster_name, req.node_id).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+
+    // 先订阅再读取当前配置，读取期间的变更会在之后重复推送一次
+    let mut receiver = self.config_watch_hub.subscribe();
+    let config_storage = ClusterConfigStorage::new(self.rocksdb_engine_handler.clone());
+    let configs = match config_storage.list(&req.cluster_name) {
+      Ok(configs) => configs,
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    };
+
+    let (tx, rx) = mpsc::channel(STREAM_CHANNEL_BUFFER);
+    tokio::spawn(async move {
+      for config in configs {
+        let event = ClusterConfigEvent {
+          event_type: ClusterConfigEventType::Set,
+          config,
+        };
+        if tx.send(Ok(event_to_reply(event))).await.is_err() {
+          return;
+        }
+      }
+
+      loop {
+        select! {
+          val = receiver.recv() => {
+            match val {
+              Ok(event) => {
+                if event.config.cluster_name != req.cluster_name {
+                  continue;
+                }
+                if tx.send(Ok(event_to_reply(event))).await.is_err() {
+                  return;
+                }
+              }
+              // 消费过慢导致事件丢失，通知节点重新订阅以重新读取全部配置
+              Err(RecvError::Lagged(_)) => {
+                let _ = tx
+                  .send(Err(Status::data_loss(
+                    "Config watch stream lagged behind, resubscribe to reload configs",
+                  )))
+                  .await;
+                return;
+              }
+              Err(RecvError::Closed) => return,
+            }
+          }
+          // 节点断开连接
+          _ = tx.closed() => return,
+        }
+      }
+    });
+
+    Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+  }
+}
+
+// resource_type 是存储 key 的一部分，不能包含 /
+fn validate_resource_type(cluster_name: &str, resource_type: &str) -> Result<(), Status> {
+  if cluster_name.is_empty() || resource_type.is_empty() {
+    return Err(
+      PlacementCenterError::RequestParamsNotEmpty("cluster_name or resource_type".to_string())
+        .into(),
+    );
+  }
+  if resource_type.contains('/') {
+    return Err(Status::invalid_argument(
+      PlacementCenterError::ClusterConfigInvalid(
+        resource_type.to_string(),
+        "resource type cannot contain /".to_string(),
+      )
+      .to_string(),
+    ));
+  }
+  return Ok(());
+}
+
+fn config_not_found(cluster_name: String, resource_type: String) -> Status {
+  return Status::not_found(
+    PlacementCenterError::ClusterConfigNotFound(cluster_name, resource_type).to_string(),
+  );
+}
+
+fn event_to_reply(event: ClusterConfigEvent) -> WatchClusterConfigReply {
+  let event_type = match event.event_type {
+    ClusterConfigEventType::Set => ConfigEventType::Set,
+    ClusterConfigEventType::Delete => ConfigEventType::Delete,
+  };
+  WatchClusterConfigReply {
+    event_type: event_type.into(),
+    config: Some(cluster_config_info(event.config)),
+  }
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::id::{
+  AllocateIdsData, AllocateIdsReply, AllocateIdsRequest, GetNextIdReply, GetNextIdRequest,
+  id_service_server::IdService,
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+  core::error::PlacementCenterError,
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+    id::FIRST_ID,
+  },
+  storage::{id::IdStorage, rocksdb::RocksDBEngine},
+};
+
+// 单次最多分配的 ID 个数，避免一次请求耗尽大量 ID
+const MAX_ALLOCATE_COUNT: u64 = 1_000_000;
+
+pub struct GrpcIdServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl GrpcIdServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+  ) -> Self {
+    return GrpcIdServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+    };
+  }
+}
+
+#[tonic::async_trait]
+impl IdService for GrpcIdServices {
+  async fn allocate_ids(
+    &self,
+    request: Request<AllocateIdsRequest>,
+  ) -> Result<Response<AllocateIdsReply>, Status> {
+    let req = request.into_inner();
+    validate_namespace(&req.namespace)?;
+    if req.count == 0 || req.count > MAX_ALLOCATE_COUNT {
+      return Err(Status::invalid_argument(format!(
+        "Count {} is invalid, it must be between 1 and {}",
+        req.count, MAX_ALLOCATE_COUNT
+      )));
+    }
+
+    // 分配的区间由状态机决定，通过写请求的响应数据返回
+    let data = AllocateIdsData {
+      request: Some(req),
+      update_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::IdAllocate,
+      AllocateIdsData::encode_to_vec(&data),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Value(value) => match AllocateIdsReply::decode(value.as_ref()) {
+        Ok(reply) => return Ok(Response::new(reply)),
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      },
+      AppResponseData::Conflict(message) => return Err(Status::resource_exhausted(message)),
+      _ => return Err(Status::internal("Allocate ids returned an empty response")),
+    }
+  }
+
+  async fn get_next_id(
+    &self,
+    request: Request<GetNextIdRequest>,
+  ) -> Result<Response<GetNextIdReply>, Status> {
+    let req = request.into_inner();
+    validate_namespace(&req.namespace)?;
+
+    let id_storage = IdStorage::new(self.rocksdb_engine_handler.clone());
+    match id_storage.get(&req.namespace) {
+      Ok(namespace) => {
+        return Ok(Response::new(GetNextIdReply {
+          next_id: namespace.map_or(FIRST_ID, |namespace| namespace.next_id),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+}
+
+fn validate_namespace(namespace: &str) -> Result<(), Status> {
+  if namespace.is_empty() || namespace.contains('/') {
+    return Err(Status::invalid_argument(
+      PlacementCenterError::IdNamespaceInvalid(namespace.to_string()).to_string(),
+    ));
+  }
+  return Ok(());
+}
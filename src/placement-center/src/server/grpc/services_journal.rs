@@ -0,0 +1,418 @@
+use std::sync::Arc;
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::{
+  CommonReply,
+  journal::{
+    CommitOffsetRequest, CreateShardData, CreateShardReply, CreateShardRequest, FetchOffsetReply,
+    FetchOffsetRequest, GetActiveSegmentReply, GetActiveSegmentRequest, ListSegmentReply,
+    ListSegmentRequest, ListShardReply, ListShardRequest, SealSegmentData, SealSegmentReply,
+    SealSegmentRequest, SegmentInfo, SegmentStatus, ShardInfo, UpdateSegmentStatusRequest,
+    journal_service_server::JournalService,
+  },
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+  core::{
+    error::PlacementCenterError,
+    journal::journal_nodes,
+    segment_placement::{SegmentPlacement, SegmentPlacementPolicy, place_segment},
+  },
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+    journal::{segment_info, shard_info, shard_offset_info},
+  },
+  storage::{
+    journal::{offset::OffsetStorage, segment::SegmentStorage, shard::ShardStorage},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// Journal 节点使用的分片、Segment 元数据以及消费组 offset 服务，创建 Segment 的请求只能由 Leader 处理
+pub struct GrpcJournalServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  segment_placement: Arc<dyn SegmentPlacementPolicy>,
+}
+
+impl GrpcJournalServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    segment_placement: Arc<dyn SegmentPlacementPolicy>,
+  ) -> Self {
+    return GrpcJournalServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+      segment_placement,
+    };
+  }
+
+  // 为新的 Segment 选择副本，节点列表和 Segment 负载只在 Leader 上是最新的
+  fn place_segment(
+    &self,
+    cluster_name: &str,
+    replica_num: u32,
+  ) -> Result<SegmentPlacement, PlacementCenterError> {
+    self.raft_machine_apply.ensure_leader()?;
+    let nodes = journal_nodes(&self.rocksdb_engine_handler, cluster_name)?;
+    let segment_storage = SegmentStorage::new(self.rocksdb_engine_handler.clone());
+    let segments = segment_storage.list_by_cluster(cluster_name)?;
+    return place_segment(
+      self.segment_placement.as_ref(),
+      cluster_name,
+      &nodes,
+      &segments,
+      replica_num,
+    );
+  }
+}
+
+#[tonic::async_trait]
+impl JournalService for GrpcJournalServices {
+  async fn create_shard(
+    &self,
+    request: Request<CreateShardRequest>,
+  ) -> Result<Response<CreateShardReply>, Status> {
+    let req = request.into_inner();
+    validate_shard_name(&req.cluster_name, &req.namespace, &req.shard_name)?;
+    if req.replica_num == 0 {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("replica_num".to_string()).into());
+    }
+
+    let placement = self.place_segment(&req.cluster_name, req.replica_num)?;
+    let now = now_second();
+    let shard = ShardInfo {
+      cluster_name: req.cluster_name.clone(),
+      namespace: req.namespace.clone(),
+      shard_name: req.shard_name.clone(),
+      replica_num: req.replica_num,
+      active_segment_seq: 0,
+      create_time: now,
+    };
+    let segment = SegmentInfo {
+      cluster_name: req.cluster_name,
+      namespace: req.namespace,
+      shard_name: req.shard_name,
+      segment_seq: 0,
+      status: SegmentStatus::Write.into(),
+      leader: placement.leader,
+      replicas: placement.replicas,
+      start_offset: 0,
+      end_offset: -1,
+      create_time: now,
+    };
+    let data = CreateShardData {
+      shard: Some(shard.clone()),
+      segment: Some(segment.clone()),
+    };
+    let data = StorageData::new(
+      StorageDataType::JournalCreateShard,
+      CreateShardData::encode_to_vec(&data),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Conflict(message) => return Err(Status::already_exists(message)),
+      _ => {
+        return Ok(Response::new(CreateShardReply {
+          shard: Some(shard),
+          segment: Some(segment),
+        }));
+      }
+    }
+  }
+
+  async fn list_shard(
+    &self,
+    request: Request<ListShardRequest>,
+  ) -> Result<Response<ListShardReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let shard_storage = ShardStorage::new(self.rocksdb_engine_handler.clone());
+    match shard_storage.list(&req.cluster_name, &req.namespace) {
+      Ok(shards) => {
+        return Ok(Response::new(ListShardReply {
+          shards: shards.into_iter().map(shard_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn list_segment(
+    &self,
+    request: Request<ListSegmentRequest>,
+  ) -> Result<Response<ListSegmentReply>, Status> {
+    let req = request.into_inner();
+    validate_shard_name(&req.cluster_name, &req.namespace, &req.shard_name)?;
+
+    let segment_storage = SegmentStorage::new(self.rocksdb_engine_handler.clone());
+    match segment_storage.list(&req.cluster_name, &req.namespace, &req.shard_name) {
+      Ok(segments) => {
+        return Ok(Response::new(ListSegmentReply {
+          segments: segments.into_iter().map(segment_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn get_active_segment(
+    &self,
+    request: Request<GetActiveSegmentRequest>,
+  ) -> Result<Response<GetActiveSegmentReply>, Status> {
+    let req = request.into_inner();
+    validate_shard_name(&req.cluster_name, &req.namespace, &req.shard_name)?;
+
+    let shard_storage = ShardStorage::new(self.rocksdb_engine_handler.clone());
+    let shard = match shard_storage.get(&req.cluster_name, &req.namespace, &req.shard_name) {
+      Ok(Some(shard)) => shard,
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::JournalShardNotFound(req.namespace, req.shard_name).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    };
+
+    let segment_storage = SegmentStorage::new(self.rocksdb_engine_handler.clone());
+    match segment_storage.get(
+      &req.cluster_name,
+      &req.namespace,
+      &req.shard_name,
+      shard.active_segment_seq,
+    ) {
+      Ok(Some(segment)) => {
+        return Ok(Response::new(GetActiveSegmentReply {
+          segment: Some(segment_info(segment)),
+        }));
+      }
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::JournalSegmentNotFound(
+            req.namespace,
+            req.shard_name,
+            shard.active_segment_seq,
+          )
+          .to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn update_segment_status(
+    &self,
+    request: Request<UpdateSegmentStatusRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_shard_name(&req.cluster_name, &req.namespace, &req.shard_name)?;
+    // 封存时需要同时确定 end_offset 并创建下一个 Segment
+    if req.status() == SegmentStatus::Sealed {
+      return Err(Status::invalid_argument(
+        "Segments must be sealed through SealSegment",
+      ));
+    }
+
+    let data = StorageData::new(
+      StorageDataType::JournalUpdateSegmentStatus,
+      UpdateSegmentStatusRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::JournalSegmentNotFound(
+            req.namespace,
+            req.shard_name,
+            req.segment_seq,
+          )
+          .to_string(),
+        ));
+      }
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn seal_segment(
+    &self,
+    request: Request<SealSegmentRequest>,
+  ) -> Result<Response<SealSegmentReply>, Status> {
+    let req = request.into_inner();
+    validate_shard_name(&req.cluster_name, &req.namespace, &req.shard_name)?;
+
+    let shard_storage = ShardStorage::new(self.rocksdb_engine_handler.clone());
+    let shard = match shard_storage.get(&req.cluster_name, &req.namespace, &req.shard_name) {
+      Ok(Some(shard)) => shard,
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::JournalShardNotFound(req.namespace, req.shard_name).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    };
+
+    let (next_seq, next_start_offset) = match (
+      req.segment_seq.checked_add(1),
+      req.end_offset.checked_add(1),
+    ) {
+      (Some(seq), Some(offset)) => (seq, offset),
+      _ => {
+        return Err(Status::invalid_argument(
+          PlacementCenterError::JournalSegmentOverflow(req.segment_seq, req.end_offset).to_string(),
+        ));
+      }
+    };
+
+    let placement = self.place_segment(&req.cluster_name, shard.replica_num)?;
+    let next_segment = SegmentInfo {
+      cluster_name: req.cluster_name.clone(),
+      namespace: req.namespace.clone(),
+      shard_name: req.shard_name.clone(),
+      segment_seq: next_seq,
+      status: SegmentStatus::Write.into(),
+      leader: placement.leader,
+      replicas: placement.replicas,
+      start_offset: next_start_offset,
+      end_offset: -1,
+      create_time: now_second(),
+    };
+    let data = SealSegmentData {
+      seal: Some(req.clone()),
+      next_segment: Some(next_segment.clone()),
+    };
+    let data = StorageData::new(
+      StorageDataType::JournalSealSegment,
+      SealSegmentData::encode_to_vec(&data),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::JournalSegmentNotFound(
+            req.namespace,
+            req.shard_name,
+            req.segment_seq,
+          )
+          .to_string(),
+        ));
+      }
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => {
+        return Ok(Response::new(SealSegmentReply {
+          segment: Some(next_segment),
+        }));
+      }
+    }
+  }
+
+  async fn commit_offset(
+    &self,
+    request: Request<CommitOffsetRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let mut req = request.into_inner();
+    if req.cluster_name.is_empty() || req.offsets.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or offsets".to_string()).into(),
+      );
+    }
+    validate_name(&req.group_name)?;
+
+    // 只允许提交已经存在的分片的 offset
+    let shard_storage = ShardStorage::new(self.rocksdb_engine_handler.clone());
+    let now = now_second();
+    for offset in req.offsets.iter_mut() {
+      validate_shard_name(&req.cluster_name, &offset.namespace, &offset.shard_name)?;
+      match shard_storage.get(&req.cluster_name, &offset.namespace, &offset.shard_name) {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+          return Err(Status::not_found(
+            PlacementCenterError::JournalShardNotFound(
+              offset.namespace.clone(),
+              offset.shard_name.clone(),
+            )
+            .to_string(),
+          ));
+        }
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      }
+      offset.commit_time = now;
+    }
+
+    let data = StorageData::new(
+      StorageDataType::JournalCommitOffset,
+      CommitOffsetRequest::encode_to_vec(&req),
+    );
+    self.raft_machine_apply.client_write(data).await?;
+    return Ok(Response::new(CommonReply::default()));
+  }
+
+  async fn fetch_offset(
+    &self,
+    request: Request<FetchOffsetRequest>,
+  ) -> Result<Response<FetchOffsetReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+    validate_name(&req.group_name)?;
+
+    let offset_storage = OffsetStorage::new(self.rocksdb_engine_handler.clone());
+    if req.shards.is_empty() {
+      match offset_storage.list(&req.cluster_name, &req.group_name) {
+        Ok(offsets) => {
+          return Ok(Response::new(FetchOffsetReply {
+            offsets: offsets.into_iter().map(shard_offset_info).collect(),
+          }));
+        }
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      }
+    }
+
+    let mut offsets = Vec::with_capacity(req.shards.len());
+    for shard in req.shards {
+      validate_shard_name(&req.cluster_name, &shard.namespace, &shard.shard_name)?;
+      match offset_storage.get(
+        &req.cluster_name,
+        &req.group_name,
+        &shard.namespace,
+        &shard.shard_name,
+      ) {
+        Ok(Some(offset)) => offsets.push(shard_offset_info(offset)),
+        Ok(None) => {}
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      }
+    }
+    return Ok(Response::new(FetchOffsetReply { offsets }));
+  }
+}
+
+// namespace、shard_name 和 group_name 是存储 key 的一部分，不能包含 /
+fn validate_shard_name(
+  cluster_name: &str,
+  namespace: &str,
+  shard_name: &str,
+) -> Result<(), Status> {
+  if cluster_name.is_empty() {
+    return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+  }
+  validate_name(namespace)?;
+  return validate_name(shard_name);
+}
+
+fn validate_name(name: &str) -> Result<(), Status> {
+  if name.is_empty() || name.contains('/') {
+    return Err(Status::invalid_argument(
+      PlacementCenterError::JournalNameInvalid(name.to_string()).to_string(),
+    ));
+  }
+  return Ok(());
+}
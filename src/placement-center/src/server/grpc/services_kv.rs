@@ -1,55 +1,324 @@
-use dashmap::DashMap;
+use std::{pin::Pin, sync::Arc};
 
+use prost::Message;
 use protocol::{
-  kv_service_server::KvService, CommonReply, DeleteRequest, ExistsReply, ExistsRequest, GetReply,
-  GetRequest, SetRequest,
+  CommonReply, CompactRequest, DeleteRequest, ExistsReply, ExistsRequest, GetReply, GetRequest,
+  LeaseGrantReply, LeaseGrantRequest, LeaseKeepAliveReply, LeaseKeepAliveRequest,
+  LeaseRevokeRequest, LeaseTimeToLiveReply, LeaseTimeToLiveRequest, ListByPrefixReply,
+  ListByPrefixRequest, ScanReply, ScanRequest, SetRequest, TxnReply, TxnRequest, WatchReply,
+  WatchRequest, kv_service_server::KvService, kv_v2::kv_service_v2_server::KvServiceV2,
 };
-use tonic::{Request, Response, Status};
+use tokio::sync::mpsc;
+use tokio_stream::{Stream, StreamExt, wrappers::ReceiverStream};
+use tonic::{Request, Response, Status, Streaming};
 
-#[derive(Debug, Default)]
+use crate::{
+  core::{error::PlacementCenterError, lease::LeaseManager, watch::KvWatchHub},
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+    lease::GENERATED_LEASE_ID_BASE,
+  },
+  storage::{kv::KvStorage, lease::LeaseStorage, rocksdb::RocksDBEngine},
+};
+
+// 每个流式请求在服务端缓冲的消息条数
+pub(super) const STREAM_CHANNEL_BUFFER: usize = 128;
+
+#[derive(Clone)]
 pub struct GrpcBrokerServices {
-  data: DashMap<String, String>,
+  pub(super) raft_machine_apply: Arc<RaftMachineApply>,
+  pub(super) rocksdb_engine_handler: Arc<RocksDBEngine>,
+  pub(super) kv_watch_hub: Arc<KvWatchHub>,
+  pub(super) lease_manager: Arc<LeaseManager>,
 }
 
 impl GrpcBrokerServices {
-  pub fn new() -> Self {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    kv_watch_hub: Arc<KvWatchHub>,
+    lease_manager: Arc<LeaseManager>,
+  ) -> Self {
     return GrpcBrokerServices {
-      data: DashMap::with_capacity(8),
+      raft_machine_apply,
+      rocksdb_engine_handler,
+      kv_watch_hub,
+      lease_manager,
     };
   }
 }
 
+/// KV 读写逻辑统一由 KvServiceV2 实现，v1 接口只负责字符串和二进制消息之间的转换
 #[tonic::async_trait]
 impl KvService for GrpcBrokerServices {
-  async fn set(
+  type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchReply, Status>> + Send>>;
+  type LeaseKeepAliveStream =
+    Pin<Box<dyn Stream<Item = Result<LeaseKeepAliveReply, Status>> + Send>>;
+
+  async fn set(&self, request: Request<SetRequest>) -> Result<Response<CommonReply>, Status> {
+    let req = convert(request.into_inner())?;
+    KvServiceV2::set(self, Request::new(req)).await
+  }
+
+  async fn delete(&self, request: Request<DeleteRequest>) -> Result<Response<CommonReply>, Status> {
+    let req = convert(request.into_inner())?;
+    KvServiceV2::delete(self, Request::new(req)).await
+  }
+
+  async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetReply>, Status> {
+    let req = convert(request.into_inner())?;
+    let reply = KvServiceV2::get(self, Request::new(req)).await?;
+    Ok(Response::new(convert(reply.into_inner())?))
+  }
+
+  async fn exists(&self, request: Request<ExistsRequest>) -> Result<Response<ExistsReply>, Status> {
+    let req = convert(request.into_inner())?;
+    let reply = KvServiceV2::exists(self, Request::new(req)).await?;
+    Ok(Response::new(convert(reply.into_inner())?))
+  }
+
+  async fn list_by_prefix(
+    &self,
+    request: Request<ListByPrefixRequest>,
+  ) -> Result<Response<ListByPrefixReply>, Status> {
+    let req = convert(request.into_inner())?;
+    let reply = KvServiceV2::list_by_prefix(self, Request::new(req)).await?;
+    Ok(Response::new(convert(reply.into_inner())?))
+  }
+
+  async fn scan(&self, request: Request<ScanRequest>) -> Result<Response<ScanReply>, Status> {
+    let req = convert(request.into_inner())?;
+    let reply = KvServiceV2::scan(self, Request::new(req)).await?;
+    Ok(Response::new(convert(reply.into_inner())?))
+  }
+
+  async fn watch(
+    &self,
+    request: Request<WatchRequest>,
+  ) -> Result<Response<Self::WatchStream>, Status> {
+    let req = convert(request.into_inner())?;
+    let stream = KvServiceV2::watch(self, Request::new(req))
+      .await?
+      .into_inner()
+      .map(|reply| reply.and_then(convert::<_, WatchReply>));
+    Ok(Response::new(Box::pin(stream)))
+  }
+
+  async fn txn(&self, request: Request<TxnRequest>) -> Result<Response<TxnReply>, Status> {
+    let req = convert(request.into_inner())?;
+    let reply = KvServiceV2::txn(self, Request::new(req)).await?;
+    Ok(Response::new(convert(reply.into_inner())?))
+  }
+
+  async fn compact(
     &self,
-    request: Request<SetRequest>,
+    request: Request<CompactRequest>,
   ) -> Result<Response<CommonReply>, Status> {
     let req = request.into_inner();
-    self.data.insert(req.key, req.value);
-    return Ok(Response::new(CommonReply::default()));
+    if req.revision == 0 {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("revision".to_string()).into());
+    }
+
+    let current = self.raft_machine_apply.last_applied_index();
+    if req.revision > current {
+      return Err(Status::out_of_range(
+        PlacementCenterError::KvFutureRevision(req.revision, current).to_string(),
+      ));
+    }
+
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    match kv_storage.compact_revision() {
+      Ok(compacted) if req.revision <= compacted => {
+        return Err(Status::out_of_range(
+          PlacementCenterError::KvRevisionCompacted(req.revision, compacted).to_string(),
+        ));
+      }
+      Ok(_) => {}
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+
+    let data = StorageData::new(
+      StorageDataType::KvCompact,
+      CompactRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      // 并发提交的更大版本的压缩已经先被应用
+      AppResponseData::Conflict(message) => return Err(Status::out_of_range(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
   }
 
-  async fn delete(&self, request: Request<DeleteRequest>) -> Result<Response<CommonReply>, Status> {
+  async fn lease_grant(
+    &self,
+    request: Request<LeaseGrantRequest>,
+  ) -> Result<Response<LeaseGrantReply>, Status> {
     let req = request.into_inner();
-    self.data.remove(&req.key);
-    return Ok(Response::new(CommonReply::default()));
+    if req.ttl <= 0 {
+      return Err(Status::invalid_argument("ttl must be greater than 0"));
+    }
+
+    // 该范围内的 ID 保留给服务端分配
+    if req.id >= GENERATED_LEASE_ID_BASE {
+      return Err(Status::invalid_argument(format!(
+        "Lease id must be less than {}",
+        GENERATED_LEASE_ID_BASE
+      )));
+    }
+
+    // 租约 ID 由状态机分配，通过写请求的响应数据返回，租约是否已经存在也由状态机判断
+    let data = StorageData::new(
+      StorageDataType::LeaseGrant,
+      LeaseGrantRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Value(value) => match LeaseGrantReply::decode(value.as_ref()) {
+        Ok(reply) => return Ok(Response::new(reply)),
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      },
+      AppResponseData::Conflict(message) => return Err(Status::already_exists(message)),
+      _ => return Err(Status::internal("Lease grant returned an empty response")),
+    }
   }
 
-  async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetReply>, Status> {
+  async fn lease_revoke(
+    &self,
+    request: Request<LeaseRevokeRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
     let req = request.into_inner();
-    if let Some(data) = self.data.get(&req.key) {
-      return Ok(Response::new(GetReply {
-        value: data.value().clone(),
-      }));
+    if req.id <= 0 {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("id".to_string()).into());
+    }
+
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    match lease_storage.get(req.id) {
+      Ok(Some(_)) => {}
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::LeaseNotFound(req.id).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+
+    let data = StorageData::new(
+      StorageDataType::LeaseRevoke,
+      LeaseRevokeRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      // 提交过程中租约已经过期被 Leader 撤销
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::LeaseNotFound(req.id).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
     }
-    return Ok(Response::new(GetReply::default()));
   }
 
-  async fn exists(&self, request: Request<ExistsRequest>) -> Result<Response<ExistsReply>, Status> {
+  async fn lease_keep_alive(
+    &self,
+    request: Request<Streaming<LeaseKeepAliveRequest>>,
+  ) -> Result<Response<Self::LeaseKeepAliveStream>, Status> {
+    // 过期时间只由 Leader 维护，续约必须发送到 Leader
+    if !self.raft_machine_apply.is_leader() {
+      return Err(Status::failed_precondition(
+        PlacementCenterError::LeaseNotLeader(self.raft_machine_apply.current_leader()).to_string(),
+      ));
+    }
+
+    let mut inbound = request.into_inner();
+    let raft_machine_apply = self.raft_machine_apply.clone();
+    let lease_manager = self.lease_manager.clone();
+    let (tx, rx) = mpsc::channel(STREAM_CHANNEL_BUFFER);
+    tokio::spawn(async move {
+      loop {
+        let req = match inbound.message().await {
+          Ok(Some(req)) => req,
+          Ok(None) => return,
+          Err(e) => {
+            let _ = tx.send(Err(e)).await;
+            return;
+          }
+        };
+
+        // 续约过程中发生切主时通知客户端重新连接新的 Leader
+        if !raft_machine_apply.is_leader() {
+          let _ = tx
+            .send(Err(Status::failed_precondition(
+              PlacementCenterError::LeaseNotLeader(raft_machine_apply.current_leader()).to_string(),
+            )))
+            .await;
+          return;
+        }
+
+        let ttl = lease_manager.keep_alive(req.id).unwrap_or(0);
+
+        if tx
+          .send(Ok(LeaseKeepAliveReply { id: req.id, ttl }))
+          .await
+          .is_err()
+        {
+          return;
+        }
+      }
+    });
+
+    Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+  }
+
+  async fn lease_time_to_live(
+    &self,
+    request: Request<LeaseTimeToLiveRequest>,
+  ) -> Result<Response<LeaseTimeToLiveReply>, Status> {
+    // 剩余时间只在 Leader 的 LeaseManager 中随续约更新，Follower 上的值可能已经过时
+    self.raft_machine_apply.ensure_leader()?;
+
     let req = request.into_inner();
-    return Ok(Response::new(ExistsReply {
-      flag: self.data.contains_key(&req.key),
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    let lease = match lease_storage.get(req.id) {
+      Ok(Some(lease)) => lease,
+      Ok(None) => {
+        return Ok(Response::new(LeaseTimeToLiveReply {
+          id: req.id,
+          ttl: -1,
+          ..Default::default()
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    };
+
+    // v1 接口的 key 为字符串，非 UTF-8 的 key 按有损方式转换
+    let keys = if req.keys {
+      lease
+        .keys
+        .iter()
+        .map(|key| String::from_utf8_lossy(key).to_string())
+        .collect()
+    } else {
+      Vec::new()
+    };
+    return Ok(Response::new(LeaseTimeToLiveReply {
+      id: lease.id,
+      ttl: self.lease_manager.remaining(lease.id).unwrap_or(0),
+      granted_ttl: lease.ttl,
+      keys,
     }));
   }
 }
+
+// kv 与 kv_v2 中同名消息的字段编号一致，string 和 bytes 的编码相同，重新编码即可完成转换。
+// bytes 转 string 时如果内容不是合法的 UTF-8 会解码失败，此时提示客户端改用 KvServiceV2
+fn convert<F: Message, T: Message + Default>(from: F) -> Result<T, Status> {
+  match T::decode(from.encode_to_vec().as_slice()) {
+    Ok(to) => Ok(to),
+    Err(e) => Err(Status::failed_precondition(format!(
+      "Key or value is not valid UTF-8, use KvServiceV2 instead: {}",
+      e
+    ))),
+  }
+}
@@ -0,0 +1,351 @@
+use std::{collections::HashSet, pin::Pin};
+
+use prost::Message;
+use protocol::{
+  CommonReply, WatchEventType,
+  kv_v2::{
+    DeleteRequest, ExistsReply, ExistsRequest, GetReply, GetRequest, KeyValue, ListByPrefixReply,
+    ListByPrefixRequest, RequestOp, ScanReply, ScanRequest, SetRequest, TxnReply, TxnRequest,
+    WatchReply, WatchRequest, kv_service_v2_server::KvServiceV2, request_op,
+  },
+};
+use tokio::{
+  select,
+  sync::{broadcast::error::RecvError, mpsc},
+};
+use tokio_stream::{Stream, wrappers::ReceiverStream};
+use tonic::{Request, Response, Status};
+
+use super::services_kv::{GrpcBrokerServices, STREAM_CHANNEL_BUFFER};
+use crate::{
+  core::{
+    error::PlacementCenterError,
+    watch::{KvWatchEvent, KvWatchEventType},
+  },
+  openraft::route::AppResponseData,
+  route::{
+    data::{StorageData, StorageDataType},
+    kv::get_reply,
+  },
+  storage::{
+    kv::{KvPage, KvRevisionRead, KvStorage},
+    lease::LeaseStorage,
+  },
+};
+
+#[tonic::async_trait]
+impl KvServiceV2 for GrpcBrokerServices {
+  type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchReply, Status>> + Send>>;
+
+  async fn set(&self, request: Request<SetRequest>) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.key.is_empty() || req.value.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("key or value".to_string()).into());
+    }
+
+    self.check_lease(req.lease_id)?;
+
+    // 写请求通过 Raft 复制，由状态机应用到 KvStorage
+    let data = StorageData::new(StorageDataType::KvSet, SetRequest::encode_to_vec(&req));
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      // 提交过程中租约被撤销，写入没有生效
+      AppResponseData::Conflict(message) => return Err(Status::aborted(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete(&self, request: Request<DeleteRequest>) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.key.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("key".to_string()).into());
+    }
+
+    let data = StorageData::new(
+      StorageDataType::KvDelete,
+      DeleteRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::KvKeyNotFound(String::from_utf8_lossy(&req.key).to_string())
+            .to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetReply>, Status> {
+    let req = request.into_inner();
+    if req.key.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("key".to_string()).into());
+    }
+
+    self
+      .raft_machine_apply
+      .ensure_read_consistency(req.consistency())
+      .await?;
+
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    if req.revision == 0 {
+      match kv_storage.get_entry(&req.key) {
+        Ok(entry) => return Ok(Response::new(get_reply(entry))),
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      }
+    }
+
+    match kv_storage.get_at_revision(&req.key, req.revision) {
+      Ok(KvRevisionRead::Value(entry)) => return Ok(Response::new(get_reply(entry))),
+      Ok(KvRevisionRead::Compacted(compacted)) => {
+        return Err(Status::out_of_range(
+          PlacementCenterError::KvRevisionCompacted(req.revision, compacted).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn exists(&self, request: Request<ExistsRequest>) -> Result<Response<ExistsReply>, Status> {
+    let req = request.into_inner();
+    if req.key.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("key".to_string()).into());
+    }
+
+    self
+      .raft_machine_apply
+      .ensure_read_consistency(req.consistency())
+      .await?;
+
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    match kv_storage.exists(&req.key) {
+      Ok(flag) => return Ok(Response::new(ExistsReply { flag })),
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn list_by_prefix(
+    &self,
+    request: Request<ListByPrefixRequest>,
+  ) -> Result<Response<ListByPrefixReply>, Status> {
+    let req = request.into_inner();
+    if req.prefix.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("prefix".to_string()).into());
+    }
+
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    match kv_storage.list_by_prefix(req.prefix, req.limit as usize, non_empty(req.page_token)) {
+      Ok(page) => {
+        let (kvs, next_page_token) = page_to_reply(page);
+        return Ok(Response::new(ListByPrefixReply {
+          kvs,
+          next_page_token,
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn scan(&self, request: Request<ScanRequest>) -> Result<Response<ScanReply>, Status> {
+    let req = request.into_inner();
+    if !req.end_key.is_empty() && req.start_key >= req.end_key {
+      return Err(Status::invalid_argument(
+        "start_key must be less than end_key",
+      ));
+    }
+
+    let kv_storage = KvStorage::new(self.rocksdb_engine_handler.clone());
+    match kv_storage.scan(
+      req.start_key,
+      non_empty(req.end_key),
+      req.limit as usize,
+      req.reverse,
+      non_empty(req.page_token),
+    ) {
+      Ok(page) => {
+        let (kvs, next_page_token) = page_to_reply(page);
+        return Ok(Response::new(ScanReply {
+          kvs,
+          next_page_token,
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn watch(
+    &self,
+    request: Request<WatchRequest>,
+  ) -> Result<Response<Self::WatchStream>, Status> {
+    let req = request.into_inner();
+    if req.key.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("key".to_string()).into());
+    }
+
+    let (history, mut receiver) = match self.kv_watch_hub.subscribe(req.start_index) {
+      Ok(data) => data,
+      Err(e) => return Err(Status::out_of_range(e.to_string())),
+    };
+
+    let (tx, rx) = mpsc::channel(STREAM_CHANNEL_BUFFER);
+    tokio::spawn(async move {
+      // 先补发历史变更，再转发订阅之后的实时变更
+      let mut last_index = req.start_index.saturating_sub(1);
+      for event in history {
+        if !watch_match(&req, &event) {
+          continue;
+        }
+        last_index = event.index;
+        if tx.send(Ok(event_to_reply(event))).await.is_err() {
+          return;
+        }
+      }
+
+      loop {
+        select! {
+          val = receiver.recv() => {
+            match val {
+              Ok(event) => {
+                if event.index < req.start_index || !watch_match(&req, &event) {
+                  continue;
+                }
+                last_index = event.index;
+                if tx.send(Ok(event_to_reply(event))).await.is_err() {
+                  return;
+                }
+              }
+              // 消费过慢导致事件丢失，通知客户端从最后收到的索引之后重新订阅
+              Err(RecvError::Lagged(_)) => {
+                let _ = tx
+                  .send(Err(Status::data_loss(format!(
+                    "Watch stream lagged behind, resume from index {}",
+                    last_index + 1
+                  ))))
+                  .await;
+                return;
+              }
+              Err(RecvError::Closed) => return,
+            }
+          }
+          // 客户端断开连接
+          _ = tx.closed() => return,
+        }
+      }
+    });
+
+    Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+  }
+
+  async fn txn(&self, request: Request<TxnRequest>) -> Result<Response<TxnReply>, Status> {
+    let req = request.into_inner();
+    if req.compare.iter().any(|compare| compare.key.is_empty()) {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("compare key".to_string()).into());
+    }
+    self.check_txn_ops(&req.success)?;
+    self.check_txn_ops(&req.failure)?;
+
+    // 比较和执行都在状态机中完成，保证与其它写请求之间的原子性
+    let data = StorageData::new(StorageDataType::KvTxn, TxnRequest::encode_to_vec(&req));
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Value(value) => match TxnReply::decode(value.as_ref()) {
+        Ok(reply) => return Ok(Response::new(reply)),
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      },
+      _ => return Err(Status::internal("Txn returned an empty response")),
+    }
+  }
+}
+
+impl GrpcBrokerServices {
+  // 写入绑定租约的 key 前检查租约是否存在
+  fn check_lease(&self, lease_id: i64) -> Result<(), Status> {
+    if lease_id <= 0 {
+      return Ok(());
+    }
+
+    let lease_storage = LeaseStorage::new(self.rocksdb_engine_handler.clone());
+    match lease_storage.get(lease_id) {
+      Ok(Some(_)) => Ok(()),
+      Ok(None) => Err(Status::not_found(
+        PlacementCenterError::LeaseNotFound(lease_id).to_string(),
+      )),
+      Err(e) => Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  // 校验事务分支中的操作，同一个分支内不允许重复写同一个 key
+  fn check_txn_ops(&self, ops: &[RequestOp]) -> Result<(), Status> {
+    let mut write_keys = HashSet::new();
+    for op in ops {
+      let key = match &op.request {
+        Some(request_op::Request::Set(set)) => {
+          if set.key.is_empty() || set.value.is_empty() {
+            return Err(
+              PlacementCenterError::RequestParamsNotEmpty("key or value".to_string()).into(),
+            );
+          }
+          self.check_lease(set.lease_id)?;
+          &set.key
+        }
+        Some(request_op::Request::Delete(delete)) => &delete.key,
+        Some(request_op::Request::Get(get)) => {
+          if get.key.is_empty() {
+            return Err(PlacementCenterError::RequestParamsNotEmpty("key".to_string()).into());
+          }
+          continue;
+        }
+        None => {
+          return Err(PlacementCenterError::RequestParamsNotEmpty("request".to_string()).into());
+        }
+      };
+
+      if key.is_empty() {
+        return Err(PlacementCenterError::RequestParamsNotEmpty("key".to_string()).into());
+      }
+      if !write_keys.insert(key.clone()) {
+        return Err(Status::invalid_argument(format!(
+          "Duplicate key {} given in txn request",
+          String::from_utf8_lossy(key)
+        )));
+      }
+    }
+    Ok(())
+  }
+}
+
+fn watch_match(req: &WatchRequest, event: &KvWatchEvent) -> bool {
+  if req.prefix {
+    event.key.starts_with(&req.key)
+  } else {
+    event.key == req.key
+  }
+}
+
+fn event_to_reply(event: KvWatchEvent) -> WatchReply {
+  let event_type = match event.event_type {
+    KvWatchEventType::Put => WatchEventType::Put,
+    KvWatchEventType::Delete => WatchEventType::Delete,
+  };
+  WatchReply {
+    event_type: event_type.into(),
+    key: event.key,
+    value: event.value,
+    index: event.index,
+  }
+}
+
+// proto3 的 bytes 没有 None，空值表示未设置
+fn non_empty(value: Vec<u8>) -> Option<Vec<u8>> {
+  if value.is_empty() { None } else { Some(value) }
+}
+
+fn page_to_reply(page: KvPage) -> (Vec<KeyValue>, Vec<u8>) {
+  let kvs = page
+    .kvs
+    .into_iter()
+    .map(|(key, value)| KeyValue { key, value })
+    .collect();
+  (kvs, page.next_page_token.unwrap_or_default())
+}
@@ -0,0 +1,433 @@
+use std::sync::Arc;
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::{
+  CommonReply,
+  mqtt::{
+    CreateTopicRequest, CreateUserRequest, DeleteSessionRequest, DeleteTopicRequest,
+    DeleteUserRequest, GetSessionReply, GetSessionRequest, ListSessionReply, ListSessionRequest,
+    ListTopicReply, ListTopicRequest, ListUserReply, ListUserRequest, MqttSession, MqttTopic,
+    MqttUser, RetainMessage, SaveSessionRequest, SetTopicRetainMessageRequest,
+    mqtt_service_server::MqttService,
+  },
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+  core::{error::PlacementCenterError, mqtt::check_mqtt_cluster},
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+    mqtt::{mqtt_session, mqtt_topic, mqtt_user},
+  },
+  storage::{
+    mqtt::{session::MqttSessionStorage, topic::MqttTopicStorage, user::MqttUserStorage},
+    rocksdb::RocksDBEngine,
+  },
+};
+
+pub struct GrpcMqttServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl GrpcMqttServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+  ) -> Self {
+    return GrpcMqttServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+    };
+  }
+}
+
+#[tonic::async_trait]
+impl MqttService for GrpcMqttServices {
+  async fn create_user(
+    &self,
+    request: Request<CreateUserRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.user_name.is_empty() || req.password_hash.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty(
+          "cluster_name, user_name or password_hash".to_string(),
+        )
+        .into(),
+      );
+    }
+    validate_mqtt_cluster(&self.rocksdb_engine_handler, &req.cluster_name)?;
+
+    let user = MqttUser {
+      cluster_name: req.cluster_name,
+      user_name: req.user_name,
+      password_hash: req.password_hash,
+      is_superuser: req.is_superuser,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::MqttCreateUser,
+      MqttUser::encode_to_vec(&user),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Conflict(message) => return Err(Status::already_exists(message)),
+      AppResponseData::NotFound => return Err(mqtt_cluster_not_found(user.cluster_name)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_user(
+    &self,
+    request: Request<DeleteUserRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.user_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or user_name".to_string()).into(),
+      );
+    }
+
+    let data = StorageData::new(
+      StorageDataType::MqttDeleteUser,
+      DeleteUserRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::MqttUserNotFound(req.cluster_name, req.user_name).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_user(
+    &self,
+    request: Request<ListUserRequest>,
+  ) -> Result<Response<ListUserReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let user_storage = MqttUserStorage::new(self.rocksdb_engine_handler.clone());
+    let users = if req.user_name.is_empty() {
+      user_storage.list(&req.cluster_name)
+    } else {
+      user_storage
+        .get(&req.cluster_name, &req.user_name)
+        .map(|user| user.into_iter().collect())
+    };
+    match users {
+      Ok(users) => {
+        return Ok(Response::new(ListUserReply {
+          users: users.into_iter().map(mqtt_user).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn create_topic(
+    &self,
+    request: Request<CreateTopicRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.topic_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or topic_name".to_string())
+          .into(),
+      );
+    }
+    validate_mqtt_cluster(&self.rocksdb_engine_handler, &req.cluster_name)?;
+
+    let topic = MqttTopic {
+      cluster_name: req.cluster_name,
+      topic_name: req.topic_name,
+      retain_message: None,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::MqttCreateTopic,
+      MqttTopic::encode_to_vec(&topic),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Conflict(message) => return Err(Status::already_exists(message)),
+      AppResponseData::NotFound => return Err(mqtt_cluster_not_found(topic.cluster_name)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_topic(
+    &self,
+    request: Request<DeleteTopicRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.topic_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or topic_name".to_string())
+          .into(),
+      );
+    }
+
+    let data = StorageData::new(
+      StorageDataType::MqttDeleteTopic,
+      DeleteTopicRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::MqttTopicNotFound(req.cluster_name, req.topic_name).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_topic(
+    &self,
+    request: Request<ListTopicRequest>,
+  ) -> Result<Response<ListTopicReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let topic_storage = MqttTopicStorage::new(self.rocksdb_engine_handler.clone());
+    let topics = if req.topic_name.is_empty() {
+      topic_storage.list(&req.cluster_name)
+    } else {
+      topic_storage
+        .get(&req.cluster_name, &req.topic_name)
+        .map(|topic| topic.into_iter().collect())
+    };
+    match topics {
+      Ok(topics) => {
+        let now = now_second();
+        return Ok(Response::new(ListTopicReply {
+          topics: topics
+            .into_iter()
+            .map(|topic| mqtt_topic(topic, now))
+            .collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn set_topic_retain_message(
+    &self,
+    request: Request<SetTopicRetainMessageRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.topic_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or topic_name".to_string())
+          .into(),
+      );
+    }
+    if req.qos > 2 {
+      return Err(Status::invalid_argument(
+        PlacementCenterError::MqttQosInvalid(req.qos).to_string(),
+      ));
+    }
+
+    // 过期间隔换算为时间戳后再提交，保证切主后所有副本按同一个时间点判断过期
+    let now = now_second();
+    let retain_message = if req.payload.is_empty() {
+      None
+    } else {
+      Some(RetainMessage {
+        payload: req.payload,
+        qos: req.qos,
+        expire_at: if req.expiry_interval > 0 {
+          now + req.expiry_interval
+        } else {
+          0
+        },
+        create_time: now,
+      })
+    };
+    let topic = MqttTopic {
+      cluster_name: req.cluster_name,
+      topic_name: req.topic_name,
+      retain_message,
+      create_time: 0,
+    };
+    let data = StorageData::new(
+      StorageDataType::MqttSetTopicRetainMessage,
+      MqttTopic::encode_to_vec(&topic),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::MqttTopicNotFound(topic.cluster_name, topic.topic_name).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn save_session(
+    &self,
+    request: Request<SaveSessionRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let mut session = match request.into_inner().session {
+      Some(session) => session,
+      None => {
+        return Err(PlacementCenterError::RequestParamsNotEmpty("session".to_string()).into());
+      }
+    };
+    validate_session(&session)?;
+    validate_mqtt_cluster(&self.rocksdb_engine_handler, &session.cluster_name)?;
+
+    let now = now_second();
+    session.create_time = now;
+    session.update_time = now;
+    let data = StorageData::new(
+      StorageDataType::MqttSaveSession,
+      MqttSession::encode_to_vec(&session),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(mqtt_cluster_not_found(session.cluster_name)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn get_session(
+    &self,
+    request: Request<GetSessionRequest>,
+  ) -> Result<Response<GetSessionReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.client_id.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or client_id".to_string()).into(),
+      );
+    }
+
+    let session_storage = MqttSessionStorage::new(self.rocksdb_engine_handler.clone());
+    match session_storage.get(&req.cluster_name, &req.client_id) {
+      Ok(session) => {
+        return Ok(Response::new(GetSessionReply {
+          session: session.map(mqtt_session),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn delete_session(
+    &self,
+    request: Request<DeleteSessionRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.client_id.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or client_id".to_string()).into(),
+      );
+    }
+
+    let data = StorageData::new(
+      StorageDataType::MqttDeleteSession,
+      DeleteSessionRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::MqttSessionNotFound(req.cluster_name, req.client_id).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_session(
+    &self,
+    request: Request<ListSessionRequest>,
+  ) -> Result<Response<ListSessionReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let session_storage = MqttSessionStorage::new(self.rocksdb_engine_handler.clone());
+    match session_storage.list(&req.cluster_name) {
+      Ok(sessions) => {
+        return Ok(Response::new(ListSessionReply {
+          sessions: sessions.into_iter().map(mqtt_session).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+}
+
+fn validate_session(session: &MqttSession) -> Result<(), Status> {
+  if session.cluster_name.is_empty() || session.client_id.is_empty() {
+    return Err(
+      PlacementCenterError::RequestParamsNotEmpty("cluster_name or client_id".to_string()).into(),
+    );
+  }
+  if let Some(last_will) = &session.last_will {
+    if last_will.topic_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("last_will.topic_name".to_string()).into(),
+      );
+    }
+    if last_will.qos > 2 {
+      return Err(Status::invalid_argument(
+        PlacementCenterError::MqttQosInvalid(last_will.qos).to_string(),
+      ));
+    }
+  }
+  for subscription in session.subscriptions.iter() {
+    if subscription.topic_filter.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("topic_filter".to_string()).into());
+    }
+    if subscription.qos > 2 {
+      return Err(Status::invalid_argument(
+        PlacementCenterError::MqttQosInvalid(subscription.qos).to_string(),
+      ));
+    }
+    if subscription.retain_handling > 2 {
+      return Err(Status::invalid_argument(
+        PlacementCenterError::MqttRetainHandlingInvalid(subscription.retain_handling).to_string(),
+      ));
+    }
+  }
+  return Ok(());
+}
+
+/// MQTT 元数据只能写入已经注册的 MQTT 集群，集群名称不能包含 /
+pub fn validate_mqtt_cluster(
+  rocksdb_engine_handler: &Arc<RocksDBEngine>,
+  cluster_name: &str,
+) -> Result<(), Status> {
+  match check_mqtt_cluster(rocksdb_engine_handler, cluster_name) {
+    Ok(()) => Ok(()),
+    Err(e @ PlacementCenterError::MqttClusterNameInvalid(_)) => {
+      Err(Status::invalid_argument(e.to_string()))
+    }
+    Err(e @ PlacementCenterError::ClusterNotFound(_)) => Err(Status::not_found(e.to_string())),
+    Err(e @ PlacementCenterError::ClusterTypeUnexpected(..)) => {
+      Err(Status::failed_precondition(e.to_string()))
+    }
+    Err(e) => Err(Status::cancelled(e.to_string())),
+  }
+}
+
+// 提交之后集群已经被删除时写请求返回 NotFound
+pub fn mqtt_cluster_not_found(cluster_name: String) -> Status {
+  return Status::not_found(PlacementCenterError::ClusterNotFound(cluster_name).to_string());
+}
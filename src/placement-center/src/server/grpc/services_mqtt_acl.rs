@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::{
+  CommonReply,
+  mqtt::{
+    CreateAclRequest, CreateBlacklistRequest, DeleteAclRequest, DeleteBlacklistRequest,
+    ListAclReply, ListAclRequest, ListBlacklistReply, ListBlacklistRequest, MqttAcl, MqttBlacklist,
+    mqtt_acl_service_server::MqttAclService,
+  },
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+  core::error::PlacementCenterError,
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+    mqtt::{mqtt_acl, mqtt_blacklist},
+  },
+  server::grpc::services_mqtt::{mqtt_cluster_not_found, validate_mqtt_cluster},
+  storage::{
+    mqtt::{
+      acl::{MqttAclStorage, is_valid_topic_filter},
+      blacklist::MqttBlacklistStorage,
+    },
+    rocksdb::RocksDBEngine,
+  },
+};
+
+/// Broker 鉴权使用的 ACL 规则和黑名单，写入通过 Raft 提交，读取使用本地存储
+pub struct GrpcMqttAclServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl GrpcMqttAclServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+  ) -> Self {
+    return GrpcMqttAclServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+    };
+  }
+}
+
+#[tonic::async_trait]
+impl MqttAclService for GrpcMqttAclServices {
+  async fn create_acl(
+    &self,
+    request: Request<CreateAclRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.resource_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or resource_name".to_string())
+          .into(),
+      );
+    }
+    if !is_valid_topic_filter(&req.topic_filter) {
+      return Err(Status::invalid_argument(
+        PlacementCenterError::MqttTopicFilterInvalid(req.topic_filter).to_string(),
+      ));
+    }
+    validate_mqtt_cluster(&self.rocksdb_engine_handler, &req.cluster_name)?;
+
+    let acl = MqttAcl {
+      cluster_name: req.cluster_name,
+      resource_type: req.resource_type,
+      resource_name: req.resource_name,
+      topic_filter: req.topic_filter,
+      action: req.action,
+      permission: req.permission,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(StorageDataType::MqttCreateAcl, MqttAcl::encode_to_vec(&acl));
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(mqtt_cluster_not_found(acl.cluster_name)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_acl(
+    &self,
+    request: Request<DeleteAclRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.resource_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or resource_name".to_string())
+          .into(),
+      );
+    }
+
+    let data = StorageData::new(
+      StorageDataType::MqttDeleteAcl,
+      DeleteAclRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::MqttAclNotFound(
+            req.cluster_name.clone(),
+            req.resource_name.clone(),
+            req.action().as_str_name().to_string(),
+            req.topic_filter,
+          )
+          .to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_acl(
+    &self,
+    request: Request<ListAclRequest>,
+  ) -> Result<Response<ListAclReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let acl_storage = MqttAclStorage::new(self.rocksdb_engine_handler.clone());
+    match acl_storage.list(&req.cluster_name) {
+      Ok(acls) => {
+        return Ok(Response::new(ListAclReply {
+          acls: acls.into_iter().map(mqtt_acl).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn create_blacklist(
+    &self,
+    request: Request<CreateBlacklistRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.resource_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or resource_name".to_string())
+          .into(),
+      );
+    }
+    validate_mqtt_cluster(&self.rocksdb_engine_handler, &req.cluster_name)?;
+
+    let blacklist = MqttBlacklist {
+      cluster_name: req.cluster_name,
+      blacklist_type: req.blacklist_type,
+      resource_name: req.resource_name,
+      end_time: req.end_time,
+      desc: req.desc,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::MqttCreateBlacklist,
+      MqttBlacklist::encode_to_vec(&blacklist),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(mqtt_cluster_not_found(blacklist.cluster_name)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn delete_blacklist(
+    &self,
+    request: Request<DeleteBlacklistRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.resource_name.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty("cluster_name or resource_name".to_string())
+          .into(),
+      );
+    }
+
+    let data = StorageData::new(
+      StorageDataType::MqttDeleteBlacklist,
+      DeleteBlacklistRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::MqttBlacklistNotFound(
+            req.cluster_name.clone(),
+            req.resource_name.clone(),
+            req.blacklist_type().as_str_name().to_string(),
+          )
+          .to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_blacklist(
+    &self,
+    request: Request<ListBlacklistRequest>,
+  ) -> Result<Response<ListBlacklistReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let blacklist_storage = MqttBlacklistStorage::new(self.rocksdb_engine_handler.clone());
+    match blacklist_storage.list(&req.cluster_name) {
+      Ok(blacklists) => {
+        let now = now_second();
+        return Ok(Response::new(ListBlacklistReply {
+          blacklists: blacklists
+            .into_iter()
+            .filter(|blacklist| !blacklist.is_expired(now))
+            .map(mqtt_blacklist)
+            .collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+}
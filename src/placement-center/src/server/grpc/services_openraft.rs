@@ -0,0 +1,150 @@
+use std::{collections::BTreeSet, path::PathBuf};
+
+use bincode::{deserialize, serialize};
+use common_base::error::CommonError;
+use openraft::{Raft, Snapshot, SnapshotMeta, Vote};
+use protocol::{
+  AddLearnerReply, AddLearnerRequest, AppendReply, AppendRequest, ChangeMembershipReply,
+  ChangeMembershipRequest, SnapshotChunk, SnapshotOffsetReply, SnapshotOffsetRequest,
+  SnapshotReply, VoteReply, VoteRequest, open_raft_service_server::OpenRaftService,
+};
+use serde::Serialize;
+use tokio::sync::Mutex;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::{
+  core::error::PlacementCenterError,
+  openraft::{raft_node::Node, store::snapshot::SnapshotReceiver, typeconfig::TypeConfig},
+};
+
+/// 节点之间的 Raft 通信接口。
+/// 请求和响应都是 bincode 序列化后的 openraft 数据结构，响应中保存的是完整的调用结果，
+/// Raft 返回的错误由调用方反序列化后交给 openraft 处理。
+/// 快照通过客户端流按分块接收，写入本地文件后再安装到状态机
+pub struct GrpcOpenRaftServices {
+  raft_node: Raft<TypeConfig>,
+  // 同一时间只接收一个快照，避免多个发送方同时写入快照文件
+  snapshot_receiver: Mutex<SnapshotReceiver>,
+}
+
+impl GrpcOpenRaftServices {
+  pub fn new(raft_node: Raft<TypeConfig>, snapshot_dir: PathBuf) -> Self {
+    return GrpcOpenRaftServices {
+      raft_node,
+      snapshot_receiver: Mutex::new(SnapshotReceiver::new(snapshot_dir)),
+    };
+  }
+}
+
+#[tonic::async_trait]
+impl OpenRaftService for GrpcOpenRaftServices {
+  async fn vote(&self, request: Request<VoteRequest>) -> Result<Response<VoteReply>, Status> {
+    let req = request.into_inner();
+    let vote_data = deserialize(&req.value).map_err(CommonError::from)?;
+    let result = self.raft_node.vote(vote_data).await;
+    Ok(Response::new(VoteReply {
+      value: encode_result(&result)?,
+    }))
+  }
+
+  async fn append(&self, request: Request<AppendRequest>) -> Result<Response<AppendReply>, Status> {
+    let req = request.into_inner();
+    let append_data = deserialize(&req.value).map_err(CommonError::from)?;
+    let result = self.raft_node.append_entries(append_data).await;
+    Ok(Response::new(AppendReply {
+      value: encode_result(&result)?,
+    }))
+  }
+
+  async fn snapshot(
+    &self,
+    request: Request<Streaming<SnapshotChunk>>,
+  ) -> Result<Response<SnapshotReply>, Status> {
+    let mut stream = request.into_inner();
+    let receiver = self.snapshot_receiver.lock().await;
+
+    // 分块校验通过后立即写入文件，流中断时已写入的数据保留，发送方重试时从断点继续
+    let mut last_chunk = None;
+    while let Some(chunk) = stream.message().await? {
+      receiver.write_chunk(&chunk)?;
+      if chunk.done {
+        last_chunk = Some(chunk);
+        break;
+      }
+    }
+
+    let chunk = match last_chunk {
+      Some(chunk) => chunk,
+      None => {
+        return Err(PlacementCenterError::RequestParamsNotEmpty("done".to_string()).into());
+      }
+    };
+
+    let (vote, meta): (Vote<TypeConfig>, SnapshotMeta<TypeConfig>) =
+      deserialize(&chunk.meta).map_err(CommonError::from)?;
+    let file = receiver.finish(&chunk.snapshot_id, chunk.total_size)?;
+    let snapshot = Snapshot {
+      meta,
+      snapshot: Box::new(file),
+    };
+
+    let result = self.raft_node.install_full_snapshot(vote, snapshot).await;
+    Ok(Response::new(SnapshotReply {
+      value: encode_result(&result)?,
+    }))
+  }
+
+  async fn snapshot_offset(
+    &self,
+    request: Request<SnapshotOffsetRequest>,
+  ) -> Result<Response<SnapshotOffsetReply>, Status> {
+    let req = request.into_inner();
+    let receiver = self.snapshot_receiver.lock().await;
+    let offset = receiver.offset(&req.snapshot_id)?;
+    Ok(Response::new(SnapshotOffsetReply { offset }))
+  }
+
+  async fn add_learner(
+    &self,
+    request: Request<AddLearnerRequest>,
+  ) -> Result<Response<AddLearnerReply>, Status> {
+    let req = request.into_inner();
+    let node = match req.node {
+      Some(node) => Node {
+        node_id: node.node_id,
+        rpc_addr: node.rpc_addr,
+      },
+      None => {
+        return Err(PlacementCenterError::RequestParamsNotEmpty("node".to_string()).into());
+      }
+    };
+
+    let result = self
+      .raft_node
+      .add_learner(req.node_id, node, req.blocking)
+      .await;
+    Ok(Response::new(AddLearnerReply {
+      value: encode_result(&result)?,
+    }))
+  }
+
+  async fn change_membership(
+    &self,
+    request: Request<ChangeMembershipRequest>,
+  ) -> Result<Response<ChangeMembershipReply>, Status> {
+    let req = request.into_inner();
+    let members: BTreeSet<u64> = req.members.into_iter().collect();
+    if members.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("members".to_string()).into());
+    }
+
+    let result = self.raft_node.change_membership(members, req.retain).await;
+    Ok(Response::new(ChangeMembershipReply {
+      value: encode_result(&result)?,
+    }))
+  }
+}
+
+fn encode_result<T: Serialize>(result: &T) -> Result<Vec<u8>, Status> {
+  serialize(result).map_err(|e| CommonError::from(e).into())
+}
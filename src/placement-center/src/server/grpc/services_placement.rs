@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::{
+  ClusterInfo, ClusterType, CommonReply, HeartbeatRequest, ListClusterReply, ListClusterRequest,
+  ListNodeReply, ListNodeRequest, NodeInfo, RegisterNodeRequest, SendRaftConfChangeReply,
+  SendRaftConfChangeRequest, SendRaftMessageReply, SendRaftMessageRequest, UnRegisterNodeRequest,
+  placement_center_server_server::PlacementCenterServer,
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+  core::{error::PlacementCenterError, heartbeat::NodeHeartbeatCache},
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    cluster::{cluster_info, node_info},
+    data::{StorageData, StorageDataType},
+  },
+  storage::{cluster::ClusterStorage, rocksdb::RocksDBEngine},
+};
+
+pub struct GrpcPlacementServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  heartbeat_cache: Arc<NodeHeartbeatCache>,
+}
+
+impl GrpcPlacementServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+    heartbeat_cache: Arc<NodeHeartbeatCache>,
+  ) -> Self {
+    return GrpcPlacementServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+      heartbeat_cache,
+    };
+  }
+}
+
+#[tonic::async_trait]
+impl PlacementCenterServer for GrpcPlacementServices {
+  // Raft 消息已经改为通过 OpenRaftService 传输
+  async fn send_raft_message(
+    &self,
+    _: Request<SendRaftMessageRequest>,
+  ) -> Result<Response<SendRaftMessageReply>, Status> {
+    return Err(Status::unimplemented(
+      "Raft messages are sent through OpenRaftService",
+    ));
+  }
+
+  async fn send_raft_conf_change(
+    &self,
+    _: Request<SendRaftConfChangeRequest>,
+  ) -> Result<Response<SendRaftConfChangeReply>, Status> {
+    return Err(Status::unimplemented(
+      "Raft membership changes are sent through OpenRaftService",
+    ));
+  }
+
+  async fn register_node(
+    &self,
+    request: Request<RegisterNodeRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() || req.node_ip.is_empty() || req.node_inner_addr.is_empty() {
+      return Err(
+        PlacementCenterError::RequestParamsNotEmpty(
+          "cluster_name, node_ip or node_inner_addr".to_string(),
+        )
+        .into(),
+      );
+    }
+    if ClusterType::try_from(req.cluster_type).is_err() {
+      return Err(Status::invalid_argument(format!(
+        "Unknown cluster type {}",
+        req.cluster_type
+      )));
+    }
+
+    // 集群不存在时先创建集群，并发注册同一个集群时由状态机保证只创建一次
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    let cluster_exists = match cluster_storage.get_cluster(&req.cluster_name) {
+      Ok(cluster) => cluster.is_some(),
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    };
+    if !cluster_exists {
+      let cluster = ClusterInfo {
+        cluster_name: req.cluster_name.clone(),
+        cluster_type: req.cluster_type,
+        create_time: now_second(),
+      };
+      let data = StorageData::new(
+        StorageDataType::ClusterAddCluster,
+        ClusterInfo::encode_to_vec(&cluster),
+      );
+      let reply = self.raft_machine_apply.client_write(data).await?;
+      if let AppResponseData::Conflict(message) = reply.data {
+        return Err(Status::failed_precondition(message));
+      }
+    }
+
+    let node = NodeInfo {
+      cluster_name: req.cluster_name,
+      cluster_type: req.cluster_type,
+      node_id: req.node_id,
+      node_ip: req.node_ip,
+      node_inner_addr: req.node_inner_addr,
+      extend_info: req.extend_info,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::ClusterAddNode,
+      NodeInfo::encode_to_vec(&node),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      // 提交过程中集群被删除
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::ClusterNotFound(node.cluster_name).to_string(),
+        ));
+      }
+      _ => {
+        // 注册成功视为一次心跳，重新注册的节点重新开始计时
+        self
+          .heartbeat_cache
+          .report(&node.cluster_name, node.node_id);
+        return Ok(Response::new(CommonReply::default()));
+      }
+    }
+  }
+
+  async fn un_register_node(
+    &self,
+    request: Request<UnRegisterNodeRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let data = StorageData::new(
+      StorageDataType::ClusterDeleteNode,
+      UnRegisterNodeRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::NodeNotFound(req.cluster_name, req.node_id).to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_cluster(
+    &self,
+    _: Request<ListClusterRequest>,
+  ) -> Result<Response<ListClusterReply>, Status> {
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    match cluster_storage.list_clusters() {
+      Ok(clusters) => {
+        return Ok(Response::new(ListClusterReply {
+          clusters: clusters.into_iter().map(cluster_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn list_node(
+    &self,
+    request: Request<ListNodeRequest>,
+  ) -> Result<Response<ListNodeReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    match cluster_storage.list_nodes(&req.cluster_name) {
+      Ok(nodes) => {
+        return Ok(Response::new(ListNodeReply {
+          nodes: nodes.into_iter().map(node_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn heartbeat(
+    &self,
+    request: Request<HeartbeatRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    // 心跳记录只保存在 Leader 上
+    self.raft_machine_apply.ensure_leader()?;
+
+    // 节点已经因为心跳超时被移除时返回 NotFound，由节点重新注册
+    let cluster_storage = ClusterStorage::new(self.rocksdb_engine_handler.clone());
+    match cluster_storage.get_node(&req.cluster_name, req.node_id) {
+      Ok(Some(_)) => {}
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::NodeNotFound(req.cluster_name, req.node_id).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+
+    self.heartbeat_cache.report(&req.cluster_name, req.node_id);
+    return Ok(Response::new(CommonReply::default()));
+  }
+}
@@ -0,0 +1,381 @@
+use std::sync::Arc;
+
+use common_base::tools::now_second;
+use prost::Message;
+use protocol::{
+  CommonReply,
+  schema::{
+    BindSchemaRequest, Compatibility, DeleteSchemaRequest, GetBoundSchemaRequest, GetSchemaReply,
+    GetSchemaRequest, ListSchemaBindingReply, ListSchemaBindingRequest, ListSchemaReply,
+    ListSchemaRequest, RegisterSchemaReply, RegisterSchemaRequest, SchemaBindingInfo, SchemaInfo,
+    SchemaResourceType, SchemaType, SetCompatibilityRequest, UnbindSchemaRequest,
+    ValidatePayloadReply, ValidatePayloadRequest, schema_service_server::SchemaService,
+  },
+};
+use tonic::{Request, Response, Status};
+
+use crate::{
+  core::{
+    error::PlacementCenterError,
+    schema::{check_schema_definition, validate_schema_payload},
+  },
+  openraft::route::AppResponseData,
+  route::{
+    apply::RaftMachineApply,
+    data::{StorageData, StorageDataType},
+    schema::{binding_info, schema_info},
+  },
+  storage::{
+    rocksdb::RocksDBEngine,
+    schema::{
+      binding::{SchemaBinding, SchemaBindingStorage},
+      subject::{SchemaStorage, SchemaVersion},
+    },
+  },
+};
+
+pub struct GrpcSchemaServices {
+  raft_machine_apply: Arc<RaftMachineApply>,
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl GrpcSchemaServices {
+  pub fn new(
+    raft_machine_apply: Arc<RaftMachineApply>,
+    rocksdb_engine_handler: Arc<RocksDBEngine>,
+  ) -> Self {
+    return GrpcSchemaServices {
+      raft_machine_apply,
+      rocksdb_engine_handler,
+    };
+  }
+
+  // version 为 0 时返回最新版本
+  fn get_version(
+    &self,
+    cluster_name: &str,
+    subject: &str,
+    version: u32,
+  ) -> Result<SchemaVersion, Status> {
+    let schema_storage = SchemaStorage::new(self.rocksdb_engine_handler.clone());
+    let version = if version == 0 {
+      match schema_storage.get_subject(cluster_name, subject) {
+        Ok(Some(subject)) => subject.latest_version,
+        Ok(None) => return Err(subject_not_found(subject)),
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      }
+    } else {
+      version
+    };
+    match schema_storage.get_version(cluster_name, subject, version) {
+      Ok(Some(schema)) => return Ok(schema),
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::SchemaVersionNotFound(subject.to_string(), version).to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  fn get_binding(
+    &self,
+    cluster_name: &str,
+    resource_type: SchemaResourceType,
+    resource_name: &str,
+  ) -> Result<SchemaBinding, Status> {
+    let binding_storage = SchemaBindingStorage::new(self.rocksdb_engine_handler.clone());
+    match binding_storage.get(cluster_name, resource_type.as_str_name(), resource_name) {
+      Ok(Some(binding)) => return Ok(binding),
+      Ok(None) => {
+        return Err(Status::not_found(
+          PlacementCenterError::SchemaBindingNotFound(
+            resource_type.as_str_name().to_string(),
+            resource_name.to_string(),
+          )
+          .to_string(),
+        ));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+}
+
+#[tonic::async_trait]
+impl SchemaService for GrpcSchemaServices {
+  async fn register_schema(
+    &self,
+    request: Request<RegisterSchemaRequest>,
+  ) -> Result<Response<RegisterSchemaReply>, Status> {
+    let req = request.into_inner();
+    validate_subject(&req.cluster_name, &req.subject)?;
+    let schema_type = match SchemaType::try_from(req.schema_type) {
+      Ok(schema_type) => schema_type,
+      Err(_) => {
+        return Err(Status::invalid_argument(format!(
+          "Unknown schema type {}",
+          req.schema_type
+        )));
+      }
+    };
+    // 无法解析的定义不需要提交到 Raft
+    if let Err(e) = check_schema_definition(schema_type, &req.definition) {
+      return Err(Status::invalid_argument(e.to_string()));
+    }
+
+    // 版本号由状态机分配，通过写请求的响应数据返回
+    let schema = SchemaInfo {
+      cluster_name: req.cluster_name,
+      subject: req.subject,
+      version: 0,
+      schema_type: req.schema_type,
+      definition: req.definition,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::SchemaRegister,
+      SchemaInfo::encode_to_vec(&schema),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::Value(value) => match RegisterSchemaReply::decode(value.as_ref()) {
+        Ok(reply) => return Ok(Response::new(reply)),
+        Err(e) => return Err(Status::cancelled(e.to_string())),
+      },
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => {
+        return Err(Status::internal(
+          "Register schema returned an empty response",
+        ));
+      }
+    }
+  }
+
+  async fn get_schema(
+    &self,
+    request: Request<GetSchemaRequest>,
+  ) -> Result<Response<GetSchemaReply>, Status> {
+    let req = request.into_inner();
+    validate_subject(&req.cluster_name, &req.subject)?;
+
+    let schema = self.get_version(&req.cluster_name, &req.subject, req.version)?;
+    return Ok(Response::new(GetSchemaReply {
+      schema: Some(schema_info(schema)),
+    }));
+  }
+
+  async fn list_schema(
+    &self,
+    request: Request<ListSchemaRequest>,
+  ) -> Result<Response<ListSchemaReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let schema_storage = SchemaStorage::new(self.rocksdb_engine_handler.clone());
+    match schema_storage.list_versions(&req.cluster_name, &req.subject) {
+      Ok(versions) => {
+        return Ok(Response::new(ListSchemaReply {
+          schemas: versions.into_iter().map(schema_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  async fn delete_schema(
+    &self,
+    request: Request<DeleteSchemaRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_subject(&req.cluster_name, &req.subject)?;
+
+    let data = StorageData::new(
+      StorageDataType::SchemaDelete,
+      DeleteSchemaRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(subject_not_found(&req.subject)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn set_compatibility(
+    &self,
+    request: Request<SetCompatibilityRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_subject(&req.cluster_name, &req.subject)?;
+    if Compatibility::try_from(req.compatibility).is_err() {
+      return Err(Status::invalid_argument(format!(
+        "Unknown compatibility {}",
+        req.compatibility
+      )));
+    }
+
+    let data = StorageData::new(
+      StorageDataType::SchemaSetCompatibility,
+      SetCompatibilityRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(subject_not_found(&req.subject)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn bind_schema(
+    &self,
+    request: Request<BindSchemaRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    validate_subject(&req.cluster_name, &req.subject)?;
+    validate_resource(req.resource_type, &req.resource_name)?;
+
+    let binding = SchemaBindingInfo {
+      cluster_name: req.cluster_name,
+      resource_type: req.resource_type,
+      resource_name: req.resource_name,
+      subject: req.subject,
+      create_time: now_second(),
+    };
+    let data = StorageData::new(
+      StorageDataType::SchemaBind,
+      SchemaBindingInfo::encode_to_vec(&binding),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => return Err(subject_not_found(&binding.subject)),
+      AppResponseData::Conflict(message) => return Err(Status::failed_precondition(message)),
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn unbind_schema(
+    &self,
+    request: Request<UnbindSchemaRequest>,
+  ) -> Result<Response<CommonReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+    validate_resource(req.resource_type, &req.resource_name)?;
+
+    let data = StorageData::new(
+      StorageDataType::SchemaUnbind,
+      UnbindSchemaRequest::encode_to_vec(&req),
+    );
+    let reply = self.raft_machine_apply.client_write(data).await?;
+    match reply.data {
+      AppResponseData::NotFound => {
+        return Err(Status::not_found(
+          PlacementCenterError::SchemaBindingNotFound(
+            req.resource_type().as_str_name().to_string(),
+            req.resource_name,
+          )
+          .to_string(),
+        ));
+      }
+      _ => return Ok(Response::new(CommonReply::default())),
+    }
+  }
+
+  async fn list_schema_binding(
+    &self,
+    request: Request<ListSchemaBindingRequest>,
+  ) -> Result<Response<ListSchemaBindingReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+
+    let binding_storage = SchemaBindingStorage::new(self.rocksdb_engine_handler.clone());
+    match binding_storage.list(&req.cluster_name, &req.subject) {
+      Ok(bindings) => {
+        return Ok(Response::new(ListSchemaBindingReply {
+          bindings: bindings.into_iter().map(binding_info).collect(),
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+
+  // 返回主题或分片绑定的 subject 的最新版本
+  async fn get_bound_schema(
+    &self,
+    request: Request<GetBoundSchemaRequest>,
+  ) -> Result<Response<GetSchemaReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+    validate_resource(req.resource_type, &req.resource_name)?;
+
+    let binding = self.get_binding(&req.cluster_name, req.resource_type(), &req.resource_name)?;
+    let schema = self.get_version(&req.cluster_name, &binding.subject, 0)?;
+    return Ok(Response::new(GetSchemaReply {
+      schema: Some(schema_info(schema)),
+    }));
+  }
+
+  // 使用主题或分片绑定的 subject 的最新版本校验消息
+  async fn validate_payload(
+    &self,
+    request: Request<ValidatePayloadRequest>,
+  ) -> Result<Response<ValidatePayloadReply>, Status> {
+    let req = request.into_inner();
+    if req.cluster_name.is_empty() {
+      return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+    }
+    validate_resource(req.resource_type, &req.resource_name)?;
+
+    let binding = self.get_binding(&req.cluster_name, req.resource_type(), &req.resource_name)?;
+    let schema = self.get_version(&req.cluster_name, &binding.subject, 0)?;
+    let schema_type = SchemaType::from_str_name(&schema.schema_type).unwrap_or_default();
+    match validate_schema_payload(schema_type, &schema.definition, &req.payload) {
+      Ok(errors) => {
+        return Ok(Response::new(ValidatePayloadReply {
+          valid: errors.is_empty(),
+          subject: schema.subject,
+          version: schema.version,
+          errors,
+        }));
+      }
+      Err(e) => return Err(Status::cancelled(e.to_string())),
+    }
+  }
+}
+
+// subject 是存储 key 的一部分，不能包含 /
+fn validate_subject(cluster_name: &str, subject: &str) -> Result<(), Status> {
+  if cluster_name.is_empty() {
+    return Err(PlacementCenterError::RequestParamsNotEmpty("cluster_name".to_string()).into());
+  }
+  if subject.is_empty() || subject.contains('/') {
+    return Err(Status::invalid_argument(
+      PlacementCenterError::SchemaSubjectInvalid(subject.to_string()).to_string(),
+    ));
+  }
+  return Ok(());
+}
+
+fn validate_resource(resource_type: i32, resource_name: &str) -> Result<(), Status> {
+  if SchemaResourceType::try_from(resource_type).is_err() {
+    return Err(Status::invalid_argument(format!(
+      "Unknown resource type {}",
+      resource_type
+    )));
+  }
+  if resource_name.is_empty() {
+    return Err(PlacementCenterError::RequestParamsNotEmpty("resource_name".to_string()).into());
+  }
+  return Ok(());
+}
+
+fn subject_not_found(subject: &str) -> Status {
+  return Status::not_found(
+    PlacementCenterError::SchemaSubjectNotFound(subject.to_string()).to_string(),
+  );
+}
@@ -3,21 +3,118 @@ use std::{
   sync::{Arc, RwLock},
 };
 
+use common_base::config::placement_center::placement_center_conf;
 use grpc::server::start_grpc_server;
+use grpc_clients::pool::ClientPool;
 use http::server::{start_http_server, HttpServerState};
-use log::info;
+use log::{error, info};
 use tokio::{signal, sync::broadcast};
 
+use crate::{
+  core::{
+    blacklist::start_blacklist_expire_check_thread,
+    config::ClusterConfigWatchHub,
+    heartbeat::{start_node_heartbeat_check_thread, NodeHeartbeatCache},
+    lease::{start_lease_expire_thread, LeaseManager},
+    retain::start_retain_message_expire_check_thread,
+    watch::KvWatchHub,
+  },
+  openraft::raft_node::{create_raft_node, start_openraft_node},
+  route::{apply::RaftMachineApply, DataRoute},
+  storage::{lease::LeaseStorage, rocksdb::RocksDBEngine},
+};
+
 pub mod grpc;
 pub mod http;
 
-pub async fn start_server(kvs: BTreeMap<String, String>, stop_sx: broadcast::Sender<bool>) {
-  let state = HttpServerState::new(Arc::new(RwLock::new(kvs)));
- 
+pub async fn start_server(stop_sx: broadcast::Sender<bool>) {
+  let config = placement_center_conf();
+  let state = HttpServerState::new(Arc::new(RwLock::new(BTreeMap::new())));
+
+  // 初始化存储、数据路由和 Raft 节点，写请求统一通过 RaftMachineApply 提交
+  let rocksdb_engine_handler = Arc::new(RocksDBEngine::new(config));
+  let client_pool = Arc::new(ClientPool::new(100));
+  // Watch 历史的起始索引由状态机加载数据目录中的应用进度后初始化
+  let kv_watch_hub = Arc::new(KvWatchHub::default());
+  let config_watch_hub = Arc::new(ClusterConfigWatchHub::default());
+  let lease_manager = Arc::new(LeaseManager::default());
+  let heartbeat_cache = Arc::new(NodeHeartbeatCache::default());
+  // 重启后按已持久化的租约重建过期时间
+  match LeaseStorage::new(rocksdb_engine_handler.clone()).list() {
+    Ok(leases) => lease_manager.reload(leases),
+    Err(e) => error!("Failed to load leases from storage, {}", e),
+  }
+  let data_route = Arc::new(DataRoute::new(
+    rocksdb_engine_handler.clone(),
+    kv_watch_hub.clone(),
+    lease_manager.clone(),
+    config_watch_hub.clone(),
+  ));
+  let raft_node = create_raft_node(client_pool.clone(), data_route).await;
+  let raft_machine_apply = Arc::new(RaftMachineApply::new(raft_node.clone()));
+
+  // 由 Leader 负责回收过期的租约
+  let raw_stop_sx = stop_sx.clone();
+  let raw_raft_machine_apply = raft_machine_apply.clone();
+  let raw_lease_manager = lease_manager.clone();
+  tokio::spawn(async move {
+    start_lease_expire_thread(raw_raft_machine_apply, raw_lease_manager, raw_stop_sx).await;
+  });
+
+  // 由 Leader 负责移除心跳超时的节点
+  let raw_stop_sx = stop_sx.clone();
+  let raw_raft_machine_apply = raft_machine_apply.clone();
+  let raw_rocksdb_engine_handler = rocksdb_engine_handler.clone();
+  let raw_heartbeat_cache = heartbeat_cache.clone();
+  tokio::spawn(async move {
+    start_node_heartbeat_check_thread(
+      raw_raft_machine_apply,
+      raw_rocksdb_engine_handler,
+      raw_heartbeat_cache,
+      raw_stop_sx,
+    )
+    .await;
+  });
+
+  // 由 Leader 负责清理失效的黑名单
+  let raw_stop_sx = stop_sx.clone();
+  let raw_raft_machine_apply = raft_machine_apply.clone();
+  let raw_rocksdb_engine_handler = rocksdb_engine_handler.clone();
+  tokio::spawn(async move {
+    start_blacklist_expire_check_thread(
+      raw_raft_machine_apply,
+      raw_rocksdb_engine_handler,
+      raw_stop_sx,
+    )
+    .await;
+  });
+
+  // 由 Leader 负责清除过期的保留消息
+  let raw_stop_sx = stop_sx.clone();
+  let raw_raft_machine_apply = raft_machine_apply.clone();
+  let raw_rocksdb_engine_handler = rocksdb_engine_handler.clone();
+  tokio::spawn(async move {
+    start_retain_message_expire_check_thread(
+      raw_raft_machine_apply,
+      raw_rocksdb_engine_handler,
+      raw_stop_sx,
+    )
+    .await;
+  });
+
   // 将 start_grpc_server 运行在一个独立 tokio task 中
   let raw_stop_sx=stop_sx.clone();
   tokio::spawn(async move{
-    start_grpc_server(raw_stop_sx).await;
+    start_grpc_server(
+      raft_machine_apply,
+      rocksdb_engine_handler,
+      kv_watch_hub,
+      lease_manager,
+      heartbeat_cache,
+      config_watch_hub,
+      raw_stop_sx,
+    )
+    .await;
   });
 
   // 将 start_http_server 运行在一个独立的 tokio task中
@@ -26,6 +123,11 @@ pub async fn start_server(kvs: BTreeMap<String, String>, stop_sx: broadcast::Sen
     start_http_server(state, raw_stop_sx).await;
   });
 
+  // 初始化 Raft 集群
+  tokio::spawn(async move {
+    start_openraft_node(raft_node).await;
+  });
+
   // 等待进程信号
   awaiting_stop(stop_sx.clone()).await;
 }
@@ -39,6 +141,6 @@ pub async fn awaiting_stop(stop_send: broadcast::Sender<bool>){
       Err(e)=>{
         panic!("{}",e);
       }
-      
+
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,143 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_amqp_binding, storage_key_amqp_binding_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// exchange 到 queue 的绑定
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Binding {
+  pub cluster_name: String,
+  pub vhost: String,
+  pub exchange_name: String,
+  pub queue_name: String,
+  pub routing_key: String,
+  pub arguments: BTreeMap<String, String>,
+  pub create_time: u64,
+}
+
+pub struct AmqpBindingStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl AmqpBindingStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    AmqpBindingStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, binding: &Binding) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_amqp_binding(
+        &binding.cluster_name,
+        &binding.vhost,
+        &binding.exchange_name,
+        &binding.queue_name,
+        &binding.routing_key,
+      ),
+      binding,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    vhost: &str,
+    exchange_name: &str,
+    queue_name: &str,
+    routing_key: &str,
+  ) -> Result<Option<Binding>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_binding(cluster_name, vhost, exchange_name, queue_name, routing_key),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Binding>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // exchange_name 和 queue_name 为空时不按该字段过滤
+  pub fn list(
+    &self,
+    cluster_name: &str,
+    vhost: &str,
+    exchange_name: &str,
+    queue_name: &str,
+  ) -> Result<Vec<Binding>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_binding_prefix(cluster_name, vhost),
+    )?;
+    let mut results = Vec::new();
+    for data in data_list {
+      let binding = serde_json::from_slice::<Binding>(&data.data)?;
+      if binding.vhost == vhost
+        && (exchange_name.is_empty() || binding.exchange_name == exchange_name)
+        && (queue_name.is_empty() || binding.queue_name == queue_name)
+      {
+        results.push(binding);
+      }
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(&self, batch: &mut StorageBatch, binding: &Binding) -> Result<(), CommonError> {
+    batch.delete(storage_key_amqp_binding(
+      &binding.cluster_name,
+      &binding.vhost,
+      &binding.exchange_name,
+      &binding.queue_name,
+      &binding.routing_key,
+    ));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{AmqpBindingStorage, Binding};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn amqp_binding_storage_test() {
+    let engine = test_rocksdb_engine("amqp_binding_storage_test");
+    let storage = AmqpBindingStorage::new(engine.clone());
+    for (exchange_name, queue_name, routing_key) in [
+      ("logs", "errors", "*.error"),
+      ("logs", "all", "#"),
+      ("orders", "all", ""),
+    ] {
+      test_commit(&engine, |batch| {
+        storage.save(
+          batch,
+          &Binding {
+            cluster_name: "amqp".to_string(),
+            vhost: "/".to_string(),
+            exchange_name: exchange_name.to_string(),
+            queue_name: queue_name.to_string(),
+            routing_key: routing_key.to_string(),
+            ..Default::default()
+          },
+        )
+      });
+    }
+    assert_eq!(storage.list("amqp", "/", "", "").unwrap().len(), 3);
+    assert_eq!(storage.list("amqp", "/", "logs", "").unwrap().len(), 2);
+    assert_eq!(storage.list("amqp", "/", "", "all").unwrap().len(), 2);
+
+    // routing_key 为空的绑定同样可以读取和删除
+    let binding = storage
+      .get("amqp", "/", "orders", "all", "")
+      .unwrap()
+      .unwrap();
+    test_commit(&engine, |batch| storage.delete(batch, &binding));
+    assert!(storage.list("amqp", "/", "orders", "").unwrap().is_empty());
+  }
+}
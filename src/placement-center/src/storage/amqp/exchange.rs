@@ -0,0 +1,130 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_amqp_exchange, storage_key_amqp_exchange_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// AMQP exchange，`exchange_type` 为 `ExchangeType` 在 proto 中的名称
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Exchange {
+  pub cluster_name: String,
+  pub vhost: String,
+  pub exchange_name: String,
+  pub exchange_type: String,
+  pub durable: bool,
+  pub auto_delete: bool,
+  pub internal: bool,
+  pub arguments: BTreeMap<String, String>,
+  pub create_time: u64,
+}
+
+pub struct AmqpExchangeStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl AmqpExchangeStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    AmqpExchangeStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, exchange: &Exchange) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_amqp_exchange(
+        &exchange.cluster_name,
+        &exchange.vhost,
+        &exchange.exchange_name,
+      ),
+      exchange,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    vhost: &str,
+    exchange_name: &str,
+  ) -> Result<Option<Exchange>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_exchange(cluster_name, vhost, exchange_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Exchange>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str, vhost: &str) -> Result<Vec<Exchange>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_exchange_prefix(cluster_name, vhost),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      let exchange = serde_json::from_slice::<Exchange>(&data.data)?;
+      // 过滤掉名称以该 vhost 开头的其它 vhost 中的 exchange
+      if exchange.vhost == vhost {
+        results.push(exchange);
+      }
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    vhost: &str,
+    exchange_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_amqp_exchange(
+      cluster_name,
+      vhost,
+      exchange_name,
+    ));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{AmqpExchangeStorage, Exchange};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn amqp_exchange_storage_test() {
+    let engine = test_rocksdb_engine("amqp_exchange_storage_test");
+    let storage = AmqpExchangeStorage::new(engine.clone());
+    for (vhost, exchange_name) in [("/", "logs"), ("/", "orders"), ("//a", "logs")] {
+      test_commit(&engine, |batch| {
+        storage.save(
+          batch,
+          &Exchange {
+            cluster_name: "amqp".to_string(),
+            vhost: vhost.to_string(),
+            exchange_name: exchange_name.to_string(),
+            exchange_type: "EXCHANGE_TYPE_TOPIC".to_string(),
+            durable: true,
+            ..Default::default()
+          },
+        )
+      });
+    }
+
+    // vhost "//a" 的 key 同样以 vhost "/" 的前缀开头
+    let exchanges = storage.list("amqp", "/").unwrap();
+    assert_eq!(exchanges.len(), 2);
+    assert!(exchanges.iter().all(|exchange| exchange.vhost == "/"));
+
+    test_commit(&engine, |batch| storage.delete(batch, "amqp", "/", "logs"));
+    assert!(storage.get("amqp", "/", "logs").unwrap().is_none());
+    assert!(storage.get("amqp", "//a", "logs").unwrap().is_some());
+  }
+}
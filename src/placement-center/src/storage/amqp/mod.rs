@@ -0,0 +1,4 @@
+pub mod binding;
+pub mod exchange;
+pub mod queue;
+pub mod vhost;
@@ -0,0 +1,113 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_amqp_queue, storage_key_amqp_queue_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// AMQP queue，`exclusive` 和 `auto_delete` 的语义由 Broker 负责实现
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Queue {
+  pub cluster_name: String,
+  pub vhost: String,
+  pub queue_name: String,
+  pub durable: bool,
+  pub exclusive: bool,
+  pub auto_delete: bool,
+  pub arguments: BTreeMap<String, String>,
+  pub create_time: u64,
+}
+
+pub struct AmqpQueueStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl AmqpQueueStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    AmqpQueueStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, queue: &Queue) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_amqp_queue(&queue.cluster_name, &queue.vhost, &queue.queue_name),
+      queue,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    vhost: &str,
+    queue_name: &str,
+  ) -> Result<Option<Queue>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_queue(cluster_name, vhost, queue_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Queue>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str, vhost: &str) -> Result<Vec<Queue>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_queue_prefix(cluster_name, vhost),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      let queue = serde_json::from_slice::<Queue>(&data.data)?;
+      // 过滤掉名称以该 vhost 开头的其它 vhost 中的 queue
+      if queue.vhost == vhost {
+        results.push(queue);
+      }
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    vhost: &str,
+    queue_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_amqp_queue(cluster_name, vhost, queue_name));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{AmqpQueueStorage, Queue};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn amqp_queue_storage_test() {
+    let engine = test_rocksdb_engine("amqp_queue_storage_test");
+    let storage = AmqpQueueStorage::new(engine.clone());
+    let queue = Queue {
+      cluster_name: "amqp".to_string(),
+      vhost: "/".to_string(),
+      queue_name: "orders".to_string(),
+      durable: true,
+      arguments: [("x-max-length".to_string(), "100".to_string())].into(),
+      ..Default::default()
+    };
+    test_commit(&engine, |batch| storage.save(batch, &queue));
+    assert_eq!(storage.get("amqp", "/", "orders").unwrap(), Some(queue));
+    assert_eq!(storage.list("amqp", "/").unwrap().len(), 1);
+
+    test_commit(&engine, |batch| {
+      storage.delete(batch, "amqp", "/", "orders")
+    });
+    assert!(storage.list("amqp", "/").unwrap().is_empty());
+  }
+}
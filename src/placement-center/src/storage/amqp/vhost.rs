@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_amqp_vhost, storage_key_amqp_vhost_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// AMQP 虚拟主机，exchange 和 queue 都属于某个 vhost
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Vhost {
+  pub cluster_name: String,
+  pub vhost: String,
+  pub create_time: u64,
+}
+
+pub struct AmqpVhostStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl AmqpVhostStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    AmqpVhostStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, vhost: &Vhost) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_amqp_vhost(&vhost.cluster_name, &vhost.vhost),
+      vhost,
+    );
+  }
+
+  pub fn get(&self, cluster_name: &str, vhost: &str) -> Result<Option<Vhost>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_vhost(cluster_name, vhost),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Vhost>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str) -> Result<Vec<Vhost>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_amqp_vhost_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Vhost>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    vhost: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_amqp_vhost(cluster_name, vhost));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{AmqpVhostStorage, Vhost};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn amqp_vhost_storage_test() {
+    let engine = test_rocksdb_engine("amqp_vhost_storage_test");
+    let storage = AmqpVhostStorage::new(engine.clone());
+    for vhost in ["/", "orders"] {
+      test_commit(&engine, |batch| {
+        storage.save(
+          batch,
+          &Vhost {
+            cluster_name: "amqp".to_string(),
+            vhost: vhost.to_string(),
+            create_time: 0,
+          },
+        )
+      });
+    }
+    assert_eq!(storage.list("amqp").unwrap().len(), 2);
+    assert!(storage.get("amqp", "/").unwrap().is_some());
+
+    test_commit(&engine, |batch| storage.delete(batch, "amqp", "/"));
+    assert!(storage.get("amqp", "/").unwrap().is_none());
+    assert_eq!(storage.list("amqp").unwrap().len(), 1);
+  }
+}
@@ -0,0 +1,106 @@
+use common_base::error::CommonError;
+use serde::Serialize;
+
+use super::StorageDataWrap;
+
+/// 批次中的一条写操作，key 和 value 均为写入 RocksDB 的原始数据
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageBatchOp {
+  Put(Vec<u8>, Vec<u8>),
+  Delete(Vec<u8>),
+  // 删除 [start_key, end_key) 范围内的全部 key
+  DeleteRange(Vec<u8>, Vec<u8>),
+}
+
+/// 应用一条 Raft 日志时产生的全部写操作。
+/// 存储模块只向批次中追加操作，由状态机在日志应用完成后与应用进度在同一个 WriteBatch 中提交，
+/// 因此应用过程中读取到的仍然是提交之前的数据
+#[derive(Debug, Default)]
+pub struct StorageBatch {
+  ops: Vec<StorageBatchOp>,
+}
+
+impl StorageBatch {
+  pub fn new() -> Self {
+    StorageBatch::default()
+  }
+
+  // 与 engine_save_by_cluster 的存储格式一致，数据序列化为 JSON 后放入 StorageDataWrap
+  pub fn save<T: Serialize>(&mut self, key_name: String, value: &T) -> Result<(), CommonError> {
+    let data = StorageDataWrap::new(serde_json::to_vec(value)?);
+    self.put_raw(key_name.into_bytes(), serde_json::to_vec(&data)?);
+    return Ok(());
+  }
+
+  pub fn delete(&mut self, key_name: String) {
+    self.delete_raw(key_name.into_bytes());
+  }
+
+  pub fn put_raw(&mut self, key: Vec<u8>, value: Vec<u8>) {
+    self.ops.push(StorageBatchOp::Put(key, value));
+  }
+
+  pub fn delete_raw(&mut self, key: Vec<u8>) {
+    self.ops.push(StorageBatchOp::Delete(key));
+  }
+
+  pub fn delete_range(&mut self, start_key: Vec<u8>, end_key: Vec<u8>) {
+    self
+      .ops
+      .push(StorageBatchOp::DeleteRange(start_key, end_key));
+  }
+
+  // 追加原始记录，value 为 None 表示删除
+  pub fn extend_raw(&mut self, records: Vec<(Vec<u8>, Option<Vec<u8>>)>) {
+    for (key, value) in records {
+      match value {
+        Some(value) => self.put_raw(key, value),
+        None => self.delete_raw(key),
+      }
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.ops.is_empty()
+  }
+
+  pub fn into_ops(self) -> Vec<StorageBatchOp> {
+    self.ops
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::StorageBatch;
+  use crate::storage::{
+    engine::{engine_get_by_cluster, engine_write_batch_by_cluster},
+    rocksdb::test_rocksdb_engine,
+  };
+
+  #[test]
+  fn storage_batch_test() {
+    let engine = test_rocksdb_engine("storage_batch_test");
+    let mut batch = StorageBatch::new();
+    batch.save("/batch/1".to_string(), &"v1").unwrap();
+    batch.save("/batch/2".to_string(), &"v2").unwrap();
+    batch.delete("/batch/2".to_string());
+
+    // 提交之前批次中的数据不可见
+    assert!(
+      engine_get_by_cluster(engine.clone(), "/batch/1".to_string())
+        .unwrap()
+        .is_none()
+    );
+
+    engine_write_batch_by_cluster(engine.clone(), batch).unwrap();
+    let data = engine_get_by_cluster(engine.clone(), "/batch/1".to_string())
+      .unwrap()
+      .unwrap();
+    assert_eq!(serde_json::from_slice::<String>(&data.data).unwrap(), "v1");
+    assert!(
+      engine_get_by_cluster(engine, "/batch/2".to_string())
+        .unwrap()
+        .is_none()
+    );
+  }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use super::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{
+    storage_key_cluster, storage_key_cluster_prefix, storage_key_node, storage_key_node_prefix,
+  },
+  rocksdb::RocksDBEngine,
+};
+
+/// 集群信息，`cluster_type` 为 `ClusterType` 在 proto 中的名称
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cluster {
+  pub cluster_name: String,
+  pub cluster_type: String,
+  pub create_time: u64,
+}
+
+/// 注册到集群中的 Broker 节点，`register_index` 为最近一次注册的 Raft 日志索引
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+  pub cluster_name: String,
+  pub cluster_type: String,
+  pub node_id: u64,
+  pub node_ip: String,
+  pub node_inner_addr: String,
+  pub extend_info: String,
+  pub create_time: u64,
+  #[serde(default)]
+  pub register_index: u64,
+}
+
+pub struct ClusterStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl ClusterStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    ClusterStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save_cluster(
+    &self,
+    batch: &mut StorageBatch,
+    cluster: &Cluster,
+  ) -> Result<(), CommonError> {
+    return batch.save(storage_key_cluster(&cluster.cluster_name), cluster);
+  }
+
+  pub fn get_cluster(&self, cluster_name: &str) -> Result<Option<Cluster>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_cluster(cluster_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Cluster>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list_clusters(&self) -> Result<Vec<Cluster>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_cluster_prefix(),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Cluster>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  // 删除集群以及集群中的所有节点，节点和集群在同一个批次中删除，避免留下不属于任何集群的节点
+  pub fn delete_cluster(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+  ) -> Result<(), CommonError> {
+    for node in self.list_nodes(cluster_name)? {
+      self.delete_node(batch, cluster_name, node.node_id)?;
+    }
+    batch.delete(storage_key_cluster(cluster_name));
+    return Ok(());
+  }
+
+  pub fn save_node(&self, batch: &mut StorageBatch, node: &Node) -> Result<(), CommonError> {
+    return batch.save(storage_key_node(&node.cluster_name, node.node_id), node);
+  }
+
+  pub fn get_node(&self, cluster_name: &str, node_id: u64) -> Result<Option<Node>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_node(cluster_name, node_id),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Node>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list_nodes(&self, cluster_name: &str) -> Result<Vec<Node>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_node_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Node>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete_node(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    node_id: u64,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_node(cluster_name, node_id));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Cluster, ClusterStorage, Node};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn cluster_storage_test() {
+    let engine = test_rocksdb_engine("cluster_storage_test");
+    let storage = ClusterStorage::new(engine.clone());
+    for cluster_name in ["mqtt", "mqtt-2"] {
+      test_commit(&engine, |batch| {
+        storage.save_cluster(
+          batch,
+          &Cluster {
+            cluster_name: cluster_name.to_string(),
+            cluster_type: "MQTTBrokerServer".to_string(),
+            create_time: 0,
+          },
+        )
+      });
+      for node_id in 1..=2 {
+        test_commit(&engine, |batch| {
+          storage.save_node(
+            batch,
+            &Node {
+              cluster_name: cluster_name.to_string(),
+              cluster_type: "MQTTBrokerServer".to_string(),
+              node_id,
+              node_ip: "127.0.0.1".to_string(),
+              ..Default::default()
+            },
+          )
+        });
+      }
+    }
+    assert_eq!(storage.list_clusters().unwrap().len(), 2);
+
+    // 集群名称互为前缀时节点列表不会混在一起
+    let nodes = storage.list_nodes("mqtt").unwrap();
+    assert_eq!(nodes.len(), 2);
+    assert!(nodes.iter().all(|node| node.cluster_name == "mqtt"));
+
+    test_commit(&engine, |batch| storage.delete_node(batch, "mqtt", 1));
+    assert!(storage.get_node("mqtt", 1).unwrap().is_none());
+
+    test_commit(&engine, |batch| storage.delete_cluster(batch, "mqtt"));
+    assert!(storage.get_cluster("mqtt").unwrap().is_none());
+    assert!(storage.list_nodes("mqtt").unwrap().is_empty());
+    assert_eq!(storage.list_nodes("mqtt-2").unwrap().len(), 2);
+  }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use super::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_cluster_config, storage_key_cluster_config_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// 集群中一类资源的动态配置，`config` 为 JSON 对象，
+/// `version` 为最后一次修改该配置的 Raft 日志索引
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClusterConfig {
+  pub cluster_name: String,
+  pub resource_type: String,
+  pub config: String,
+  pub version: u64,
+  pub update_time: u64,
+}
+
+pub struct ClusterConfigStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl ClusterConfigStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    ClusterConfigStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, config: &ClusterConfig) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_cluster_config(&config.cluster_name, &config.resource_type),
+      config,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    resource_type: &str,
+  ) -> Result<Option<ClusterConfig>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_cluster_config(cluster_name, resource_type),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<ClusterConfig>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str) -> Result<Vec<ClusterConfig>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_cluster_config_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<ClusterConfig>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    resource_type: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_cluster_config(cluster_name, resource_type));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{ClusterConfig, ClusterConfigStorage};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn cluster_config_storage_test() {
+    let engine = test_rocksdb_engine("cluster_config_storage_test");
+    let storage = ClusterConfigStorage::new(engine.clone());
+    for cluster_name in ["mqtt", "mqtt-2"] {
+      for resource_type in ["connection", "session"] {
+        test_commit(&engine, |batch| {
+          storage.save(
+            batch,
+            &ClusterConfig {
+              cluster_name: cluster_name.to_string(),
+              resource_type: resource_type.to_string(),
+              config: r#"{"max_connections":1000}"#.to_string(),
+              version: 1,
+              update_time: 0,
+            },
+          )
+        });
+      }
+    }
+
+    // 集群名称互为前缀时配置列表不会混在一起
+    let configs = storage.list("mqtt").unwrap();
+    assert_eq!(configs.len(), 2);
+    assert!(configs.iter().all(|config| config.cluster_name == "mqtt"));
+
+    test_commit(&engine, |batch| storage.delete(batch, "mqtt", "connection"));
+    assert!(storage.get("mqtt", "connection").unwrap().is_none());
+    assert!(storage.get("mqtt-2", "connection").unwrap().is_some());
+    assert_eq!(storage.list("mqtt").unwrap().len(), 1);
+  }
+}
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
-use common_base::errors::NezaMQError;
+use common_base::error::CommonError;
 use serde::Serialize;
 
 use super::{
   StorageDataWrap,
+  batch::StorageBatch,
   rocksdb::{DB_COLUMN_FAMILY_CLUSTER, RocksDBEngine},
 };
 
@@ -12,7 +13,7 @@ pub fn engine_save_by_cluster<T>(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   key_name: String,
   value: T,
-) -> Result<(), NezaMQError>
+) -> Result<(), CommonError>
 where
   T: Serialize,
 {
@@ -27,28 +28,28 @@ where
 pub fn engine_get_by_cluster(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   key_name: String,
-) -> Result<Option<StorageDataWrap>, NezaMQError> {
+) -> Result<Option<StorageDataWrap>, CommonError> {
   return engine_get(rocksdb_engine_handler, DB_COLUMN_FAMILY_CLUSTER, key_name);
 }
 
 pub fn engine_delete_by_cluster(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   key_name: String,
-) -> Result<(), NezaMQError> {
+) -> Result<(), CommonError> {
   return engine_delete(rocksdb_engine_handler, DB_COLUMN_FAMILY_CLUSTER, key_name);
 }
 
 pub fn engine_exists_by_cluster(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   key_name: String,
-) -> Result<bool, NezaMQError> {
+) -> Result<bool, CommonError> {
   return engine_exists(rocksdb_engine_handler, DB_COLUMN_FAMILY_CLUSTER, key_name);
 }
 
 pub fn engine_prefix_list_by_cluster(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   prefix_key_name: String,
-) -> Result<Vec<StorageDataWrap>, NezaMQError> {
+) -> Result<Vec<StorageDataWrap>, CommonError> {
   return engine_prefix_list(
     rocksdb_engine_handler,
     DB_COLUMN_FAMILY_CLUSTER,
@@ -56,31 +57,65 @@ pub fn engine_prefix_list_by_cluster(
   );
 }
 
+// 以下接口直接读写原始数据，不经过 StorageDataWrap 和 JSON 序列化，用于二进制安全的 KV 数据
+pub fn engine_get_raw_by_cluster(
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  key: &[u8],
+) -> Result<Option<Vec<u8>>, CommonError> {
+  return rocksdb_engine_handler.read_raw(DB_COLUMN_FAMILY_CLUSTER, key);
+}
+
+pub fn engine_exists_raw_by_cluster(
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  key: &[u8],
+) -> Result<bool, CommonError> {
+  return rocksdb_engine_handler.exist(DB_COLUMN_FAMILY_CLUSTER, key);
+}
+
+// 提交一条 Raft 日志应用过程中产生的全部写操作
+pub fn engine_write_batch_by_cluster(
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  batch: StorageBatch,
+) -> Result<(), CommonError> {
+  return rocksdb_engine_handler.write_ops(DB_COLUMN_FAMILY_CLUSTER, batch.into_ops());
+}
+
+// 按 key 范围扫描 [start_key, end_key)，返回原始的 key 和 value
+pub fn engine_range_raw_by_cluster(
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+  start_key: &[u8],
+  end_key: Option<&[u8]>,
+  limit: usize,
+  reverse: bool,
+) -> Result<Vec<(Vec<u8>, Vec<u8>)>, CommonError> {
+  return rocksdb_engine_handler.read_range(
+    DB_COLUMN_FAMILY_CLUSTER,
+    start_key,
+    end_key,
+    limit,
+    reverse,
+  );
+}
+
 fn engine_save<T>(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   rocksdb_cluster: &str,
   key_name: String,
   value: T,
-) -> Result<(), NezaMQError>
+) -> Result<(), CommonError>
 where
   T: Serialize,
 {
-  let cf = if rocksdb_cluster.to_string() == DB_COLUMN_FAMILY_CLUSTER.to_string() {
-    rocksdb_engine_handler.cf_cluster()
-  } else {
-    return Err(NezaMQError::ClusterNoAvailableNode);
-  };
-
   let content = match serde_json::to_vec(&value) {
     Ok(data) => data,
-    Err(e) => return Err(NezaMQError::CommonError(e.to_string())),
+    Err(e) => return Err(CommonError::CommonError(e.to_string())),
   };
 
   let data = StorageDataWrap::new(content);
-  match rocksdb_engine_handler.write(cf, &key_name, &data) {
+  match rocksdb_engine_handler.write(rocksdb_cluster, &key_name, &data) {
     Ok(_) => return Ok(()),
     Err(e) => {
-      return Err(NezaMQError::CommonError(e));
+      return Err(CommonError::CommonError(e));
     }
   }
 }
@@ -89,14 +124,8 @@ fn engine_get(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   rocksdb_cluster: &str,
   key_name: String,
-) -> Result<Option<StorageDataWrap>, NezaMQError> {
-  let cf = if rocksdb_cluster.to_string() == DB_COLUMN_FAMILY_CLUSTER.to_string() {
-    rocksdb_engine_handler.cf_cluster()
-  } else {
-    return Err(NezaMQError::ClusterNoAvailableNode);
-  };
-
-  match rocksdb_engine_handler.read::<StorageDataWrap>(cf, &key_name) {
+) -> Result<Option<StorageDataWrap>, CommonError> {
+  match rocksdb_engine_handler.read::<StorageDataWrap>(rocksdb_cluster, &key_name) {
     Ok(Some(data)) => {
       return Ok(Some(data));
     }
@@ -106,7 +135,7 @@ fn engine_get(
     }
 
     Err(e) => {
-      return Err(NezaMQError::CommonError(e));
+      return Err(CommonError::CommonError(e));
     }
   }
 }
@@ -115,42 +144,24 @@ fn engine_delete(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   rocksdb_cluster: &str,
   key_name: String,
-) -> Result<(), NezaMQError> {
-  let cf = if rocksdb_cluster.to_string() == DB_COLUMN_FAMILY_CLUSTER.to_string() {
-    rocksdb_engine_handler.cf_cluster()
-  } else {
-    return Err(NezaMQError::ClusterNoAvailableNode);
-  };
-
-  rocksdb_engine_handler.delete(cf, &key_name)
+) -> Result<(), CommonError> {
+  rocksdb_engine_handler.delete(rocksdb_cluster, &key_name)
 }
 
 fn engine_exists(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   rocksdb_cluster: &str,
   key_name: String,
-) -> Result<bool, NezaMQError> {
-  let cf = if rocksdb_cluster.to_string() == DB_COLUMN_FAMILY_CLUSTER.to_string() {
-    rocksdb_engine_handler.cf_cluster()
-  } else {
-    return Err(NezaMQError::ClusterNoAvailableNode);
-  };
-
-  return Ok(rocksdb_engine_handler.exist(cf, &key_name));
+) -> Result<bool, CommonError> {
+  return rocksdb_engine_handler.exist(rocksdb_cluster, &key_name);
 }
 
 fn engine_prefix_list(
   rocksdb_engine_handler: Arc<RocksDBEngine>,
   rocksdb_cluster: &str,
   prefix_key_name: String,
-) -> Result<Vec<StorageDataWrap>, NezaMQError> {
-  let cf = if rocksdb_cluster.to_string() == DB_COLUMN_FAMILY_CLUSTER.to_string() {
-    rocksdb_engine_handler.cf_cluster()
-  } else {
-    return Err(NezaMQError::ClusterNoAvailableNode);
-  };
-
-  let data_list = rocksdb_engine_handler.read_prefix(cf, &prefix_key_name);
+) -> Result<Vec<StorageDataWrap>, CommonError> {
+  let data_list = rocksdb_engine_handler.read_prefix(rocksdb_cluster, &prefix_key_name)?;
   let mut results = Vec::new();
   for raw in data_list {
     for (_, v) in raw {
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use super::{
+  batch::StorageBatch, engine::engine_get_by_cluster, keys::storage_key_id_namespace,
+  rocksdb::RocksDBEngine,
+};
+
+/// namespace 的高水位，`next_id` 之前的 ID 都已经分配出去，重启后从 `next_id` 继续分配
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IdNamespace {
+  pub namespace: String,
+  pub next_id: u64,
+  pub update_time: u64,
+}
+
+pub struct IdStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl IdStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    IdStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, namespace: &IdNamespace) -> Result<(), CommonError> {
+    return batch.save(storage_key_id_namespace(&namespace.namespace), namespace);
+  }
+
+  pub fn get(&self, namespace: &str) -> Result<Option<IdNamespace>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_id_namespace(namespace),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<IdNamespace>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use prost::Message;
+  use protocol::id::{AllocateIdsData, AllocateIdsReply, AllocateIdsRequest};
+
+  use super::{IdNamespace, IdStorage};
+  use crate::{
+    openraft::route::AppResponseData,
+    route::id::{DataRouteId, FIRST_ID},
+    storage::{
+      batch::StorageBatch,
+      engine::engine_write_batch_by_cluster,
+      rocksdb::{test_commit, test_rocksdb_engine},
+    },
+  };
+
+  #[test]
+  fn id_storage_test() {
+    let engine = test_rocksdb_engine("id_storage_test");
+    let storage = IdStorage::new(engine.clone());
+    assert!(storage.get("segment").unwrap().is_none());
+
+    test_commit(&engine, |batch| {
+      storage.save(
+        batch,
+        &IdNamespace {
+          namespace: "segment".to_string(),
+          next_id: 1001,
+          update_time: 0,
+        },
+      )
+    });
+    assert_eq!(storage.get("segment").unwrap().unwrap().next_id, 1001);
+    assert!(storage.get("session").unwrap().is_none());
+  }
+
+  #[test]
+  fn id_allocate_test() {
+    let engine = test_rocksdb_engine("id_allocate_test");
+    let route = DataRouteId::new(engine.clone());
+    let allocate = |namespace: &str, count: u64| {
+      let data = AllocateIdsData {
+        request: Some(AllocateIdsRequest {
+          namespace: namespace.to_string(),
+          count,
+        }),
+        update_time: 0,
+      };
+      let mut batch = StorageBatch::new();
+      let reply = route
+        .allocate(AllocateIdsData::encode_to_vec(&data), &mut batch)
+        .unwrap();
+      engine_write_batch_by_cluster(engine.clone(), batch).unwrap();
+      match reply {
+        AppResponseData::Value(value) => AllocateIdsReply::decode(value.as_ref()).unwrap(),
+        _ => panic!("unexpected allocate reply"),
+      }
+    };
+
+    // 每次分配的区间紧接着上一次的区间，不会重叠
+    let mut next_id = FIRST_ID;
+    for count in [1, 10, 3] {
+      let reply = allocate("segment", count);
+      assert_eq!(reply.start_id, next_id);
+      assert_eq!(reply.end_id, next_id + count);
+      next_id = reply.end_id;
+    }
+
+    // 不同 namespace 独立分配
+    let reply = allocate("session", 5);
+    assert_eq!(reply.start_id, FIRST_ID);
+    assert_eq!(reply.end_id, FIRST_ID + 5);
+    assert_eq!(
+      IdStorage::new(engine.clone())
+        .get("segment")
+        .unwrap()
+        .unwrap()
+        .next_id,
+      next_id
+    );
+  }
+}
@@ -0,0 +1,3 @@
+pub mod offset;
+pub mod segment;
+pub mod shard;
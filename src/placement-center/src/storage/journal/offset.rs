@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_journal_offset, storage_key_journal_offset_group_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// 消费组在分片上已经提交的 offset，`metadata` 由客户端自行定义
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShardOffset {
+  pub cluster_name: String,
+  pub group_name: String,
+  pub namespace: String,
+  pub shard_name: String,
+  pub offset: i64,
+  pub metadata: String,
+  pub commit_time: u64,
+}
+
+pub struct OffsetStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl OffsetStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    OffsetStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, offset: &ShardOffset) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_journal_offset(
+        &offset.cluster_name,
+        &offset.group_name,
+        &offset.namespace,
+        &offset.shard_name,
+      ),
+      offset,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    group_name: &str,
+    namespace: &str,
+    shard_name: &str,
+  ) -> Result<Option<ShardOffset>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_journal_offset(cluster_name, group_name, namespace, shard_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<ShardOffset>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // 返回消费组在所有分片上提交的 offset
+  pub fn list(
+    &self,
+    cluster_name: &str,
+    group_name: &str,
+  ) -> Result<Vec<ShardOffset>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_journal_offset_group_prefix(cluster_name, group_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<ShardOffset>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    group_name: &str,
+    namespace: &str,
+    shard_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_journal_offset(
+      cluster_name,
+      group_name,
+      namespace,
+      shard_name,
+    ));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{OffsetStorage, ShardOffset};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn journal_offset_storage_test() {
+    let engine = test_rocksdb_engine("journal_offset_storage_test");
+    let storage = OffsetStorage::new(engine.clone());
+    // 同一个请求中的 offset 在一个批次中提交
+    test_commit(&engine, |batch| {
+      for (group_name, shard_name, offset) in [
+        ("g1", "orders", 10),
+        ("g1", "payments", 3),
+        ("g1-2", "orders", 7),
+      ] {
+        storage.save(
+          batch,
+          &ShardOffset {
+            cluster_name: "journal".to_string(),
+            group_name: group_name.to_string(),
+            namespace: "ns".to_string(),
+            shard_name: shard_name.to_string(),
+            offset,
+            ..Default::default()
+          },
+        )?;
+      }
+      return Ok(());
+    });
+
+    // 消费组名称互为前缀时 offset 不会混在一起
+    assert_eq!(storage.list("journal", "g1").unwrap().len(), 2);
+    assert_eq!(
+      storage
+        .get("journal", "g1-2", "ns", "orders")
+        .unwrap()
+        .unwrap()
+        .offset,
+      7
+    );
+
+    test_commit(&engine, |batch| {
+      storage.delete(batch, "journal", "g1", "ns", "orders")
+    });
+    assert!(
+      storage
+        .get("journal", "g1", "ns", "orders")
+        .unwrap()
+        .is_none()
+    );
+    assert_eq!(storage.list("journal", "g1").unwrap().len(), 1);
+  }
+}
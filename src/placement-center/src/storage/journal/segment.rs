@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{
+    storage_key_journal_segment, storage_key_journal_segment_cluster_prefix,
+    storage_key_journal_segment_shard_prefix,
+  },
+  rocksdb::RocksDBEngine,
+};
+
+/// 分片中的 Segment，`status` 为 `SegmentStatus` 在 proto 中的名称，
+/// `end_offset` 在 Segment 封存之前为 -1
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Segment {
+  pub cluster_name: String,
+  pub namespace: String,
+  pub shard_name: String,
+  pub segment_seq: u32,
+  pub status: String,
+  pub leader: u64,
+  pub replicas: Vec<u64>,
+  pub start_offset: i64,
+  pub end_offset: i64,
+  pub create_time: u64,
+}
+
+pub struct SegmentStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl SegmentStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    SegmentStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, segment: &Segment) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_journal_segment(
+        &segment.cluster_name,
+        &segment.namespace,
+        &segment.shard_name,
+        segment.segment_seq,
+      ),
+      segment,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    namespace: &str,
+    shard_name: &str,
+    segment_seq: u32,
+  ) -> Result<Option<Segment>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_journal_segment(cluster_name, namespace, shard_name, segment_seq),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Segment>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // 按 segment_seq 从小到大返回分片中的所有 Segment
+  pub fn list(
+    &self,
+    cluster_name: &str,
+    namespace: &str,
+    shard_name: &str,
+  ) -> Result<Vec<Segment>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_journal_segment_shard_prefix(cluster_name, namespace, shard_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Segment>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  // 返回集群中所有分片的 Segment，用于统计节点上的 Segment 数量
+  pub fn list_by_cluster(&self, cluster_name: &str) -> Result<Vec<Segment>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_journal_segment_cluster_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Segment>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    namespace: &str,
+    shard_name: &str,
+    segment_seq: u32,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_journal_segment(
+      cluster_name,
+      namespace,
+      shard_name,
+      segment_seq,
+    ));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Segment, SegmentStorage};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn journal_segment_storage_test() {
+    let engine = test_rocksdb_engine("journal_segment_storage_test");
+    let storage = SegmentStorage::new(engine.clone());
+    for segment_seq in [10, 2, 1] {
+      test_commit(&engine, |batch| {
+        storage.save(
+          batch,
+          &Segment {
+            cluster_name: "journal".to_string(),
+            namespace: "ns".to_string(),
+            shard_name: "orders".to_string(),
+            segment_seq,
+            replicas: vec![1, 2, 3],
+            end_offset: -1,
+            ..Default::default()
+          },
+        )
+      });
+    }
+
+    // Segment 按序号排列，而不是按字符串排列
+    let segments = storage.list("journal", "ns", "orders").unwrap();
+    let seqs: Vec<u32> = segments.iter().map(|segment| segment.segment_seq).collect();
+    assert_eq!(seqs, vec![1, 2, 10]);
+    assert_eq!(storage.list_by_cluster("journal").unwrap().len(), 3);
+    assert!(storage.list_by_cluster("journal-2").unwrap().is_empty());
+
+    test_commit(&engine, |batch| {
+      storage.delete(batch, "journal", "ns", "orders", 1)
+    });
+    assert!(storage.get("journal", "ns", "orders", 1).unwrap().is_none());
+    assert_eq!(storage.list("journal", "ns", "orders").unwrap().len(), 2);
+  }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{
+    storage_key_journal_shard, storage_key_journal_shard_cluster_prefix,
+    storage_key_journal_shard_namespace_prefix,
+  },
+  rocksdb::RocksDBEngine,
+};
+
+/// Journal 分片，`active_segment_seq` 为当前写入的 Segment
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Shard {
+  pub cluster_name: String,
+  pub namespace: String,
+  pub shard_name: String,
+  pub replica_num: u32,
+  pub active_segment_seq: u32,
+  pub create_time: u64,
+}
+
+pub struct ShardStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl ShardStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    ShardStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, shard: &Shard) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_journal_shard(&shard.cluster_name, &shard.namespace, &shard.shard_name),
+      shard,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    namespace: &str,
+    shard_name: &str,
+  ) -> Result<Option<Shard>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_journal_shard(cluster_name, namespace, shard_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Shard>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // namespace 为空时返回集群中的所有分片
+  pub fn list(&self, cluster_name: &str, namespace: &str) -> Result<Vec<Shard>, CommonError> {
+    let prefix = if namespace.is_empty() {
+      storage_key_journal_shard_cluster_prefix(cluster_name)
+    } else {
+      storage_key_journal_shard_namespace_prefix(cluster_name, namespace)
+    };
+    let data_list = engine_prefix_list_by_cluster(self.rocksdb_engine_handler.clone(), prefix)?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Shard>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    namespace: &str,
+    shard_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_journal_shard(
+      cluster_name,
+      namespace,
+      shard_name,
+    ));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Shard, ShardStorage};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn journal_shard_storage_test() {
+    let engine = test_rocksdb_engine("journal_shard_storage_test");
+    let storage = ShardStorage::new(engine.clone());
+    for (namespace, shard_name) in [("ns", "orders"), ("ns", "payments"), ("ns-2", "orders")] {
+      test_commit(&engine, |batch| {
+        storage.save(
+          batch,
+          &Shard {
+            cluster_name: "journal".to_string(),
+            namespace: namespace.to_string(),
+            shard_name: shard_name.to_string(),
+            replica_num: 3,
+            ..Default::default()
+          },
+        )
+      });
+    }
+    assert_eq!(storage.list("journal", "").unwrap().len(), 3);
+    assert_eq!(storage.list("journal", "ns").unwrap().len(), 2);
+
+    test_commit(&engine, |batch| {
+      storage.delete(batch, "journal", "ns", "orders")
+    });
+    assert!(storage.get("journal", "ns", "orders").unwrap().is_none());
+    assert!(storage.get("journal", "ns-2", "orders").unwrap().is_some());
+  }
+}
@@ -27,21 +27,338 @@ pub fn key_name_snapshot()->String{
   return "/raft/snapshot".to_string();
 }
 
+// 状态机最后应用的日志ID和成员信息，与业务数据保存在同一个 RocksDB 中，随快照一起恢复
+pub fn key_name_applied_state() -> String {
+  return "/raft/applied_state".to_string();
+}
+
 /** ======Cluster============ */
+pub fn storage_key_cluster(cluster_name: &str) -> String {
+  return format!("/cluster/info/{}", cluster_name);
+}
+
+pub fn storage_key_cluster_prefix() -> String {
+  return "/cluster/info/".to_string();
+}
+
+// 集群中的节点，前缀以 / 结尾，避免匹配到名称以该集群名称开头的其它集群
+pub fn storage_key_node(cluster_name: &str, node_id: u64) -> String {
+  return format!("/cluster/node/{}/{}", cluster_name, node_id);
+}
+
+pub fn storage_key_node_prefix(cluster_name: &str) -> String {
+  return format!("/cluster/node/{}/", cluster_name);
+}
+
+/** ======Mqtt============ */
 // 用户User的key
-pub fn storage_key_mqtt_user(cluster_name: &String, user_name: &String) -> String {
+pub fn storage_key_mqtt_user(cluster_name: &str, user_name: &str) -> String {
   return format!("/mqtt/user/{}/{}", cluster_name, user_name);
 }
 
-// 用户使用前缀搜索
-pub fn storage_key_mqtt_user_cluster_prefix(cluster_name: &String) -> String {
-  return format!("/mqtt/user/{}", cluster_name);
+// 用户使用前缀搜索，前缀以 / 结尾，避免匹配到名称以该集群名称开头的其它集群
+pub fn storage_key_mqtt_user_cluster_prefix(cluster_name: &str) -> String {
+  return format!("/mqtt/user/{}/", cluster_name);
+}
+
+// 主题名称中可以包含 /，主题的 key 只能按集群前缀搜索
+pub fn storage_key_mqtt_topic(cluster_name: &str, topic_name: &str) -> String {
+  return format!("/mqtt/topic/{}/{}", cluster_name, topic_name);
+}
+
+pub fn storage_key_mqtt_topic_prefix(cluster_name: &str) -> String {
+  return format!("/mqtt/topic/{}/", cluster_name);
+}
+
+// 所有集群的主题，用于 Leader 清理已经过期的保留消息
+pub fn storage_key_mqtt_topic_all_prefix() -> String {
+  return "/mqtt/topic/".to_string();
+}
+
+// 持久会话按客户端 ID 保存
+pub fn storage_key_mqtt_session(cluster_name: &str, client_id: &str) -> String {
+  return format!("/mqtt/session/{}/{}", cluster_name, client_id);
+}
+
+pub fn storage_key_mqtt_session_prefix(cluster_name: &str) -> String {
+  return format!("/mqtt/session/{}/", cluster_name);
+}
+
+// ACL 规则按作用对象分组，topic_filter 放在最后，其中可以包含 /。
+// resource_name 可能是包含 / 的 IP 网段，需要转义后再放入 key 中
+pub fn storage_key_mqtt_acl(
+  cluster_name: &str,
+  resource_type: &str,
+  resource_name: &str,
+  action: &str,
+  topic_filter: &str,
+) -> String {
+  return format!(
+    "/mqtt/acl/{}/{}/{}/{}/{}",
+    cluster_name,
+    resource_type,
+    escape_key_segment(resource_name),
+    action,
+    topic_filter
+  );
+}
+
+pub fn storage_key_mqtt_acl_prefix(cluster_name: &str) -> String {
+  return format!("/mqtt/acl/{}/", cluster_name);
+}
+
+pub fn storage_key_mqtt_blacklist(
+  cluster_name: &str,
+  blacklist_type: &str,
+  resource_name: &str,
+) -> String {
+  return format!(
+    "/mqtt/blacklist/{}/{}/{}",
+    cluster_name,
+    blacklist_type,
+    escape_key_segment(resource_name)
+  );
+}
+
+pub fn storage_key_mqtt_blacklist_prefix(cluster_name: &str) -> String {
+  return format!("/mqtt/blacklist/{}/", cluster_name);
+}
+
+// 所有集群的黑名单，用于 Leader 清理已经失效的黑名单
+pub fn storage_key_mqtt_blacklist_all_prefix() -> String {
+  return "/mqtt/blacklist/".to_string();
+}
+
+/** ======Amqp============ */
+// vhost 可以包含 /（例如默认的 vhost "/"），exchange、queue 和 routing_key 不能包含 /，
+// 按 vhost 前缀遍历时可能包含名称以该 vhost 开头的其它 vhost，需要按 vhost 过滤
+pub fn storage_key_amqp_vhost(cluster_name: &str, vhost: &str) -> String {
+  return format!("/amqp/vhost/{}/{}", cluster_name, vhost);
+}
+
+pub fn storage_key_amqp_vhost_prefix(cluster_name: &str) -> String {
+  return format!("/amqp/vhost/{}/", cluster_name);
+}
+
+pub fn storage_key_amqp_exchange(cluster_name: &str, vhost: &str, exchange_name: &str) -> String {
+  return format!(
+    "/amqp/exchange/{}/{}/{}",
+    cluster_name, vhost, exchange_name
+  );
+}
+
+pub fn storage_key_amqp_exchange_prefix(cluster_name: &str, vhost: &str) -> String {
+  return format!("/amqp/exchange/{}/{}/", cluster_name, vhost);
+}
+
+pub fn storage_key_amqp_queue(cluster_name: &str, vhost: &str, queue_name: &str) -> String {
+  return format!("/amqp/queue/{}/{}/{}", cluster_name, vhost, queue_name);
+}
+
+pub fn storage_key_amqp_queue_prefix(cluster_name: &str, vhost: &str) -> String {
+  return format!("/amqp/queue/{}/{}/", cluster_name, vhost);
+}
+
+pub fn storage_key_amqp_binding(
+  cluster_name: &str,
+  vhost: &str,
+  exchange_name: &str,
+  queue_name: &str,
+  routing_key: &str,
+) -> String {
+  return format!(
+    "/amqp/binding/{}/{}/{}/{}/{}",
+    cluster_name, vhost, exchange_name, queue_name, routing_key
+  );
+}
+
+pub fn storage_key_amqp_binding_prefix(cluster_name: &str, vhost: &str) -> String {
+  return format!("/amqp/binding/{}/{}/", cluster_name, vhost);
+}
+
+/** ======Journal============ */
+pub fn storage_key_journal_shard(cluster_name: &str, namespace: &str, shard_name: &str) -> String {
+  return format!(
+    "/journal/shard/{}/{}/{}",
+    cluster_name, namespace, shard_name
+  );
+}
+
+pub fn storage_key_journal_shard_cluster_prefix(cluster_name: &str) -> String {
+  return format!("/journal/shard/{}/", cluster_name);
+}
+
+pub fn storage_key_journal_shard_namespace_prefix(cluster_name: &str, namespace: &str) -> String {
+  return format!("/journal/shard/{}/{}/", cluster_name, namespace);
+}
+
+// segment_seq 补齐为固定宽度，按前缀遍历时 Segment 按序号排列
+pub fn storage_key_journal_segment(
+  cluster_name: &str,
+  namespace: &str,
+  shard_name: &str,
+  segment_seq: u32,
+) -> String {
+  return format!(
+    "/journal/segment/{}/{}/{}/{:010}",
+    cluster_name, namespace, shard_name, segment_seq
+  );
+}
+
+pub fn storage_key_journal_segment_cluster_prefix(cluster_name: &str) -> String {
+  return format!("/journal/segment/{}/", cluster_name);
+}
+
+pub fn storage_key_journal_segment_shard_prefix(
+  cluster_name: &str,
+  namespace: &str,
+  shard_name: &str,
+) -> String {
+  return format!(
+    "/journal/segment/{}/{}/{}/",
+    cluster_name, namespace, shard_name
+  );
+}
+
+pub fn storage_key_journal_offset(
+  cluster_name: &str,
+  group_name: &str,
+  namespace: &str,
+  shard_name: &str,
+) -> String {
+  return format!(
+    "/journal/offset/{}/{}/{}/{}",
+    cluster_name, group_name, namespace, shard_name
+  );
+}
+
+pub fn storage_key_journal_offset_group_prefix(cluster_name: &str, group_name: &str) -> String {
+  return format!("/journal/offset/{}/{}/", cluster_name, group_name);
+}
+
+/** ======Schema============ */
+pub fn storage_key_schema_subject(cluster_name: &str, subject: &str) -> String {
+  return format!("/schema/subject/{}/{}", cluster_name, subject);
+}
+
+pub fn storage_key_schema_subject_prefix(cluster_name: &str) -> String {
+  return format!("/schema/subject/{}/", cluster_name);
+}
+
+// version 补齐为固定宽度，按前缀遍历时按版本号排列
+pub fn storage_key_schema_version(cluster_name: &str, subject: &str, version: u32) -> String {
+  return format!(
+    "/schema/version/{}/{}/{:010}",
+    cluster_name, subject, version
+  );
+}
+
+pub fn storage_key_schema_version_cluster_prefix(cluster_name: &str) -> String {
+  return format!("/schema/version/{}/", cluster_name);
+}
+
+pub fn storage_key_schema_version_subject_prefix(cluster_name: &str, subject: &str) -> String {
+  return format!("/schema/version/{}/{}/", cluster_name, subject);
+}
+
+// resource_name 可以包含 /，放在 key 的最后
+pub fn storage_key_schema_binding(
+  cluster_name: &str,
+  resource_type: &str,
+  resource_name: &str,
+) -> String {
+  return format!(
+    "/schema/binding/{}/{}/{}",
+    cluster_name, resource_type, resource_name
+  );
+}
+
+pub fn storage_key_schema_binding_prefix(cluster_name: &str) -> String {
+  return format!("/schema/binding/{}/", cluster_name);
+}
+
+/** ======Config============ */
+// resource_type 不能包含 /，前缀以 / 结尾，避免匹配到名称以该集群名称开头的其它集群
+pub fn storage_key_cluster_config(cluster_name: &str, resource_type: &str) -> String {
+  return format!("/config/info/{}/{}", cluster_name, resource_type);
+}
+
+pub fn storage_key_cluster_config_prefix(cluster_name: &str) -> String {
+  return format!("/config/info/{}/", cluster_name);
+}
+
+/** ======Id============ */
+pub fn storage_key_id_namespace(namespace: &str) -> String {
+  return format!("/id/namespace/{}", namespace);
+}
+
+/** ======Kv============ */
+// KV 数据统一存放在该前缀下，与其它元数据隔离，key 为二进制安全的原始字节
+pub fn storage_key_kv_data_prefix() -> Vec<u8> {
+  return b"/kv/data/".to_vec();
+}
+
+pub fn storage_key_kv_data(key: &[u8]) -> Vec<u8> {
+  let mut storage_key = storage_key_kv_data_prefix();
+  storage_key.extend_from_slice(key);
+  return storage_key;
+}
+
+// key 的历史版本，每个版本一条记录
+pub fn storage_key_kv_history_prefix() -> Vec<u8> {
+  return b"/kv/history/".to_vec();
+}
+
+// key 的历史版本元数据，转义后的 key 不包含 /，该记录排在这个 key 的全部版本之前
+pub fn storage_key_kv_history_meta(key: &[u8]) -> Vec<u8> {
+  let mut storage_key = storage_key_kv_history_prefix();
+  storage_key.extend_from_slice(&escape_key_bytes(key));
+  storage_key.push(b'/');
+  return storage_key;
+}
+
+// 版本号使用大端序编码，同一个 key 的版本按版本号升序排列
+pub fn storage_key_kv_history(key: &[u8], revision: u64) -> Vec<u8> {
+  let mut storage_key = storage_key_kv_history_meta(key);
+  storage_key.extend_from_slice(&revision.to_be_bytes());
+  return storage_key;
+}
+
+// 已经压缩到的版本号
+pub fn storage_key_kv_compact_revision() -> Vec<u8> {
+  return b"/kv/compact_revision".to_vec();
+}
+
+/** ======Lease============ */
+pub fn storage_key_lease(lease_id: i64) -> String {
+  return format!("/lease/info/{}", lease_id);
+}
+
+pub fn storage_key_lease_prefix() -> String {
+  return "/lease/info/".to_string();
+}
+
+// 记录 key 当前绑定的租约，key 为二进制安全的原始字节
+pub fn storage_key_lease_owner(key: &[u8]) -> Vec<u8> {
+  let mut storage_key = b"/lease/owner/".to_vec();
+  storage_key.extend_from_slice(key);
+  return storage_key;
 }
 
-pub fn storage_key_mqtt_topic(cluster_name: &String, user_name: &String) -> String {
-  return format!("/mqtt/topic/{}/{}", cluster_name, user_name);
+// 转义 key 中一段名称里的 % 和 /，转义后的名称不包含 /，不同的名称转义后也不会相同
+fn escape_key_segment(name: &str) -> String {
+  return name.replace('%', "%25").replace('/', "%2F");
 }
 
-pub fn storage_key_mqtt_topic_prefix(cluster_name: &String) -> String {
-  return format!("/mqtt/topic/{}", cluster_name);
+// 与 escape_key_segment 相同的转义规则，用于二进制安全的 key
+fn escape_key_bytes(key: &[u8]) -> Vec<u8> {
+  let mut escaped = Vec::with_capacity(key.len());
+  for &byte in key {
+    match byte {
+      b'%' => escaped.extend_from_slice(b"%25"),
+      b'/' => escaped.extend_from_slice(b"%2F"),
+      _ => escaped.push(byte),
+    }
+  }
+  return escaped;
 }
@@ -1,15 +1,93 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use common_base::errors::NezaMQError;
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 use super::{
-  engine::{
-    engine_delete_by_cluster, engine_exists_by_cluster, engine_get_by_cluster,
-    engine_save_by_cluster,
+  batch::StorageBatch,
+  engine::{engine_exists_raw_by_cluster, engine_get_raw_by_cluster, engine_range_raw_by_cluster},
+  keys::{
+    storage_key_kv_compact_revision, storage_key_kv_data, storage_key_kv_data_prefix,
+    storage_key_kv_history, storage_key_kv_history_meta, storage_key_kv_history_prefix,
   },
   rocksdb::RocksDBEngine,
 };
 
+// 范围查询未指定 limit 时的默认返回条数
+const DEFAULT_SCAN_LIMIT: usize = 100;
+// 范围查询单页允许返回的最大条数
+const MAX_SCAN_LIMIT: usize = 10000;
+// 每个 key 最多保留的历史版本数，超出后淘汰最旧的版本
+const MAX_KV_HISTORY_VERSIONS: u64 = 100;
+// 压缩时每次从存储中读取的历史记录条数
+const KV_COMPACT_PAGE_SIZE: usize = 1000;
+
+/// 范围查询的一页结果，`next_page_token` 为 None 表示没有更多数据
+#[derive(Debug, Default, PartialEq)]
+pub struct KvPage {
+  pub kvs: Vec<(Vec<u8>, Vec<u8>)>,
+  pub next_page_token: Option<Vec<u8>>,
+}
+
+/// KV 数据在存储中的格式，使用 bincode 编码，value 为原始字节。
+/// 版本号均为产生该修改的 Raft 日志索引：`create_revision` 为创建该 key 时的版本号，
+/// `mod_revision` 为最后一次修改的版本号，`version` 为创建之后被修改的次数，删除后重新创建时从 1 开始
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KvEntry {
+  pub value: Vec<u8>,
+  pub create_revision: u64,
+  pub mod_revision: u64,
+  pub version: u64,
+}
+
+/// key 的历史版本，`deleted` 为 true 表示该版本是一次删除
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KvHistoryEntry {
+  pub entry: KvEntry,
+  pub deleted: bool,
+}
+
+/// key 的历史版本元数据，`versions` 为保留的版本数，
+/// `compacted_revision` 为超出保留数量被淘汰的最新版本号，小于等于该版本的历史无法读取
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KvHistoryMeta {
+  pub compacted_revision: u64,
+  pub versions: u64,
+}
+
+/// 按版本号读取的结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum KvRevisionRead {
+  Value(Option<KvEntry>),
+  // 请求的版本已经被压缩，携带已压缩到的版本号
+  Compacted(u64),
+}
+
+/// 在 `prev` 的基础上写入新值之后 key 的状态，`prev` 为 None 表示新建 key
+pub fn next_entry(prev: Option<&KvEntry>, value: Vec<u8>, revision: u64) -> KvEntry {
+  match prev {
+    Some(prev) => KvEntry {
+      value,
+      create_revision: prev.create_revision,
+      mod_revision: revision,
+      version: prev.version + 1,
+    },
+    None => KvEntry {
+      value,
+      create_revision: revision,
+      mod_revision: revision,
+      version: 1,
+    },
+  }
+}
+
+/// 批量写入中的一条操作
+#[derive(Debug, Clone, PartialEq)]
+pub enum KvBatchOp {
+  Set(Vec<u8>, Vec<u8>),
+  Delete(Vec<u8>),
+}
+
 pub struct KvStorage {
   rocksdb_engine_handler: Arc<RocksDBEngine>,
 }
@@ -21,33 +99,616 @@ impl KvStorage {
     }
   }
 
-  pub fn set(&self, key: String, value: String) -> Result<(), NezaMQError> {
-    return engine_save_by_cluster(self.rocksdb_engine_handler.clone(), key, value);
+  pub fn set(
+    &self,
+    batch: &mut StorageBatch,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    revision: u64,
+  ) -> Result<(), CommonError> {
+    return self.write(batch, vec![KvBatchOp::Set(key, value)], revision);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    key: Vec<u8>,
+    revision: u64,
+  ) -> Result<(), CommonError> {
+    return self.write(batch, vec![KvBatchOp::Delete(key)], revision);
+  }
+
+  // 将多条写操作追加到同一个批次中原子提交，并同时记录每个 key 的历史版本。
+  // 每个版本单独一条记录，同一批次内的操作版本号相同，每个 key 只记录批次内最后的状态
+  pub fn write(
+    &self,
+    batch: &mut StorageBatch,
+    ops: Vec<KvBatchOp>,
+    revision: u64,
+  ) -> Result<(), CommonError> {
+    // 同一批次内多次修改同一个 key 时以批次内最新的状态为准
+    let mut pending: HashMap<Vec<u8>, (Option<KvEntry>, Option<KvHistoryEntry>)> = HashMap::new();
+    let mut order = Vec::new();
+    for op in ops {
+      let key = match &op {
+        KvBatchOp::Set(key, _) | KvBatchOp::Delete(key) => key.clone(),
+      };
+      if !pending.contains_key(&key) {
+        pending.insert(key.clone(), (self.get_entry(&key)?, None));
+        order.push(key.clone());
+      }
+      let (current, record) = pending.get_mut(&key).unwrap();
+
+      match op {
+        KvBatchOp::Set(_, value) => {
+          let entry = next_entry(current.as_ref(), value, revision);
+          *record = Some(KvHistoryEntry {
+            entry: entry.clone(),
+            deleted: false,
+          });
+          *current = Some(entry);
+        }
+        KvBatchOp::Delete(_) => {
+          // 删除不存在的 key 不产生新版本
+          if current.is_none() {
+            continue;
+          }
+          let tombstone = KvEntry {
+            mod_revision: revision,
+            ..Default::default()
+          };
+          *record = Some(KvHistoryEntry {
+            entry: tombstone,
+            deleted: true,
+          });
+          *current = None;
+        }
+      }
+    }
+
+    for key in order {
+      let (current, record) = pending.remove(&key).unwrap();
+      let record = match record {
+        Some(record) => record,
+        None => continue,
+      };
+      batch.put_raw(
+        storage_key_kv_history(&key, revision),
+        bincode::serialize(&record)?,
+      );
+      self.push_history(batch, &key)?;
+      match current {
+        Some(entry) => batch.put_raw(storage_key_kv_data(&key), bincode::serialize(&entry)?),
+        None => batch.delete_raw(storage_key_kv_data(&key)),
+      }
+    }
+    return Ok(());
+  }
+
+  pub fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, CommonError> {
+    return Ok(self.get_entry(key)?.map(|entry| entry.value));
+  }
+
+  pub fn get_entry(&self, key: &[u8]) -> Result<Option<KvEntry>, CommonError> {
+    return self.read(&storage_key_kv_data(key));
+  }
+
+  // 读取 key 在指定版本时的值，即版本号小于等于 revision 的最新版本
+  pub fn get_at_revision(&self, key: &[u8], revision: u64) -> Result<KvRevisionRead, CommonError> {
+    let compact_revision = self.compact_revision()?;
+    if revision < compact_revision {
+      return Ok(KvRevisionRead::Compacted(compact_revision));
+    }
+
+    // 版本号小于等于 revision 的最新一条历史记录
+    let start_key = storage_key_kv_history(key, 0);
+    let end_key = match revision.checked_add(1) {
+      Some(next) => Some(storage_key_kv_history(key, next)),
+      None => prefix_end_key(&storage_key_kv_history_meta(key)),
+    };
+    let data_list = engine_range_raw_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      &start_key,
+      end_key.as_deref(),
+      1,
+      true,
+    )?;
+    if let Some((_, data)) = data_list.into_iter().next() {
+      let record = bincode::deserialize::<KvHistoryEntry>(&data)?;
+      if record.deleted {
+        return Ok(KvRevisionRead::Value(None));
+      }
+      return Ok(KvRevisionRead::Value(Some(record.entry)));
+    }
+
+    match self.get_history_meta(key)? {
+      // 没有历史记录的 key 只能读取当前版本
+      None => {
+        let entry = self
+          .get_entry(key)?
+          .filter(|entry| entry.mod_revision <= revision);
+        Ok(KvRevisionRead::Value(entry))
+      }
+      Some(meta) if meta.compacted_revision > 0 => {
+        Ok(KvRevisionRead::Compacted(meta.compacted_revision))
+      }
+      Some(_) => Ok(KvRevisionRead::Value(None)),
+    }
   }
 
-  pub fn delete(&self, key: String) -> Result<(), NezaMQError> {
-    return engine_delete_by_cluster(self.rocksdb_engine_handler.clone(), key);
+  // 已经压缩到的版本号，小于该版本的历史无法读取
+  pub fn compact_revision(&self) -> Result<u64, CommonError> {
+    return Ok(
+      self
+        .read::<u64>(&storage_key_kv_compact_revision())?
+        .unwrap_or(0),
+    );
   }
 
-  pub fn get(&self, key: String) -> Result<Option<String>, NezaMQError> {
-    match engine_get_by_cluster(self.rocksdb_engine_handler.clone(), key) {
-      Ok(Some(data)) => match serde_json::from_slice::<String>(&data.data) {
-        Ok(data) => {
-          return Ok(Some(data));
+  // 压缩 revision 之前的历史版本：每个 key 只保留版本号小于等于 revision 的最新一个版本，
+  // 如果该版本是删除且之后没有新版本，则删除整个历史记录。
+  // 历史记录分页读取，每个 key 需要删除的版本使用一次范围删除
+  pub fn compact(&self, batch: &mut StorageBatch, revision: u64) -> Result<(), CommonError> {
+    let prefix = storage_key_kv_history_prefix();
+    let end_key = prefix_end_key(&prefix);
+    let mut start_key = prefix.clone();
+    let mut group: Option<KvCompactGroup> = None;
+    loop {
+      let data_list = engine_range_raw_by_cluster(
+        self.rocksdb_engine_handler.clone(),
+        &start_key,
+        end_key.as_deref(),
+        KV_COMPACT_PAGE_SIZE,
+        false,
+      )?;
+      let has_more = data_list.len() == KV_COMPACT_PAGE_SIZE;
+      if let Some((last_key, _)) = data_list.last() {
+        start_key = last_key.clone();
+        start_key.push(0);
+      }
+
+      for (history_key, data) in data_list {
+        let (key_prefix, suffix) = match split_history_key(&prefix, &history_key) {
+          Some(parts) => parts,
+          None => continue,
+        };
+        if group.as_ref().map_or(true, |g| g.key_prefix != key_prefix) {
+          if let Some(finished) = group.take() {
+            finished.finish(batch)?;
+          }
+          group = Some(KvCompactGroup::new(key_prefix.to_vec()));
+        }
+        let current = group.as_mut().unwrap();
+
+        // 元数据记录排在该 key 的全部版本之前
+        if suffix.is_empty() {
+          current.meta = Some(bincode::deserialize::<KvHistoryMeta>(&data)?);
+          continue;
+        }
+        let record_revision = match <[u8; 8]>::try_from(suffix) {
+          Ok(bytes) => u64::from_be_bytes(bytes),
+          Err(_) => continue,
+        };
+        if record_revision > revision {
+          current.has_newer = true;
+          continue;
         }
+        let record = bincode::deserialize::<KvHistoryEntry>(&data)?;
+        if current.keep.is_some() {
+          current.older += 1;
+        }
+        current.keep = Some((record_revision, record.deleted));
+      }
+
+      if !has_more {
+        break;
+      }
+    }
+    if let Some(finished) = group {
+      finished.finish(batch)?;
+    }
+
+    batch.put_raw(
+      storage_key_kv_compact_revision(),
+      bincode::serialize(&revision)?,
+    );
+    return Ok(());
+  }
+
+  pub fn exists(&self, key: &[u8]) -> Result<bool, CommonError> {
+    return engine_exists_raw_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      &storage_key_kv_data(key),
+    );
+  }
+
+  // 列出以 prefix 开头的 key，按 key 升序分页返回
+  pub fn list_by_prefix(
+    &self,
+    prefix: Vec<u8>,
+    limit: usize,
+    page_token: Option<Vec<u8>>,
+  ) -> Result<KvPage, CommonError> {
+    let end_key = prefix_end_key(&prefix);
+    return self.range(prefix, end_key, limit, false, page_token);
+  }
+
+  // 扫描 [start_key, end_key) 区间，end_key 为 None 表示不限制上界
+  pub fn scan(
+    &self,
+    start_key: Vec<u8>,
+    end_key: Option<Vec<u8>>,
+    limit: usize,
+    reverse: bool,
+    page_token: Option<Vec<u8>>,
+  ) -> Result<KvPage, CommonError> {
+    return self.range(start_key, end_key, limit, reverse, page_token);
+  }
+
+  fn get_history_meta(&self, key: &[u8]) -> Result<Option<KvHistoryMeta>, CommonError> {
+    return self.read::<KvHistoryMeta>(&storage_key_kv_history_meta(key));
+  }
 
-        Err(e) => {
-          return Err(e.into());
+  // 记录 key 新增了一个版本，超出保留数量时淘汰最旧的版本
+  fn push_history(&self, batch: &mut StorageBatch, key: &[u8]) -> Result<(), CommonError> {
+    let mut meta = self.get_history_meta(key)?.unwrap_or_default();
+    meta.versions += 1;
+    if meta.versions > MAX_KV_HISTORY_VERSIONS {
+      // 批次中新写入的版本读取不到，读取到的最旧版本一定早于本次写入的版本
+      let meta_key = storage_key_kv_history_meta(key);
+      let oldest = engine_range_raw_by_cluster(
+        self.rocksdb_engine_handler.clone(),
+        &storage_key_kv_history(key, 0),
+        prefix_end_key(&meta_key).as_deref(),
+        1,
+        false,
+      )?;
+      if let Some((oldest_key, data)) = oldest.into_iter().next() {
+        let record = bincode::deserialize::<KvHistoryEntry>(&data)?;
+        meta.compacted_revision = meta.compacted_revision.max(record.entry.mod_revision);
+        meta.versions -= 1;
+        batch.delete_raw(oldest_key);
+      }
+    }
+    batch.put_raw(storage_key_kv_history_meta(key), bincode::serialize(&meta)?);
+    return Ok(());
+  }
+
+  fn read<T: DeserializeOwned>(&self, storage_key: &[u8]) -> Result<Option<T>, CommonError> {
+    match engine_get_raw_by_cluster(self.rocksdb_engine_handler.clone(), storage_key)? {
+      Some(data) => Ok(Some(bincode::deserialize::<T>(&data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // page_token 是上一页返回的最后一个 key，顺序扫描时从它之后继续，倒序扫描时从它之前继续
+  fn range(
+    &self,
+    mut start_key: Vec<u8>,
+    mut end_key: Option<Vec<u8>>,
+    limit: usize,
+    reverse: bool,
+    page_token: Option<Vec<u8>>,
+  ) -> Result<KvPage, CommonError> {
+    if let Some(token) = page_token {
+      if reverse {
+        if end_key.as_ref().map_or(true, |end| token < *end) {
+          end_key = Some(token);
+        }
+      } else {
+        let mut next_key = token;
+        next_key.push(0);
+        if next_key > start_key {
+          start_key = next_key;
         }
-      },
-      Ok(None) => {
-        return Ok(None);
       }
-      Err(e) => Err(e),
     }
+
+    let limit = match limit {
+      0 => DEFAULT_SCAN_LIMIT,
+      n => n.min(MAX_SCAN_LIMIT),
+    };
+
+    // 扫描范围限定在 KV 数据前缀内，不限制上界时以前缀的上界为准
+    let prefix = storage_key_kv_data_prefix();
+    let storage_start = storage_key_kv_data(&start_key);
+    let storage_end = match end_key {
+      Some(end) => Some(storage_key_kv_data(&end)),
+      None => prefix_end_key(&prefix),
+    };
+
+    // 多读取一条用于判断是否还有下一页
+    let mut data_list = engine_range_raw_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      &storage_start,
+      storage_end.as_deref(),
+      limit + 1,
+      reverse,
+    )?;
+
+    let has_more = data_list.len() > limit;
+    data_list.truncate(limit);
+
+    let mut kvs = Vec::with_capacity(data_list.len());
+    for (storage_key, data) in data_list {
+      let entry = bincode::deserialize::<KvEntry>(&data)?;
+      kvs.push((storage_key[prefix.len()..].to_vec(), entry.value));
+    }
+
+    let next_page_token = if has_more {
+      kvs.last().map(|(key, _)| key.clone())
+    } else {
+      None
+    };
+
+    return Ok(KvPage {
+      kvs,
+      next_page_token,
+    });
+  }
+}
+
+// 压缩过程中一个 key 的历史版本，`keep` 为需要保留的版本及其是否为删除，
+// `older` 为比保留版本更早、需要删除的版本数
+struct KvCompactGroup {
+  key_prefix: Vec<u8>,
+  meta: Option<KvHistoryMeta>,
+  keep: Option<(u64, bool)>,
+  older: u64,
+  has_newer: bool,
+}
+
+impl KvCompactGroup {
+  fn new(key_prefix: Vec<u8>) -> Self {
+    KvCompactGroup {
+      key_prefix,
+      meta: None,
+      keep: None,
+      older: 0,
+      has_newer: false,
+    }
+  }
+
+  fn finish(self, batch: &mut StorageBatch) -> Result<(), CommonError> {
+    let (keep_revision, deleted) = match self.keep {
+      Some(keep) => keep,
+      None => return Ok(()),
+    };
+
+    // 最后一个版本是删除时，连同元数据删除整个历史记录
+    if deleted && !self.has_newer {
+      let end_key = history_key_with_revision(&self.key_prefix, keep_revision + 1);
+      batch.delete_range(self.key_prefix, end_key);
+      return Ok(());
+    }
+    if self.older == 0 {
+      return Ok(());
+    }
+
+    let start_key = history_key_with_revision(&self.key_prefix, 0);
+    let end_key = history_key_with_revision(&self.key_prefix, keep_revision);
+    batch.delete_range(start_key, end_key);
+    let mut meta = self.meta.unwrap_or_default();
+    meta.versions = meta.versions.saturating_sub(self.older);
+    batch.put_raw(self.key_prefix, bincode::serialize(&meta)?);
+    return Ok(());
+  }
+}
+
+// 将历史记录的存储 key 拆分为该 key 的元数据 key 和版本号部分，元数据记录的版本号部分为空
+fn split_history_key<'a>(prefix: &[u8], history_key: &'a [u8]) -> Option<(&'a [u8], &'a [u8])> {
+  let pos = history_key[prefix.len()..]
+    .iter()
+    .position(|byte| *byte == b'/')?;
+  return Some(history_key.split_at(prefix.len() + pos + 1));
+}
+
+fn history_key_with_revision(key_prefix: &[u8], revision: u64) -> Vec<u8> {
+  let mut storage_key = key_prefix.to_vec();
+  storage_key.extend_from_slice(&revision.to_be_bytes());
+  return storage_key;
+}
+
+// 计算前缀扫描的上界：去掉末尾的 0xff 后将最后一个字节加一，前缀为空或全部为 0xff 时不限制上界
+fn prefix_end_key(prefix: &[u8]) -> Option<Vec<u8>> {
+  let mut end = prefix.to_vec();
+  while let Some(last) = end.pop() {
+    if last < 0xff {
+      end.push(last + 1);
+      return Some(end);
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{KvBatchOp, KvPage, KvRevisionRead, KvStorage, MAX_KV_HISTORY_VERSIONS};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn kv_storage_test() {
+    let engine = test_rocksdb_engine("kv_storage_test");
+    let kv_storage = KvStorage::new(engine.clone());
+
+    let key = b"/mqtt/user/cluster1/u1".to_vec();
+    test_commit(&engine, |batch| {
+      kv_storage.set(batch, key.clone(), b"nezamq".to_vec(), 1)
+    });
+    assert!(kv_storage.exists(&key).unwrap());
+    assert_eq!(kv_storage.get(&key).unwrap(), Some(b"nezamq".to_vec()));
+    assert_eq!(kv_storage.get_entry(&key).unwrap().unwrap().mod_revision, 1);
+
+    test_commit(&engine, |batch| kv_storage.delete(batch, key.clone(), 2));
+    assert!(!kv_storage.exists(&key).unwrap());
+    assert_eq!(kv_storage.get(&key).unwrap(), None);
+
+    // key 和 value 都不要求是合法的 UTF-8
+    let key = vec![0xff, 0x00, 0xfe];
+    test_commit(&engine, |batch| {
+      kv_storage.set(batch, key.clone(), vec![0x80, 0x81], 3)
+    });
+    assert_eq!(kv_storage.get(&key).unwrap(), Some(vec![0x80, 0x81]));
+  }
+
+  #[test]
+  fn kv_storage_range_test() {
+    let engine = test_rocksdb_engine("kv_storage_range_test");
+    let kv_storage = KvStorage::new(engine.clone());
+    for key in ["/a/1", "/a/2", "/a/3", "/a/4", "/b/1"] {
+      kv_storage
+        .set(key.as_bytes().to_vec(), key.as_bytes().to_vec(), 1)
+        .unwrap();
+    }
+
+    let keys = |page: &KvPage| {
+      page
+        .kvs
+        .iter()
+        .map(|(k, _)| String::from_utf8(k.clone()).unwrap())
+        .collect::<Vec<String>>()
+    };
+
+    let page = kv_storage.list_by_prefix(b"/a/".to_vec(), 3, None).unwrap();
+    assert_eq!(keys(&page), vec!["/a/1", "/a/2", "/a/3"]);
+    assert_eq!(page.next_page_token, Some(b"/a/3".to_vec()));
+
+    let page = kv_storage
+      .list_by_prefix(b"/a/".to_vec(), 3, page.next_page_token)
+      .unwrap();
+    assert_eq!(keys(&page), vec!["/a/4"]);
+    assert_eq!(page.next_page_token, None);
+
+    let page = kv_storage
+      .scan(b"/a/2".to_vec(), Some(b"/b/1".to_vec()), 2, true, None)
+      .unwrap();
+    assert_eq!(keys(&page), vec!["/a/4", "/a/3"]);
+
+    let page = kv_storage
+      .scan(
+        b"/a/2".to_vec(),
+        Some(b"/b/1".to_vec()),
+        2,
+        true,
+        page.next_page_token,
+      )
+      .unwrap();
+    assert_eq!(keys(&page), vec!["/a/2"]);
+    assert_eq!(page.next_page_token, None);
+
+    // 不限制上界时也不会扫描到历史版本等内部数据
+    let page = kv_storage
+      .scan(b"/a/4".to_vec(), None, 0, false, None)
+      .unwrap();
+    assert_eq!(keys(&page), vec!["/a/4", "/b/1"]);
+    let page = kv_storage.scan(Vec::new(), None, 0, true, None).unwrap();
+    assert_eq!(page.kvs.len(), 5);
+  }
+
+  #[test]
+  fn kv_storage_batch_test() {
+    let engine = test_rocksdb_engine("kv_storage_batch_test");
+    let kv_storage = KvStorage::new(engine.clone());
+    test_commit(&engine, |batch| {
+      kv_storage.set(batch, b"/a".to_vec(), b"1".to_vec(), 1)
+    });
+
+    test_commit(&engine, |batch| {
+      kv_storage.write(
+        batch,
+        vec![
+          KvBatchOp::Delete(b"/a".to_vec()),
+          KvBatchOp::Set(b"/b".to_vec(), b"2".to_vec()),
+        ],
+        2,
+      )
+    });
+    assert_eq!(kv_storage.get(b"/a").unwrap(), None);
+    let entry = kv_storage.get_entry(b"/b").unwrap().unwrap();
+    assert_eq!(entry.value, b"2".to_vec());
+    assert_eq!(entry.mod_revision, 2);
+  }
+
+  #[test]
+  fn kv_storage_revision_test() {
+    let engine = test_rocksdb_engine("kv_storage_revision_test");
+    let kv_storage = KvStorage::new(engine.clone());
+    let key = b"/a".to_vec();
+    test_commit(&engine, |batch| {
+      kv_storage.set(batch, key.clone(), b"v1".to_vec(), 1)
+    });
+    test_commit(&engine, |batch| {
+      kv_storage.set(batch, key.clone(), b"v2".to_vec(), 2)
+    });
+    test_commit(&engine, |batch| kv_storage.delete(batch, key.clone(), 3));
+    test_commit(&engine, |batch| {
+      kv_storage.set(batch, key.clone(), b"v4".to_vec(), 4)
+    });
+
+    let entry = kv_storage.get_entry(&key).unwrap().unwrap();
+    assert_eq!(
+      (entry.create_revision, entry.mod_revision, entry.version),
+      (4, 4, 1)
+    );
+
+    let read = |revision| match kv_storage.get_at_revision(&key, revision).unwrap() {
+      KvRevisionRead::Value(entry) => Ok(entry.map(|e| (e.value, e.version))),
+      KvRevisionRead::Compacted(rev) => Err(rev),
+    };
+    assert_eq!(read(2), Ok(Some((b"v2".to_vec(), 2))));
+    assert_eq!(read(3), Ok(None));
+
+    test_commit(&engine, |batch| kv_storage.compact(batch, 3));
+    assert_eq!(read(2), Err(3));
+    assert_eq!(read(3), Ok(None));
+    assert_eq!(read(4), Ok(Some((b"v4".to_vec(), 1))));
   }
 
-  pub fn exists(&self, key: String) -> Result<bool, NezaMQError> {
-    return engine_exists_by_cluster(self.rocksdb_engine_handler.clone(), key);
+  #[test]
+  fn kv_storage_history_test() {
+    let engine = test_rocksdb_engine("kv_storage_history_test");
+    let kv_storage = KvStorage::new(engine.clone());
+    let key = b"/a".to_vec();
+    let child = b"/a/b".to_vec();
+    let total = MAX_KV_HISTORY_VERSIONS + 5;
+    for revision in 1..=total {
+      test_commit(&engine, |batch| {
+        kv_storage.set(
+          batch,
+          key.clone(),
+          revision.to_be_bytes().to_vec(),
+          revision,
+        )
+      });
+    }
+    test_commit(&engine, |batch| {
+      kv_storage.set(batch, child.clone(), b"c".to_vec(), total + 1)
+    });
+
+    let read = |key: &[u8], revision| match kv_storage.get_at_revision(key, revision).unwrap() {
+      KvRevisionRead::Value(entry) => Ok(entry.map(|e| e.value)),
+      KvRevisionRead::Compacted(rev) => Err(rev),
+    };
+    // 超出保留数量的最旧版本被淘汰
+    assert_eq!(read(&key, 5), Err(5));
+    assert_eq!(read(&key, 6), Ok(Some(6u64.to_be_bytes().to_vec())));
+    assert_eq!(
+      read(&key, total + 1),
+      Ok(Some(total.to_be_bytes().to_vec()))
+    );
+
+    // 以 key 为前缀的其它 key 的历史版本互不影响
+    assert_eq!(read(&child, total), Ok(None));
+    assert_eq!(read(&child, total + 1), Ok(Some(b"c".to_vec())));
+
+    test_commit(&engine, |batch| {
+      kv_storage.delete(batch, child.clone(), total + 2)
+    });
+    test_commit(&engine, |batch| kv_storage.compact(batch, total + 2));
+    assert_eq!(read(&child, total + 2), Ok(None));
+    assert_eq!(
+      read(&key, total + 2),
+      Ok(Some(total.to_be_bytes().to_vec()))
+    );
   }
 }
@@ -0,0 +1,285 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use super::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_get_raw_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_lease, storage_key_lease_owner, storage_key_lease_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// 租约信息，`ttl` 单位为秒，`keys` 为绑定在该租约上的 key
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Lease {
+  pub id: i64,
+  pub ttl: i64,
+  pub keys: Vec<Vec<u8>>,
+}
+
+/// 一次日志应用中对租约和 key 绑定关系的修改。批次提交之前读取不到这些修改，
+/// 同一批次中多个 key 绑定或解绑同一个租约时以这里的状态为准
+#[derive(Debug, Default)]
+pub struct LeaseBindings {
+  leases: HashMap<i64, Lease>,
+  // None 表示 key 已经解除绑定
+  owners: HashMap<Vec<u8>, Option<i64>>,
+}
+
+impl LeaseBindings {
+  pub fn new() -> Self {
+    LeaseBindings::default()
+  }
+}
+
+pub struct LeaseStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl LeaseStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    LeaseStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, lease: &Lease) -> Result<(), CommonError> {
+    return batch.save(storage_key_lease(lease.id), lease);
+  }
+
+  pub fn get(&self, lease_id: i64) -> Result<Option<Lease>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_lease(lease_id),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Lease>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self) -> Result<Vec<Lease>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_lease_prefix(),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Lease>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  // 删除租约及其全部 key 的绑定关系，由调用方在同一个批次中删除绑定的 key
+  pub fn revoke(&self, batch: &mut StorageBatch, lease: &Lease) -> Result<(), CommonError> {
+    for key in &lease.keys {
+      batch.delete_raw(storage_key_lease_owner(key));
+    }
+    batch.delete(storage_key_lease(lease.id));
+    return Ok(());
+  }
+
+  // 查询 key 当前绑定的租约 ID
+  pub fn get_owner(&self, key: &[u8]) -> Result<Option<i64>, CommonError> {
+    match engine_get_raw_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      &storage_key_lease_owner(key),
+    )? {
+      Some(data) => Ok(Some(bincode::deserialize::<i64>(&data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // 将 key 绑定到租约上，如果 key 之前绑定了其它租约则先解除绑定。租约不存在时不做任何处理
+  pub fn attach_key(
+    &self,
+    bindings: &mut LeaseBindings,
+    key: &[u8],
+    lease_id: i64,
+  ) -> Result<(), CommonError> {
+    match self.pending_owner(bindings, key)? {
+      Some(owner) if owner == lease_id => return Ok(()),
+      Some(_) => self.detach_key(bindings, key)?,
+      None => {}
+    }
+
+    let lease = match self.pending_lease(bindings, lease_id)? {
+      Some(lease) => lease,
+      None => return Ok(()),
+    };
+    lease.keys.push(key.to_vec());
+    bindings.owners.insert(key.to_vec(), Some(lease_id));
+    return Ok(());
+  }
+
+  // 解除 key 与租约的绑定，key 没有绑定租约时不做任何处理
+  pub fn detach_key(&self, bindings: &mut LeaseBindings, key: &[u8]) -> Result<(), CommonError> {
+    let owner = match self.pending_owner(bindings, key)? {
+      Some(owner) => owner,
+      None => return Ok(()),
+    };
+
+    if let Some(lease) = self.pending_lease(bindings, owner)? {
+      lease.keys.retain(|k| k != key);
+    }
+    bindings.owners.insert(key.to_vec(), None);
+    return Ok(());
+  }
+
+  // 将绑定关系的修改追加到批次中，与 key 的写入一起提交
+  pub fn save_bindings(
+    &self,
+    batch: &mut StorageBatch,
+    bindings: LeaseBindings,
+  ) -> Result<(), CommonError> {
+    for lease in bindings.leases.values() {
+      self.save(batch, lease)?;
+    }
+    for (key, owner) in bindings.owners {
+      match owner {
+        Some(lease_id) => batch.put_raw(
+          storage_key_lease_owner(&key),
+          bincode::serialize(&lease_id)?,
+        ),
+        None => batch.delete_raw(storage_key_lease_owner(&key)),
+      }
+    }
+    return Ok(());
+  }
+
+  fn pending_owner(
+    &self,
+    bindings: &LeaseBindings,
+    key: &[u8],
+  ) -> Result<Option<i64>, CommonError> {
+    match bindings.owners.get(key) {
+      Some(owner) => Ok(*owner),
+      None => self.get_owner(key),
+    }
+  }
+
+  fn pending_lease<'a>(
+    &self,
+    bindings: &'a mut LeaseBindings,
+    lease_id: i64,
+  ) -> Result<Option<&'a mut Lease>, CommonError> {
+    if !bindings.leases.contains_key(&lease_id) {
+      match self.get(lease_id)? {
+        Some(lease) => {
+          bindings.leases.insert(lease_id, lease);
+        }
+        None => return Ok(None),
+      }
+    }
+    return Ok(bindings.leases.get_mut(&lease_id));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Lease, LeaseBindings, LeaseStorage};
+  use crate::storage::{
+    kv::KvStorage,
+    rocksdb::{test_commit, test_rocksdb_engine},
+  };
+
+  #[test]
+  fn lease_storage_attach_test() {
+    let engine = test_rocksdb_engine("lease_storage_attach_test");
+    let storage = LeaseStorage::new(engine.clone());
+    for id in [1, 2] {
+      let lease = Lease {
+        id,
+        ttl: 10,
+        keys: Vec::new(),
+      };
+      test_commit(&engine, |batch| storage.save(batch, &lease));
+    }
+
+    let key = b"/broker/1".to_vec();
+    test_commit(&engine, |batch| {
+      let mut bindings = LeaseBindings::new();
+      storage.attach_key(&mut bindings, &key, 1)?;
+      storage.save_bindings(batch, bindings)
+    });
+    assert_eq!(storage.get_owner(&key).unwrap(), Some(1));
+
+    // 重新绑定到另一个租约时会从原租约中移除
+    test_commit(&engine, |batch| {
+      let mut bindings = LeaseBindings::new();
+      storage.attach_key(&mut bindings, &key, 2)?;
+      storage.save_bindings(batch, bindings)
+    });
+    assert!(storage.get(1).unwrap().unwrap().keys.is_empty());
+    assert_eq!(storage.get(2).unwrap().unwrap().keys, vec![key.clone()]);
+
+    test_commit(&engine, |batch| {
+      let mut bindings = LeaseBindings::new();
+      storage.detach_key(&mut bindings, &key)?;
+      storage.save_bindings(batch, bindings)
+    });
+    assert_eq!(storage.get_owner(&key).unwrap(), None);
+    assert!(storage.get(2).unwrap().unwrap().keys.is_empty());
+    assert_eq!(storage.list().unwrap().len(), 2);
+  }
+
+  #[test]
+  fn lease_storage_bindings_test() {
+    let engine = test_rocksdb_engine("lease_storage_bindings_test");
+    let storage = LeaseStorage::new(engine.clone());
+    let lease = Lease {
+      id: 1,
+      ttl: 10,
+      keys: Vec::new(),
+    };
+    test_commit(&engine, |batch| storage.save(batch, &lease));
+
+    // 同一个批次中多个 key 绑定到同一个租约，提交之前的修改不会互相覆盖
+    test_commit(&engine, |batch| {
+      let mut bindings = LeaseBindings::new();
+      storage.attach_key(&mut bindings, b"/a", 1)?;
+      storage.attach_key(&mut bindings, b"/b", 1)?;
+      storage.attach_key(&mut bindings, b"/c", 1)?;
+      storage.detach_key(&mut bindings, b"/b")?;
+      storage.save_bindings(batch, bindings)
+    });
+    assert_eq!(
+      storage.get(1).unwrap().unwrap().keys,
+      vec![b"/a".to_vec(), b"/c".to_vec()]
+    );
+    assert_eq!(storage.get_owner(b"/b").unwrap(), None);
+    assert_eq!(storage.get_owner(b"/c").unwrap(), Some(1));
+  }
+
+  #[test]
+  fn lease_storage_revoke_test() {
+    let engine = test_rocksdb_engine("lease_storage_revoke_test");
+    let storage = LeaseStorage::new(engine.clone());
+    let kv_storage = KvStorage::new(engine.clone());
+    let lease = Lease {
+      id: 1,
+      ttl: 10,
+      keys: Vec::new(),
+    };
+    test_commit(&engine, |batch| storage.save(batch, &lease));
+
+    let key = vec![0xff, 0xfe];
+    test_commit(&engine, |batch| {
+      let mut bindings = LeaseBindings::new();
+      kv_storage.set(batch, key.clone(), b"v".to_vec(), 1)?;
+      storage.attach_key(&mut bindings, &key, 1)?;
+      storage.save_bindings(batch, bindings)
+    });
+
+    // 删除 key 和租约在同一个批次中写入
+    let lease = storage.get(1).unwrap().unwrap();
+    test_commit(&engine, |batch| {
+      kv_storage.delete(batch, key.clone(), 2)?;
+      storage.revoke(batch, &lease)
+    });
+    assert_eq!(kv_storage.get(&key).unwrap(), None);
+    assert_eq!(storage.get_owner(&key).unwrap(), None);
+    assert!(storage.get(1).unwrap().is_none());
+    assert!(storage.list().unwrap().is_empty());
+  }
+}
@@ -1,22 +1,29 @@
-use common_base::tools::now_second;
 use serde::{Deserialize, Serialize};
 
+pub mod amqp;
+pub mod batch;
+pub mod cluster;
+pub mod config;
 pub mod engine;
+pub mod id;
+pub mod journal;
 pub mod keys;
 pub mod kv;
+pub mod lease;
+pub mod mqtt;
 pub mod rocksdb;
+pub mod schema;
+pub mod snapshot;
 
+/// 数据在 Raft 日志应用时写入，不能依赖本地时钟，否则各副本写入的内容不一致。
+/// 需要的时间戳由写请求在提交前设置，旧数据中的 create_time 字段在反序列化时忽略
 #[derive(Serialize, Deserialize, Debug)]
 pub struct StorageDataWrap {
   pub data: Vec<u8>,
-  pub create_time: u64,
 }
 
 impl StorageDataWrap {
   pub fn new(data: Vec<u8>) -> Self {
-    Self {
-      data,
-      create_time: now_second(),
-    }
+    Self { data }
   }
 }
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_mqtt_acl, storage_key_mqtt_acl_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// ACL 规则，`resource_type`、`action` 和 `permission` 为对应枚举在 proto 中的名称
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Acl {
+  pub cluster_name: String,
+  pub resource_type: String,
+  pub resource_name: String,
+  pub topic_filter: String,
+  pub action: String,
+  pub permission: String,
+  pub create_time: u64,
+}
+
+pub struct MqttAclStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl MqttAclStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    MqttAclStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, acl: &Acl) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_mqtt_acl(
+        &acl.cluster_name,
+        &acl.resource_type,
+        &acl.resource_name,
+        &acl.action,
+        &acl.topic_filter,
+      ),
+      acl,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    resource_type: &str,
+    resource_name: &str,
+    action: &str,
+    topic_filter: &str,
+  ) -> Result<Option<Acl>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_acl(
+        cluster_name,
+        resource_type,
+        resource_name,
+        action,
+        topic_filter,
+      ),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Acl>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str) -> Result<Vec<Acl>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_acl_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Acl>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(&self, batch: &mut StorageBatch, acl: &Acl) -> Result<(), CommonError> {
+    batch.delete(storage_key_mqtt_acl(
+      &acl.cluster_name,
+      &acl.resource_type,
+      &acl.resource_name,
+      &acl.action,
+      &acl.topic_filter,
+    ));
+    return Ok(());
+  }
+}
+
+/// 检查主题过滤器是否合法：`+` 和 `#` 必须单独占据一个层级，`#` 只能出现在最后一个层级
+pub fn is_valid_topic_filter(topic_filter: &str) -> bool {
+  if topic_filter.is_empty() {
+    return false;
+  }
+  let levels: Vec<&str> = topic_filter.split('/').collect();
+  for (index, level) in levels.iter().enumerate() {
+    if level.contains('#') && (*level != "#" || index != levels.len() - 1) {
+      return false;
+    }
+    if level.contains('+') && *level != "+" {
+      return false;
+    }
+  }
+  return true;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Acl, MqttAclStorage, is_valid_topic_filter};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn mqtt_acl_storage_test() {
+    let engine = test_rocksdb_engine("mqtt_acl_storage_test");
+    let storage = MqttAclStorage::new(engine.clone());
+    let acl = Acl {
+      cluster_name: "mqtt".to_string(),
+      resource_type: "ACL_RESOURCE_TYPE_USER".to_string(),
+      resource_name: "guest".to_string(),
+      topic_filter: "sensor/#".to_string(),
+      action: "ACL_ACTION_PUBLISH".to_string(),
+      permission: "ACL_PERMISSION_DENY".to_string(),
+      create_time: 0,
+    };
+    test_commit(&engine, |batch| storage.save(batch, &acl));
+    test_commit(&engine, |batch| {
+      storage.save(
+        batch,
+        &Acl {
+          action: "ACL_ACTION_SUBSCRIBE".to_string(),
+          permission: "ACL_PERMISSION_ALLOW".to_string(),
+          ..acl.clone()
+        },
+      )
+    });
+    assert_eq!(storage.list("mqtt").unwrap().len(), 2);
+    assert!(storage.list("mqtt-2").unwrap().is_empty());
+
+    test_commit(&engine, |batch| storage.delete(batch, &acl));
+    assert!(
+      storage
+        .get(
+          "mqtt",
+          "ACL_RESOURCE_TYPE_USER",
+          "guest",
+          "ACL_ACTION_PUBLISH",
+          "sensor/#"
+        )
+        .unwrap()
+        .is_none()
+    );
+    assert_eq!(storage.list("mqtt").unwrap().len(), 1);
+  }
+
+  #[test]
+  fn topic_filter_test() {
+    for topic_filter in ["sensor/1", "sensor/+/temp", "sensor/#", "#", "+", "/sensor"] {
+      assert!(is_valid_topic_filter(topic_filter), "{}", topic_filter);
+    }
+    for topic_filter in [
+      "",
+      "sensor/#/temp",
+      "sensor/1#",
+      "sensor/+1",
+      "sensor+/temp",
+    ] {
+      assert!(!is_valid_topic_filter(topic_filter), "{}", topic_filter);
+    }
+  }
+}
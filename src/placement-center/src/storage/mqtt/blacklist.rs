@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{
+    storage_key_mqtt_blacklist, storage_key_mqtt_blacklist_all_prefix,
+    storage_key_mqtt_blacklist_prefix,
+  },
+  rocksdb::RocksDBEngine,
+};
+
+/// 黑名单，`blacklist_type` 为 `BlacklistType` 在 proto 中的名称，
+/// `end_time` 为失效的时间戳（秒），0 表示永久有效
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Blacklist {
+  pub cluster_name: String,
+  pub blacklist_type: String,
+  pub resource_name: String,
+  pub end_time: u64,
+  pub desc: String,
+  pub create_time: u64,
+}
+
+impl Blacklist {
+  pub fn is_expired(&self, now: u64) -> bool {
+    self.end_time > 0 && self.end_time <= now
+  }
+}
+
+pub struct MqttBlacklistStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl MqttBlacklistStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    MqttBlacklistStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, blacklist: &Blacklist) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_mqtt_blacklist(
+        &blacklist.cluster_name,
+        &blacklist.blacklist_type,
+        &blacklist.resource_name,
+      ),
+      blacklist,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    blacklist_type: &str,
+    resource_name: &str,
+  ) -> Result<Option<Blacklist>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_blacklist(cluster_name, blacklist_type, resource_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Blacklist>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str) -> Result<Vec<Blacklist>, CommonError> {
+    return self.list_by_prefix(storage_key_mqtt_blacklist_prefix(cluster_name));
+  }
+
+  // 列出所有集群中在 now 时已经失效的黑名单
+  pub fn list_expired(&self, now: u64) -> Result<Vec<Blacklist>, CommonError> {
+    let blacklists = self.list_by_prefix(storage_key_mqtt_blacklist_all_prefix())?;
+    return Ok(
+      blacklists
+        .into_iter()
+        .filter(|blacklist| blacklist.is_expired(now))
+        .collect(),
+    );
+  }
+
+  fn list_by_prefix(&self, prefix: String) -> Result<Vec<Blacklist>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(self.rocksdb_engine_handler.clone(), prefix)?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Blacklist>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    blacklist_type: &str,
+    resource_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_mqtt_blacklist(
+      cluster_name,
+      blacklist_type,
+      resource_name,
+    ));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Blacklist, MqttBlacklistStorage};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn mqtt_blacklist_storage_test() {
+    let engine = test_rocksdb_engine("mqtt_blacklist_storage_test");
+    let storage = MqttBlacklistStorage::new(engine.clone());
+    for (blacklist_type, resource_name, end_time) in [
+      ("BLACKLIST_TYPE_CLIENT_ID", "client-1", 0),
+      ("BLACKLIST_TYPE_IP", "10.0.0.1", 100),
+    ] {
+      let blacklist = Blacklist {
+        cluster_name: "mqtt".to_string(),
+        blacklist_type: blacklist_type.to_string(),
+        resource_name: resource_name.to_string(),
+        end_time,
+        ..Default::default()
+      };
+      test_commit(&engine, |batch| storage.save(batch, &blacklist));
+    }
+
+    let blacklists = storage.list("mqtt").unwrap();
+    assert_eq!(blacklists.len(), 2);
+    assert_eq!(
+      blacklists
+        .iter()
+        .filter(|blacklist| !blacklist.is_expired(100))
+        .count(),
+      1
+    );
+
+    let expired = storage.list_expired(100).unwrap();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].resource_name, "10.0.0.1");
+
+    test_commit(&engine, |batch| {
+      storage.delete(batch, "mqtt", "BLACKLIST_TYPE_IP", "10.0.0.1")
+    });
+    assert!(
+      storage
+        .get("mqtt", "BLACKLIST_TYPE_IP", "10.0.0.1")
+        .unwrap()
+        .is_none()
+    );
+    assert_eq!(storage.list("mqtt").unwrap().len(), 1);
+  }
+
+  #[test]
+  fn mqtt_blacklist_network_segment_test() {
+    let engine = test_rocksdb_engine("mqtt_blacklist_network_segment_test");
+    let storage = MqttBlacklistStorage::new(engine.clone());
+    // 网段中的 / 不会与其它对象的 key 冲突
+    for resource_name in ["10.0.0.0/8", "10.0.0.0", "10.0.0.0%2F8"] {
+      let blacklist = Blacklist {
+        cluster_name: "mqtt".to_string(),
+        blacklist_type: "BLACKLIST_TYPE_IP".to_string(),
+        resource_name: resource_name.to_string(),
+        ..Default::default()
+      };
+      test_commit(&engine, |batch| storage.save(batch, &blacklist));
+    }
+    assert_eq!(storage.list("mqtt").unwrap().len(), 3);
+
+    test_commit(&engine, |batch| {
+      storage.delete(batch, "mqtt", "BLACKLIST_TYPE_IP", "10.0.0.0/8")
+    });
+    let blacklists = storage.list("mqtt").unwrap();
+    assert_eq!(blacklists.len(), 2);
+    assert!(
+      blacklists
+        .iter()
+        .all(|blacklist| blacklist.resource_name != "10.0.0.0/8")
+    );
+  }
+}
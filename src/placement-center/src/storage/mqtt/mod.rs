@@ -0,0 +1,5 @@
+pub mod acl;
+pub mod blacklist;
+pub mod session;
+pub mod topic;
+pub mod user;
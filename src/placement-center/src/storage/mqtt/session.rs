@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_mqtt_session, storage_key_mqtt_session_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// 遗嘱消息，`delay_interval` 为延迟发送的间隔（秒）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LastWill {
+  pub topic_name: String,
+  pub payload: Vec<u8>,
+  pub qos: u32,
+  pub retain: bool,
+  pub delay_interval: u64,
+}
+
+/// 会话中的订阅以及 MQTT 5 的订阅选项
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Subscription {
+  pub topic_filter: String,
+  pub qos: u32,
+  pub no_local: bool,
+  pub retain_as_published: bool,
+  pub retain_handling: u32,
+}
+
+/// clean-start=false 的客户端的持久会话，`broker_node_id` 为当前持有会话的 Broker 节点
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+  pub cluster_name: String,
+  pub client_id: String,
+  pub session_expiry_interval: u64,
+  pub broker_node_id: u64,
+  pub last_will: Option<LastWill>,
+  pub subscriptions: Vec<Subscription>,
+  pub create_time: u64,
+  pub update_time: u64,
+}
+
+pub struct MqttSessionStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl MqttSessionStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    MqttSessionStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, session: &Session) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_mqtt_session(&session.cluster_name, &session.client_id),
+      session,
+    );
+  }
+
+  pub fn get(&self, cluster_name: &str, client_id: &str) -> Result<Option<Session>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_session(cluster_name, client_id),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Session>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str) -> Result<Vec<Session>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_session_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Session>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    client_id: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_mqtt_session(cluster_name, client_id));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{LastWill, MqttSessionStorage, Session, Subscription};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn mqtt_session_storage_test() {
+    let engine = test_rocksdb_engine("mqtt_session_storage_test");
+    let storage = MqttSessionStorage::new(engine.clone());
+    let session = Session {
+      cluster_name: "mqtt".to_string(),
+      client_id: "client-1".to_string(),
+      session_expiry_interval: 3600,
+      broker_node_id: 1,
+      last_will: Some(LastWill {
+        topic_name: "client/1/status".to_string(),
+        payload: b"offline".to_vec(),
+        qos: 1,
+        ..Default::default()
+      }),
+      subscriptions: vec![Subscription {
+        topic_filter: "sensor/+/temp".to_string(),
+        qos: 2,
+        no_local: true,
+        ..Default::default()
+      }],
+      ..Default::default()
+    };
+    test_commit(&engine, |batch| storage.save(batch, &session));
+    assert_eq!(
+      storage.get("mqtt", "client-1").unwrap(),
+      Some(session.clone())
+    );
+
+    // 客户端重连到其它 Broker 后覆盖会话
+    test_commit(&engine, |batch| {
+      storage.save(
+        batch,
+        &Session {
+          broker_node_id: 2,
+          ..session
+        },
+      )
+    });
+    let sessions = storage.list("mqtt").unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].broker_node_id, 2);
+    assert!(storage.list("mqtt-2").unwrap().is_empty());
+
+    test_commit(&engine, |batch| storage.delete(batch, "mqtt", "client-1"));
+    assert!(storage.get("mqtt", "client-1").unwrap().is_none());
+  }
+}
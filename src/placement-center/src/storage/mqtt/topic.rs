@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{
+    storage_key_mqtt_topic, storage_key_mqtt_topic_all_prefix, storage_key_mqtt_topic_prefix,
+  },
+  rocksdb::RocksDBEngine,
+};
+
+/// 主题的保留消息，`expire_at` 为过期的时间戳（秒），0 表示永不过期
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RetainMessage {
+  pub payload: Vec<u8>,
+  pub qos: u32,
+  pub expire_at: u64,
+  pub create_time: u64,
+}
+
+impl RetainMessage {
+  pub fn is_expired(&self, now: u64) -> bool {
+    self.expire_at > 0 && self.expire_at <= now
+  }
+}
+
+/// MQTT 主题，过期的保留消息在读取时过滤，并由 Leader 通过 Raft 日志定期清除
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Topic {
+  pub cluster_name: String,
+  pub topic_name: String,
+  pub retain_message: Option<RetainMessage>,
+  pub create_time: u64,
+}
+
+pub struct MqttTopicStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl MqttTopicStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    MqttTopicStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, topic: &Topic) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_mqtt_topic(&topic.cluster_name, &topic.topic_name),
+      topic,
+    );
+  }
+
+  pub fn get(&self, cluster_name: &str, topic_name: &str) -> Result<Option<Topic>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_topic(cluster_name, topic_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<Topic>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str) -> Result<Vec<Topic>, CommonError> {
+    return self.list_by_prefix(storage_key_mqtt_topic_prefix(cluster_name));
+  }
+
+  // 列出所有集群中在 now 时保留消息已经过期的主题
+  pub fn list_expired_retain(&self, now: u64) -> Result<Vec<Topic>, CommonError> {
+    let topics = self.list_by_prefix(storage_key_mqtt_topic_all_prefix())?;
+    return Ok(
+      topics
+        .into_iter()
+        .filter(|topic| {
+          topic
+            .retain_message
+            .as_ref()
+            .is_some_and(|message| message.is_expired(now))
+        })
+        .collect(),
+    );
+  }
+
+  fn list_by_prefix(&self, prefix: String) -> Result<Vec<Topic>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(self.rocksdb_engine_handler.clone(), prefix)?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<Topic>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    topic_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_mqtt_topic(cluster_name, topic_name));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{MqttTopicStorage, RetainMessage, Topic};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn mqtt_topic_storage_test() {
+    let engine = test_rocksdb_engine("mqtt_topic_storage_test");
+    let storage = MqttTopicStorage::new(engine.clone());
+    for topic_name in ["sensor/1", "sensor/1/temp"] {
+      test_commit(&engine, |batch| {
+        storage.save(
+          batch,
+          &Topic {
+            cluster_name: "mqtt".to_string(),
+            topic_name: topic_name.to_string(),
+            ..Default::default()
+          },
+        )
+      });
+    }
+    assert_eq!(storage.list("mqtt").unwrap().len(), 2);
+    assert!(storage.list("mqtt-2").unwrap().is_empty());
+
+    let mut topic = storage.get("mqtt", "sensor/1").unwrap().unwrap();
+    topic.retain_message = Some(RetainMessage {
+      payload: b"21.5".to_vec(),
+      qos: 1,
+      expire_at: 100,
+      create_time: 40,
+    });
+    test_commit(&engine, |batch| storage.save(batch, &topic));
+    let retain_message = storage
+      .get("mqtt", "sensor/1")
+      .unwrap()
+      .unwrap()
+      .retain_message
+      .unwrap();
+    assert!(!retain_message.is_expired(99));
+    assert!(retain_message.is_expired(100));
+    assert!(storage.list_expired_retain(99).unwrap().is_empty());
+    let expired = storage.list_expired_retain(100).unwrap();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].topic_name, "sensor/1");
+
+    test_commit(&engine, |batch| storage.delete(batch, "mqtt", "sensor/1"));
+    assert!(storage.get("mqtt", "sensor/1").unwrap().is_none());
+    assert_eq!(storage.list("mqtt").unwrap().len(), 1);
+  }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_mqtt_user, storage_key_mqtt_user_cluster_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// MQTT 用户，`password_hash` 为 Broker 计算好的密码哈希
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct User {
+  pub cluster_name: String,
+  pub user_name: String,
+  pub password_hash: String,
+  pub is_superuser: bool,
+  pub create_time: u64,
+}
+
+pub struct MqttUserStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl MqttUserStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    MqttUserStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, user: &User) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_mqtt_user(&user.cluster_name, &user.user_name),
+      user,
+    );
+  }
+
+  pub fn get(&self, cluster_name: &str, user_name: &str) -> Result<Option<User>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_user(cluster_name, user_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<User>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list(&self, cluster_name: &str) -> Result<Vec<User>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_mqtt_user_cluster_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<User>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    user_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_mqtt_user(cluster_name, user_name));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{MqttUserStorage, User};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn mqtt_user_storage_test() {
+    let engine = test_rocksdb_engine("mqtt_user_storage_test");
+    let storage = MqttUserStorage::new(engine.clone());
+    for cluster_name in ["mqtt", "mqtt-2"] {
+      for user_name in ["admin", "guest"] {
+        test_commit(&engine, |batch| {
+          storage.save(
+            batch,
+            &User {
+              cluster_name: cluster_name.to_string(),
+              user_name: user_name.to_string(),
+              password_hash: "hash".to_string(),
+              is_superuser: user_name == "admin",
+              create_time: 0,
+            },
+          )
+        });
+      }
+    }
+
+    let users = storage.list("mqtt").unwrap();
+    assert_eq!(users.len(), 2);
+    assert!(users.iter().all(|user| user.cluster_name == "mqtt"));
+    assert!(storage.get("mqtt", "admin").unwrap().unwrap().is_superuser);
+
+    test_commit(&engine, |batch| storage.delete(batch, "mqtt", "admin"));
+    assert!(storage.get("mqtt", "admin").unwrap().is_none());
+    assert_eq!(storage.list("mqtt").unwrap().len(), 1);
+    assert_eq!(storage.list("mqtt-2").unwrap().len(), 2);
+  }
+}
@@ -1,38 +1,74 @@
-use std::{collections::HashMap, path::Path};
+use std::{
+  collections::HashMap,
+  fs,
+  path::Path,
+  sync::{RwLock, RwLockReadGuard},
+};
 
-use common_base::{config::placement_center::PlacementCenterConfig, errors::NezaMQError};
-use rocksdb::{ColumnFamily, DB, DBCompactionStyle, Options, SliceTransform};
+use common_base::{
+  config::placement_center::{PlacementCenterConfig, SnapshotMode},
+  error::CommonError,
+};
+use rocksdb::{
+  ColumnFamily, ColumnFamilyDescriptor, DB, DBCompactionStyle, Options, ReadOptions,
+  SliceTransform, WriteBatch, checkpoint::Checkpoint,
+};
 use serde::{Serialize, de::DeserializeOwned};
 
+use super::batch::{StorageBatch, StorageBatchOp};
+
 pub const DB_COLUMN_FAMILY_CLUSTER: &str = "cluster";
 
+// 恢复快照时，当前数据目录会先重命名为该后缀的目录，新数据目录打开成功后再删除
+const REPLACED_DB_SUFFIX: &str = "_replaced";
+
+// DB 放在读写锁中，读写数据时持有读锁，使用快照替换整个数据目录时持有写锁
+#[derive(Debug)]
 pub struct RocksDBEngine {
-  pub db: DB,
+  db: RwLock<DB>,
+  db_path: String,
+  config: PlacementCenterConfig,
 }
 
 impl RocksDBEngine {
   // 创建RocksDB 实例
   pub fn new(config: &PlacementCenterConfig) -> Self {
+    let db_path = format!("{}/{}", config.rocksdb.data_path, "_storage_rocksdb");
+    // 替换数据目录的过程中进程退出时，使用被替换的目录恢复，之后 Raft 会重新安装快照
+    let replaced_path = format!("{}{}", db_path, REPLACED_DB_SUFFIX);
+    if !Path::new(&db_path).exists() && Path::new(&replaced_path).exists() {
+      fs::rename(&replaced_path, &db_path).unwrap();
+    }
+    let instance = Self::open_db(config, &db_path).unwrap();
+
+    Self {
+      db: RwLock::new(instance),
+      db_path,
+      config: config.clone(),
+    }
+  }
+
+  // 打开 RocksDB 实例：首次启动时需要同时创建业务数据所在的列蔟
+  fn open_db(config: &PlacementCenterConfig, db_path: &str) -> Result<DB, CommonError> {
     // 1. 设置 RocksDB 配置参数
-    let opts = Self::set_db_opts();
-    let db_path = format!("{}/{}", config.data_path, "_storage_rocksdb");
-    // 2. 初始化 RocksDB 实例：判断RocksDB是否初始化成功，否则进行初始化。
-    if !Path::new(&db_path).exists() {
-      DB::open(&opts, db_path.clone()).unwrap();
+    let opts = Self::set_db_opts(config);
+    // 2. 初始化 RocksDB 实例
+    if !Path::new(db_path).exists() {
+      let cf = ColumnFamilyDescriptor::new(DB_COLUMN_FAMILY_CLUSTER, Self::set_db_opts(config));
+      let db = DB::open_cf_descriptors(&opts, db_path, vec![cf])?;
+      drop(db);
     }
     // 3. 初始化 RocksDB 中的列蔟
-    let cf_list = DB::list_cf(&opts, &db_path).unwrap();
-    let instance = DB::open_cf(&opts, db_path.clone(), &cf_list).unwrap();
-
-    Self { db: instance }
+    let cf_list = DB::list_cf(&opts, db_path)?;
+    return Ok(DB::open_cf(&opts, db_path, &cf_list)?);
   }
 
   // RocksDB 配置设置
-  fn set_db_opts() -> Options {
+  fn set_db_opts(config: &PlacementCenterConfig) -> Options {
     let mut opts = Options::default();
     opts.create_if_missing(true);
     opts.create_missing_column_families(true);
-    opts.set_max_open_files(1000);
+    opts.set_max_open_files(config.rocksdb.max_open_files.unwrap_or(1000));
     opts.set_use_fsync(false);
     opts.set_bytes_per_sync(8388608);
     opts.optimize_for_point_lookup(1024);
@@ -58,13 +94,14 @@ impl RocksDBEngine {
   // 写数据（Write）
   pub fn write<T: Serialize + std::fmt::Debug>(
     &self,
-    cf: &ColumnFamily,
+    cf_name: &str,
     key: &str,
     value: &T,
   ) -> Result<(), String> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name).map_err(|err| err.to_string())?;
     match serde_json::to_string(&value) {
-      Ok(serialized) => self
-        .db
+      Ok(serialized) => db
         .put_cf(cf, key, serialized.into_bytes())
         .map_err(|err| format!("Failed to put to ColumnFamily:{:?}", err)),
       Err(err) => Err(format!(
@@ -75,12 +112,10 @@ impl RocksDBEngine {
   }
 
   // 根据 key 读取数据
-  pub fn read<T: DeserializeOwned>(
-    &self,
-    cf: &ColumnFamily,
-    key: &str,
-  ) -> Result<Option<T>, String> {
-    match self.db.get_cf(cf, key) {
+  pub fn read<T: DeserializeOwned>(&self, cf_name: &str, key: &str) -> Result<Option<T>, String> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name).map_err(|err| err.to_string())?;
+    match db.get_cf(cf, key) {
       Ok(opt) => match opt {
         Some(found) => match String::from_utf8(found) {
           Ok(s) => match serde_json::from_str::<T>(&s) {
@@ -95,20 +130,46 @@ impl RocksDBEngine {
     }
   }
 
+  // 根据 key 读取原始数据，不做反序列化
+  pub fn read_raw<K: AsRef<[u8]>>(
+    &self,
+    cf_name: &str,
+    key: K,
+  ) -> Result<Option<Vec<u8>>, CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+    return Ok(db.get_cf(cf, key)?);
+  }
+
   // 根据 key 删除数据
-  pub fn delete(&self, cf: &ColumnFamily, key: &str) -> Result<(), NezaMQError> {
-    return Ok(self.db.delete_cf(cf, key)?);
+  pub fn delete<K: AsRef<[u8]>>(&self, cf_name: &str, key: K) -> Result<(), CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+    return Ok(db.delete_cf(cf, key)?);
   }
 
   // 根据 key 是否存在
-  pub fn exist(&self, cf: &ColumnFamily, key: &str) -> bool {
-    self.db.key_may_exist_cf(cf, key)
+  // key_may_exist_cf 存在误判，删除后的 key 也可能返回 true，因此这里直接读取判断
+  pub fn exist<K: AsRef<[u8]>>(&self, cf_name: &str, key: K) -> Result<bool, CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+    match db.get_pinned_cf(cf, key) {
+      Ok(Some(_)) => Ok(true),
+      _ => Ok(false),
+    }
   }
 
   // 根据 key 前缀搜索
-  pub fn read_prefix(&self, cf: &ColumnFamily, search_key: &str) -> Vec<HashMap<String, Vec<u8>>> {
+  pub fn read_prefix(
+    &self,
+    cf_name: &str,
+    search_key: &str,
+  ) -> Result<Vec<HashMap<String, Vec<u8>>>, CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+
     // 获取 ColumnFamily 的迭代器
-    let mut iter = self.db.raw_iterator_cf(cf);
+    let mut iter = db.raw_iterator_cf(cf);
 
     // 搜索到第一个匹配这个前缀的 Key
     iter.seek(search_key);
@@ -127,25 +188,264 @@ impl RocksDBEngine {
         break;
       }
 
-      let result_key = match String::from_utf8(key.unwrap().to_vec()) {
-        Ok(s) => s,
-        Err(_) => continue,
-      };
-
       // 如果 key 不匹配前缀，说明已经获取到所有这个前缀的 key,则退出循环。
-      if !result_key.starts_with(search_key) {
+      // 按字节比较前缀，KV 数据和租约的 key 中可能包含非 UTF-8 的二进制数据
+      let key = key.unwrap();
+      if !key.starts_with(search_key.as_bytes()) {
         break;
       }
 
+      let result_key = match String::from_utf8(key.to_vec()) {
+        Ok(s) => s,
+        Err(_) => {
+          iter.next();
+          continue;
+        }
+      };
+
       raw.insert(result_key, value.unwrap().to_vec());
       result.push(raw);
       iter.next();
     }
 
+    return Ok(result);
+  }
+
+  // 按 key 范围扫描 [start_key, end_key)，end_key 为 None 表示不限制上界，最多返回 limit 条
+  // 列蔟配置了固定长度的前缀提取器，跨前缀扫描时需要开启 total_order_seek 才能保证顺序正确
+  pub fn read_range(
+    &self,
+    cf_name: &str,
+    start_key: &[u8],
+    end_key: Option<&[u8]>,
+    limit: usize,
+    reverse: bool,
+  ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+    let mut read_opts = ReadOptions::default();
+    read_opts.set_total_order_seek(true);
+    let mut iter = db.raw_iterator_cf_opt(cf, read_opts);
+
+    if reverse {
+      match end_key {
+        Some(end) => {
+          // 定位到最后一个小于等于 end_key 的 key，end_key 本身不包含在结果中
+          iter.seek_for_prev(end);
+          if iter.valid() && iter.key() == Some(end) {
+            iter.prev();
+          }
+        }
+        None => iter.seek_to_last(),
+      }
+    } else {
+      iter.seek(start_key);
+    }
+
+    let mut result = Vec::new();
+    while iter.valid() && result.len() < limit {
+      let (key, value) = match (iter.key(), iter.value()) {
+        (Some(key), Some(value)) => (key, value),
+        _ => break,
+      };
+
+      if reverse {
+        if key < start_key {
+          break;
+        }
+      } else if let Some(end) = end_key {
+        if key >= end {
+          break;
+        }
+      }
+
+      result.push((key.to_vec(), value.to_vec()));
+      if reverse {
+        iter.prev();
+      } else {
+        iter.next();
+      }
+    }
+    return Ok(result);
+  }
+
+  // 读取列蔟中的全部数据，用于构建快照
+  pub fn read_all_by_cf(&self, cf_name: &str) -> Result<Vec<(Vec<u8>, Vec<u8>)>, CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+    return Ok(Self::read_all(&db, cf));
+  }
+
+  // 使用快照数据覆盖列蔟中的全部数据，删除和写入在同一个 WriteBatch 中原子完成
+  pub fn reset_by_cf(
+    &self,
+    cf_name: &str,
+    records: Vec<(Vec<u8>, Vec<u8>)>,
+  ) -> Result<(), CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+    let mut batch = WriteBatch::default();
+    for (key, _) in Self::read_all(&db, cf) {
+      batch.delete_cf(cf, key);
+    }
+    for (key, value) in records {
+      batch.put_cf(cf, key, value);
+    }
+    db.write(batch)?;
+    return Ok(());
+  }
+
+  // 在同一个 WriteBatch 中原子地写入和删除多个 key，value 为原始数据，None 表示删除
+  pub fn write_batch(
+    &self,
+    cf_name: &str,
+    records: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+  ) -> Result<(), CommonError> {
+    let mut batch = StorageBatch::new();
+    batch.extend_raw(records);
+    return self.write_ops(cf_name, batch.into_ops());
+  }
+
+  // 按顺序在同一个 WriteBatch 中执行全部操作，要么全部写入，要么全部不写入
+  pub fn write_ops(&self, cf_name: &str, ops: Vec<StorageBatchOp>) -> Result<(), CommonError> {
+    let db = self.db();
+    let cf = Self::cf_handle(&db, cf_name)?;
+    let mut batch = WriteBatch::default();
+    for op in ops {
+      match op {
+        StorageBatchOp::Put(key, value) => batch.put_cf(cf, key, value),
+        StorageBatchOp::Delete(key) => batch.delete_cf(cf, key),
+        StorageBatchOp::DeleteRange(start_key, end_key) => {
+          batch.delete_range_cf(cf, start_key, end_key)
+        }
+      }
+    }
+    db.write(batch)?;
+    return Ok(());
+  }
+
+  // 创建 RocksDB checkpoint，SST 文件通过硬链接共享，不需要把数据读入内存
+  // checkpoint_dir 不能已经存在
+  pub fn create_checkpoint(&self, checkpoint_dir: &Path) -> Result<(), CommonError> {
+    let db = self.db();
+    let checkpoint = Checkpoint::new(&db)?;
+    checkpoint.create_checkpoint(checkpoint_dir)?;
+    return Ok(());
+  }
+
+  // 使用 checkpoint 目录替换当前的数据目录，替换期间持有写锁，读写请求会等待替换完成。
+  // 当前 DB 打开期间先将数据目录重命名，新的目录打开成功后再关闭并删除旧的目录
+  pub fn restore_from_checkpoint(&self, checkpoint_dir: &Path) -> Result<(), CommonError> {
+    let mut db = self.db.write().unwrap();
+    let replaced_path = format!("{}{}", self.db_path, REPLACED_DB_SUFFIX);
+    if Path::new(&replaced_path).exists() {
+      fs::remove_dir_all(&replaced_path)?;
+    }
+
+    fs::rename(&self.db_path, &replaced_path)?;
+    if let Err(e) = fs::rename(checkpoint_dir, &self.db_path) {
+      fs::rename(&replaced_path, &self.db_path)?;
+      return Err(e.into());
+    }
+
+    let instance = match Self::open_db(&self.config, &self.db_path) {
+      Ok(instance) => instance,
+      Err(e) => {
+        fs::remove_dir_all(&self.db_path)?;
+        fs::rename(&replaced_path, &self.db_path)?;
+        return Err(e);
+      }
+    };
+
+    let replaced = std::mem::replace(&mut *db, instance);
+    drop(replaced);
+    fs::remove_dir_all(&replaced_path)?;
+    return Ok(());
+  }
+
+  // 配置的数据目录，RocksDB 数据目录以及 checkpoint 等临时目录都位于其中
+  pub fn data_path(&self) -> &str {
+    return &self.config.rocksdb.data_path;
+  }
+
+  pub fn snapshot_mode(&self) -> SnapshotMode {
+    return self.config.rocksdb.snapshot_mode;
+  }
+
+  fn db(&self) -> RwLockReadGuard<'_, DB> {
+    return self.db.read().unwrap();
+  }
+
+  fn cf_handle<'a>(db: &'a DB, cf_name: &str) -> Result<&'a ColumnFamily, CommonError> {
+    match db.cf_handle(cf_name) {
+      Some(cf) => Ok(cf),
+      None => Err(CommonError::CommonError(format!(
+        "Column family {} does not exist",
+        cf_name
+      ))),
+    }
+  }
+
+  fn read_all(db: &DB, cf: &ColumnFamily) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut iter = db.raw_iterator_cf(cf);
+    iter.seek_to_first();
+
+    let mut result = Vec::new();
+    while iter.valid() {
+      if let (Some(key), Some(value)) = (iter.key(), iter.value()) {
+        result.push((key.to_vec(), value.to_vec()));
+      }
+      iter.next();
+    }
     return result;
   }
+}
+
+#[cfg(test)]
+pub(crate) fn test_rocksdb_engine(name: &str) -> std::sync::Arc<RocksDBEngine> {
+  let mut config = PlacementCenterConfig::default();
+  config.rocksdb.data_path = format!("{}/nezamq-test/{}", std::env::temp_dir().display(), name);
+  let _ = std::fs::remove_dir_all(&config.rocksdb.data_path);
+  std::sync::Arc::new(RocksDBEngine::new(&config))
+}
+
+// 测试中直接提交一个批次的写操作
+#[cfg(test)]
+pub(crate) fn test_commit<F>(engine: &std::sync::Arc<RocksDBEngine>, write: F)
+where
+  F: FnOnce(&mut StorageBatch) -> Result<(), CommonError>,
+{
+  let mut batch = StorageBatch::new();
+  write(&mut batch).unwrap();
+  engine
+    .write_ops(DB_COLUMN_FAMILY_CLUSTER, batch.into_ops())
+    .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{DB_COLUMN_FAMILY_CLUSTER, test_rocksdb_engine};
+
+  #[test]
+  fn read_prefix_skip_binary_key_test() {
+    let engine = test_rocksdb_engine("read_prefix_skip_binary_key_test");
+    engine
+      .write_batch(
+        DB_COLUMN_FAMILY_CLUSTER,
+        vec![
+          (b"/lease/info/1".to_vec(), Some(b"1".to_vec())),
+          (b"/lease/owner/\xff\xfe".to_vec(), Some(b"2".to_vec())),
+          (b"/lease/owner/key".to_vec(), Some(b"3".to_vec())),
+          (b"/lease/zzz".to_vec(), Some(b"4".to_vec())),
+        ],
+      )
+      .unwrap();
 
-  pub fn cf_cluster(&self) -> &ColumnFamily {
-    return self.db.cf_handle(&DB_COLUMN_FAMILY_CLUSTER).unwrap();
+    // 非 UTF-8 的 key 会被跳过，不会导致扫描卡住
+    let result = engine
+      .read_prefix(DB_COLUMN_FAMILY_CLUSTER, "/lease/owner/")
+      .unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].contains_key("/lease/owner/key"));
   }
 }
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{storage_key_schema_binding, storage_key_schema_binding_prefix},
+  rocksdb::RocksDBEngine,
+};
+
+/// MQTT 主题或 Journal 分片绑定的 subject，`resource_type` 为 `SchemaResourceType` 在 proto 中的名称
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaBinding {
+  pub cluster_name: String,
+  pub resource_type: String,
+  pub resource_name: String,
+  pub subject: String,
+  pub create_time: u64,
+}
+
+pub struct SchemaBindingStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl SchemaBindingStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    SchemaBindingStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save(&self, batch: &mut StorageBatch, binding: &SchemaBinding) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_schema_binding(
+        &binding.cluster_name,
+        &binding.resource_type,
+        &binding.resource_name,
+      ),
+      binding,
+    );
+  }
+
+  pub fn get(
+    &self,
+    cluster_name: &str,
+    resource_type: &str,
+    resource_name: &str,
+  ) -> Result<Option<SchemaBinding>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_schema_binding(cluster_name, resource_type, resource_name),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<SchemaBinding>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // subject 为空时返回集群中的所有绑定
+  pub fn list(&self, cluster_name: &str, subject: &str) -> Result<Vec<SchemaBinding>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_schema_binding_prefix(cluster_name),
+    )?;
+    let mut results = Vec::new();
+    for data in data_list {
+      let binding = serde_json::from_slice::<SchemaBinding>(&data.data)?;
+      if subject.is_empty() || binding.subject == subject {
+        results.push(binding);
+      }
+    }
+    return Ok(results);
+  }
+
+  pub fn delete(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    resource_type: &str,
+    resource_name: &str,
+  ) -> Result<(), CommonError> {
+    batch.delete(storage_key_schema_binding(
+      cluster_name,
+      resource_type,
+      resource_name,
+    ));
+    return Ok(());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use protocol::schema::SchemaResourceType;
+
+  use super::{SchemaBinding, SchemaBindingStorage};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn schema_binding_storage_test() {
+    let engine = test_rocksdb_engine("schema_binding_storage_test");
+    let storage = SchemaBindingStorage::new(engine.clone());
+    let topic = SchemaResourceType::MqttTopic.as_str_name();
+    let shard = SchemaResourceType::JournalShard.as_str_name();
+    for (resource_type, resource_name, subject) in [
+      (topic, "sensor/+/temperature", "temperature"),
+      (topic, "sensor/1/humidity", "humidity"),
+      (shard, "ns/temperature", "temperature"),
+    ] {
+      test_commit(&engine, |batch| {
+        storage.save(
+          batch,
+          &SchemaBinding {
+            cluster_name: "mqtt".to_string(),
+            resource_type: resource_type.to_string(),
+            resource_name: resource_name.to_string(),
+            subject: subject.to_string(),
+            create_time: 0,
+          },
+        )
+      });
+    }
+    assert_eq!(storage.list("mqtt", "").unwrap().len(), 3);
+    assert_eq!(storage.list("mqtt", "temperature").unwrap().len(), 2);
+
+    // 主题名称中的 / 不影响读取和删除
+    test_commit(&engine, |batch| {
+      storage.delete(batch, "mqtt", topic, "sensor/+/temperature")
+    });
+    assert!(
+      storage
+        .get("mqtt", topic, "sensor/+/temperature")
+        .unwrap()
+        .is_none()
+    );
+    assert!(
+      storage
+        .get("mqtt", shard, "ns/temperature")
+        .unwrap()
+        .is_some()
+    );
+    assert_eq!(storage.list("mqtt", "temperature").unwrap().len(), 1);
+  }
+}
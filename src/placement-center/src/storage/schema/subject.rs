@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use common_base::error::CommonError;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{
+  batch::StorageBatch,
+  engine::{engine_get_by_cluster, engine_prefix_list_by_cluster},
+  keys::{
+    storage_key_schema_subject, storage_key_schema_subject_prefix, storage_key_schema_version,
+    storage_key_schema_version_cluster_prefix, storage_key_schema_version_subject_prefix,
+  },
+  rocksdb::RocksDBEngine,
+};
+
+/// Schema subject，`schema_type` 和 `compatibility` 为 proto 中枚举的名称，
+/// subject 中所有版本的类型相同
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaSubject {
+  pub cluster_name: String,
+  pub subject: String,
+  pub schema_type: String,
+  pub compatibility: String,
+  pub latest_version: u32,
+  pub create_time: u64,
+}
+
+/// subject 的一个版本，版本号从 1 开始递增
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaVersion {
+  pub cluster_name: String,
+  pub subject: String,
+  pub version: u32,
+  pub schema_type: String,
+  pub definition: String,
+  pub create_time: u64,
+}
+
+pub struct SchemaStorage {
+  rocksdb_engine_handler: Arc<RocksDBEngine>,
+}
+
+impl SchemaStorage {
+  pub fn new(rocksdb_engine_handler: Arc<RocksDBEngine>) -> Self {
+    SchemaStorage {
+      rocksdb_engine_handler,
+    }
+  }
+
+  pub fn save_subject(
+    &self,
+    batch: &mut StorageBatch,
+    subject: &SchemaSubject,
+  ) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_schema_subject(&subject.cluster_name, &subject.subject),
+      subject,
+    );
+  }
+
+  pub fn get_subject(
+    &self,
+    cluster_name: &str,
+    subject: &str,
+  ) -> Result<Option<SchemaSubject>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_schema_subject(cluster_name, subject),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<SchemaSubject>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  pub fn list_subjects(&self, cluster_name: &str) -> Result<Vec<SchemaSubject>, CommonError> {
+    let data_list = engine_prefix_list_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_schema_subject_prefix(cluster_name),
+    )?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<SchemaSubject>(&data.data)?);
+    }
+    return Ok(results);
+  }
+
+  // 删除 subject 以及它的所有版本
+  pub fn delete_subject(
+    &self,
+    batch: &mut StorageBatch,
+    cluster_name: &str,
+    subject: &str,
+  ) -> Result<(), CommonError> {
+    for version in self.list_versions(cluster_name, subject)? {
+      batch.delete(storage_key_schema_version(
+        cluster_name,
+        subject,
+        version.version,
+      ));
+    }
+    batch.delete(storage_key_schema_subject(cluster_name, subject));
+    return Ok(());
+  }
+
+  pub fn save_version(
+    &self,
+    batch: &mut StorageBatch,
+    version: &SchemaVersion,
+  ) -> Result<(), CommonError> {
+    return batch.save(
+      storage_key_schema_version(&version.cluster_name, &version.subject, version.version),
+      version,
+    );
+  }
+
+  pub fn get_version(
+    &self,
+    cluster_name: &str,
+    subject: &str,
+    version: u32,
+  ) -> Result<Option<SchemaVersion>, CommonError> {
+    match engine_get_by_cluster(
+      self.rocksdb_engine_handler.clone(),
+      storage_key_schema_version(cluster_name, subject, version),
+    )? {
+      Some(data) => Ok(Some(serde_json::from_slice::<SchemaVersion>(&data.data)?)),
+      None => Ok(None),
+    }
+  }
+
+  // subject 为空时返回集群中所有 subject 的版本，同一个 subject 的版本按版本号排列
+  pub fn list_versions(
+    &self,
+    cluster_name: &str,
+    subject: &str,
+  ) -> Result<Vec<SchemaVersion>, CommonError> {
+    let prefix = if subject.is_empty() {
+      storage_key_schema_version_cluster_prefix(cluster_name)
+    } else {
+      storage_key_schema_version_subject_prefix(cluster_name, subject)
+    };
+    let data_list = engine_prefix_list_by_cluster(self.rocksdb_engine_handler.clone(), prefix)?;
+    let mut results = Vec::with_capacity(data_list.len());
+    for data in data_list {
+      results.push(serde_json::from_slice::<SchemaVersion>(&data.data)?);
+    }
+    return Ok(results);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{SchemaStorage, SchemaSubject, SchemaVersion};
+  use crate::storage::rocksdb::{test_commit, test_rocksdb_engine};
+
+  #[test]
+  fn schema_storage_test() {
+    let engine = test_rocksdb_engine("schema_storage_test");
+    let storage = SchemaStorage::new(engine.clone());
+    for subject in ["orders", "orders-v2"] {
+      // subject 和它的版本在同一个批次中提交
+      test_commit(&engine, |batch| {
+        storage.save_subject(
+          batch,
+          &SchemaSubject {
+            cluster_name: "mqtt".to_string(),
+            subject: subject.to_string(),
+            latest_version: 10,
+            ..Default::default()
+          },
+        )?;
+        for version in [10, 2, 1] {
+          storage.save_version(
+            batch,
+            &SchemaVersion {
+              cluster_name: "mqtt".to_string(),
+              subject: subject.to_string(),
+              version,
+              ..Default::default()
+            },
+          )?;
+        }
+        return Ok(());
+      });
+    }
+
+    // 版本按版本号排列，subject 名称互为前缀时不会混在一起
+    let versions = storage.list_versions("mqtt", "orders").unwrap();
+    let numbers: Vec<u32> = versions.iter().map(|version| version.version).collect();
+    assert_eq!(numbers, vec![1, 2, 10]);
+    assert_eq!(storage.list_versions("mqtt", "").unwrap().len(), 6);
+
+    test_commit(&engine, |batch| {
+      storage.delete_subject(batch, "mqtt", "orders")
+    });
+    assert!(storage.get_subject("mqtt", "orders").unwrap().is_none());
+    assert!(storage.get_version("mqtt", "orders", 1).unwrap().is_none());
+    assert_eq!(storage.list_subjects("mqtt").unwrap().len(), 1);
+    assert_eq!(storage.list_versions("mqtt", "orders-v2").unwrap().len(), 3);
+  }
+}
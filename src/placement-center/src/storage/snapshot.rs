@@ -0,0 +1,178 @@
+use std::{
+  fs::{self, File},
+  io::{self, BufReader, BufWriter, ErrorKind, Read, Write},
+  path::{Path, PathBuf},
+};
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use common_base::{config::placement_center::SnapshotMode, error::CommonError};
+
+use super::rocksdb::{DB_COLUMN_FAMILY_CLUSTER, RocksDBEngine};
+
+// 快照文件的第一个字节表示快照格式，接收方按格式恢复，不依赖本节点配置的快照模式
+const SNAPSHOT_FORMAT_RECORDS: u8 = 1;
+const SNAPSHOT_FORMAT_CHECKPOINT: u8 = 2;
+
+/// 将 cluster 列蔟的数据写入快照文件。
+///
+/// - `Serialize` 模式：序列化列蔟中的全部数据。
+/// - `Checkpoint` 模式：创建 RocksDB checkpoint，再将 checkpoint 目录中的文件依次写入快照文件，
+///   每个文件的格式为 `名称长度(u32) | 名称 | 文件长度(u64) | 文件内容`。
+pub fn build_storage_snapshot(
+  rocksdb_engine_handler: &RocksDBEngine,
+  path: &Path,
+) -> Result<(), CommonError> {
+  let mut writer = BufWriter::new(File::create(path)?);
+  match rocksdb_engine_handler.snapshot_mode() {
+    SnapshotMode::Serialize => {
+      let records = rocksdb_engine_handler.read_all_by_cf(DB_COLUMN_FAMILY_CLUSTER)?;
+      writer.write_u8(SNAPSHOT_FORMAT_RECORDS)?;
+      bincode::serialize_into(&mut writer, &records)?;
+    }
+    SnapshotMode::Checkpoint => {
+      let checkpoint_dir = temp_dir(rocksdb_engine_handler, "_storage_checkpoint")?;
+      rocksdb_engine_handler.create_checkpoint(&checkpoint_dir)?;
+      writer.write_u8(SNAPSHOT_FORMAT_CHECKPOINT)?;
+      let result = pack_file_set(&checkpoint_dir, &mut writer);
+      fs::remove_dir_all(&checkpoint_dir)?;
+      result?;
+    }
+  }
+
+  let file = writer.into_inner().map_err(|e| e.into_error())?;
+  file.sync_all()?;
+  return Ok(());
+}
+
+/// 使用快照文件恢复 cluster 列蔟，checkpoint 格式的快照解压到临时目录后直接替换数据目录
+pub fn recover_storage_snapshot(
+  rocksdb_engine_handler: &RocksDBEngine,
+  path: &Path,
+) -> Result<(), CommonError> {
+  let mut reader = BufReader::new(File::open(path)?);
+  match reader.read_u8()? {
+    SNAPSHOT_FORMAT_RECORDS => {
+      let records: Vec<(Vec<u8>, Vec<u8>)> = bincode::deserialize_from(&mut reader)?;
+      rocksdb_engine_handler.reset_by_cf(DB_COLUMN_FAMILY_CLUSTER, records)
+    }
+    SNAPSHOT_FORMAT_CHECKPOINT => {
+      let restore_dir = temp_dir(rocksdb_engine_handler, "_storage_restore")?;
+      fs::create_dir_all(&restore_dir)?;
+      if let Err(e) = unpack_file_set(&mut reader, &restore_dir) {
+        fs::remove_dir_all(&restore_dir)?;
+        return Err(e);
+      }
+      rocksdb_engine_handler.restore_from_checkpoint(&restore_dir)
+    }
+    format => Err(CommonError::CommonError(format!(
+      "Unknown snapshot format {}",
+      format
+    ))),
+  }
+}
+
+// 临时目录与数据目录位于同一个文件系统，checkpoint 才能使用硬链接，恢复时才能直接重命名
+fn temp_dir(rocksdb_engine_handler: &RocksDBEngine, name: &str) -> Result<PathBuf, CommonError> {
+  let dir = Path::new(rocksdb_engine_handler.data_path()).join(name);
+  if dir.exists() {
+    fs::remove_dir_all(&dir)?;
+  }
+  return Ok(dir);
+}
+
+fn pack_file_set<W: Write>(dir: &Path, writer: &mut W) -> Result<(), CommonError> {
+  for entry in fs::read_dir(dir)? {
+    let entry = entry?;
+    if !entry.file_type()?.is_file() {
+      continue;
+    }
+
+    let name = entry.file_name().to_string_lossy().to_string();
+    let mut file = File::open(entry.path())?;
+    writer.write_u32::<BigEndian>(name.len() as u32)?;
+    writer.write_all(name.as_bytes())?;
+    writer.write_u64::<BigEndian>(file.metadata()?.len())?;
+    io::copy(&mut file, writer)?;
+  }
+  return Ok(());
+}
+
+fn unpack_file_set<R: Read>(reader: &mut R, dir: &Path) -> Result<(), CommonError> {
+  loop {
+    let name_len = match reader.read_u32::<BigEndian>() {
+      Ok(len) => len,
+      Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(()),
+      Err(e) => return Err(e.into()),
+    };
+
+    let mut name = vec![0; name_len as usize];
+    reader.read_exact(&mut name)?;
+    let name = String::from_utf8(name)?;
+    // 文件名来自其它节点，只允许写入恢复目录中
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+      return Err(CommonError::CommonError(format!(
+        "Invalid file name {} in snapshot",
+        name
+      )));
+    }
+
+    let size = reader.read_u64::<BigEndian>()?;
+    let mut file = File::create(dir.join(&name))?;
+    let copied = io::copy(&mut reader.by_ref().take(size), &mut file)?;
+    if copied != size {
+      return Err(CommonError::CommonError(format!(
+        "Snapshot file {} is truncated, expected {} bytes, got {} bytes",
+        name, size, copied
+      )));
+    }
+    file.sync_all()?;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use common_base::config::placement_center::SnapshotMode;
+
+  use crate::storage::rocksdb::{DB_COLUMN_FAMILY_CLUSTER, test_rocksdb_engine};
+
+  use super::{build_storage_snapshot, recover_storage_snapshot};
+
+  #[test]
+  fn checkpoint_snapshot_test() {
+    let engine = test_rocksdb_engine("checkpoint_snapshot_test");
+    assert_eq!(engine.snapshot_mode(), SnapshotMode::Checkpoint);
+    let snapshot_path = format!("{}/snapshot.snap", engine.data_path());
+
+    engine
+      .write_batch(
+        DB_COLUMN_FAMILY_CLUSTER,
+        vec![
+          (b"k1".to_vec(), Some(b"v1".to_vec())),
+          (b"k2".to_vec(), Some(b"v2".to_vec())),
+        ],
+      )
+      .unwrap();
+    build_storage_snapshot(&engine, snapshot_path.as_ref()).unwrap();
+
+    // 快照之后的写入在恢复后不再存在
+    engine
+      .write_batch(
+        DB_COLUMN_FAMILY_CLUSTER,
+        vec![
+          (b"k1".to_vec(), None),
+          (b"k3".to_vec(), Some(b"v3".to_vec())),
+        ],
+      )
+      .unwrap();
+    recover_storage_snapshot(&engine, snapshot_path.as_ref()).unwrap();
+
+    let data = engine.read_all_by_cf(DB_COLUMN_FAMILY_CLUSTER).unwrap();
+    assert_eq!(
+      data,
+      vec![
+        (b"k1".to_vec(), b"v1".to_vec()),
+        (b"k2".to_vec(), b"v2".to_vec()),
+      ]
+    );
+  }
+}
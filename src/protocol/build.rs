@@ -6,7 +6,14 @@ fn main() {
       &[
         "src/pb/common.proto",
         "src/pb/kv.proto",
+        "src/pb/kv_v2.proto",
         "src/pb/placement.proto",
+        "src/pb/mqtt.proto",
+        "src/pb/amqp.proto",
+        "src/pb/schema.proto",
+        "src/pb/config.proto",
+        "src/pb/id.proto",
+        "src/pb/journal.proto",
         "src/pb/openraft.proto",
       ],
       &["src/pb"],
@@ -1,7 +1,14 @@
+pub mod amqp;
 pub mod common;
+pub mod config;
+pub mod id;
+pub mod journal;
 pub mod kv;
+pub mod kv_v2;
+pub mod mqtt;
 pub mod openraft;
 pub mod placement;
+pub mod schema;
 
 pub use common::*;
 pub use kv::*;
@@ -19,16 +19,41 @@ pub struct AppendReply {
     #[prost(bytes = "vec", tag = "1")]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+/// 快照按固定大小分块发送，offset 为分块在快照文件中的起始位置，checksum 为 data 的 CRC32。
+/// 最后一个分块 done 为 true，并携带快照文件的总长度以及 bincode 序列化后的 vote 和快照元数据
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SnapshotRequest {
-    #[prost(bytes = "vec", tag = "1")]
-    pub value: ::prost::alloc::vec::Vec<u8>,
+pub struct SnapshotChunk {
+    #[prost(string, tag = "1")]
+    pub snapshot_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub offset: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag = "4")]
+    pub checksum: u32,
+    #[prost(bool, tag = "5")]
+    pub done: bool,
+    #[prost(uint64, tag = "6")]
+    pub total_size: u64,
+    #[prost(bytes = "vec", tag = "7")]
+    pub meta: ::prost::alloc::vec::Vec<u8>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotReply {
     #[prost(bytes = "vec", tag = "1")]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+/// 查询接收方已经收到的快照数据长度，发送方从该位置继续发送，中断的传输不需要从头开始
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotOffsetRequest {
+    #[prost(string, tag = "1")]
+    pub snapshot_id: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SnapshotOffsetReply {
+    #[prost(uint64, tag = "1")]
+    pub offset: u64,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddLearnerRequest {
     #[prost(uint64, tag = "1")]
@@ -197,7 +222,7 @@ pub mod open_raft_service_client {
         }
         pub async fn snapshot(
             &mut self,
-            request: impl tonic::IntoRequest<super::SnapshotRequest>,
+            request: impl tonic::IntoStreamingRequest<Message = super::SnapshotChunk>,
         ) -> std::result::Result<tonic::Response<super::SnapshotReply>, tonic::Status> {
             self.inner
                 .ready()
@@ -211,9 +236,33 @@ pub mod open_raft_service_client {
             let path = http::uri::PathAndQuery::from_static(
                 "/openraft.OpenRaftService/snapshot",
             );
-            let mut req = request.into_request();
+            let mut req = request.into_streaming_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("openraft.OpenRaftService", "snapshot"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        pub async fn snapshot_offset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SnapshotOffsetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SnapshotOffsetReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/openraft.OpenRaftService/snapshot_offset",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("openraft.OpenRaftService", "snapshot_offset"));
             self.inner.unary(req, path, codec).await
         }
         pub async fn add_learner(
@@ -291,8 +340,15 @@ pub mod open_raft_service_server {
         ) -> std::result::Result<tonic::Response<super::AppendReply>, tonic::Status>;
         async fn snapshot(
             &self,
-            request: tonic::Request<super::SnapshotRequest>,
+            request: tonic::Request<tonic::Streaming<super::SnapshotChunk>>,
         ) -> std::result::Result<tonic::Response<super::SnapshotReply>, tonic::Status>;
+        async fn snapshot_offset(
+            &self,
+            request: tonic::Request<super::SnapshotOffsetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SnapshotOffsetReply>,
+            tonic::Status,
+        >;
         async fn add_learner(
             &self,
             request: tonic::Request<super::AddLearnerRequest>,
@@ -475,7 +531,7 @@ pub mod open_raft_service_server {
                     struct snapshotSvc<T: OpenRaftService>(pub Arc<T>);
                     impl<
                         T: OpenRaftService,
-                    > tonic::server::UnaryService<super::SnapshotRequest>
+                    > tonic::server::ClientStreamingService<super::SnapshotChunk>
                     for snapshotSvc<T> {
                         type Response = super::SnapshotReply;
                         type Future = BoxFuture<
@@ -484,7 +540,9 @@ pub mod open_raft_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SnapshotRequest>,
+                            request: tonic::Request<
+                                tonic::Streaming<super::SnapshotChunk>,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
@@ -501,6 +559,52 @@ pub mod open_raft_service_server {
                     let fut = async move {
                         let method = snapshotSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/openraft.OpenRaftService/snapshot_offset" => {
+                    #[allow(non_camel_case_types)]
+                    struct snapshot_offsetSvc<T: OpenRaftService>(pub Arc<T>);
+                    impl<
+                        T: OpenRaftService,
+                    > tonic::server::UnaryService<super::SnapshotOffsetRequest>
+                    for snapshot_offsetSvc<T> {
+                        type Response = super::SnapshotOffsetReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SnapshotOffsetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as OpenRaftService>::snapshot_offset(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = snapshot_offsetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
                                 accept_compression_encodings,
@@ -13,6 +13,93 @@ pub struct SendRaftConfChangeRequest {
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SendRaftConfChangeReply {}
+/// 集群由名称唯一标识，首次注册节点时自动创建，同名集群的类型必须一致
+/// node_inner_addr 为节点之间通信使用的 gRPC 地址
+/// extend_info 为节点自定义的扩展信息（如 JSON 字符串），Placement Center 只负责保存
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterNodeRequest {
+    #[prost(enumeration = "super::common::ClusterType", tag = "1")]
+    pub cluster_type: i32,
+    #[prost(string, tag = "2")]
+    pub cluster_name: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub node_id: u64,
+    #[prost(string, tag = "4")]
+    pub node_ip: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub node_inner_addr: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub extend_info: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnRegisterNodeRequest {
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub node_id: u64,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListClusterRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClusterInfo {
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "super::common::ClusterType", tag = "2")]
+    pub cluster_type: i32,
+    #[prost(uint64, tag = "3")]
+    pub create_time: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListClusterReply {
+    #[prost(message, repeated, tag = "1")]
+    pub clusters: ::prost::alloc::vec::Vec<ClusterInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNodeRequest {
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NodeInfo {
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "super::common::ClusterType", tag = "2")]
+    pub cluster_type: i32,
+    #[prost(uint64, tag = "3")]
+    pub node_id: u64,
+    #[prost(string, tag = "4")]
+    pub node_ip: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub node_inner_addr: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub extend_info: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "7")]
+    pub create_time: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNodeReply {
+    #[prost(message, repeated, tag = "1")]
+    pub nodes: ::prost::alloc::vec::Vec<NodeInfo>,
+}
+/// Broker 定期向 Leader 上报心跳，超过 heartbeat.hearbeat_timeout_ms 没有心跳的节点会被移出集群
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HeartbeatRequest {
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub node_id: u64,
+}
+/// 以下为提交到 Raft 日志中的数据，由 Leader 移除心跳超时的节点。
+/// register_index 为 Leader 检查时节点注册信息对应的日志索引，节点在此之后重新注册时不会被移除
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveExpiredNodeData {
+    #[prost(string, tag = "1")]
+    pub cluster_name: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub node_id: u64,
+    #[prost(uint64, tag = "3")]
+    pub register_index: u64,
+}
 /// Generated client implementations.
 pub mod placement_center_server_client {
     #![allow(
@@ -159,6 +246,129 @@ pub mod placement_center_server_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn register_node(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RegisterNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::CommonReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/placement.PlacementCenterServer/RegisterNode",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("placement.PlacementCenterServer", "RegisterNode"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn un_register_node(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnRegisterNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::CommonReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/placement.PlacementCenterServer/UnRegisterNode",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("placement.PlacementCenterServer", "UnRegisterNode"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_cluster(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListClusterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListClusterReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/placement.PlacementCenterServer/ListCluster",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("placement.PlacementCenterServer", "ListCluster"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_node(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListNodeRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListNodeReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/placement.PlacementCenterServer/ListNode",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("placement.PlacementCenterServer", "ListNode"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn heartbeat(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HeartbeatRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::CommonReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/placement.PlacementCenterServer/Heartbeat",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("placement.PlacementCenterServer", "Heartbeat"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -188,6 +398,38 @@ pub mod placement_center_server_server {
             tonic::Response<super::SendRaftConfChangeReply>,
             tonic::Status,
         >;
+        async fn register_node(
+            &self,
+            request: tonic::Request<super::RegisterNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::CommonReply>,
+            tonic::Status,
+        >;
+        async fn un_register_node(
+            &self,
+            request: tonic::Request<super::UnRegisterNodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::CommonReply>,
+            tonic::Status,
+        >;
+        async fn list_cluster(
+            &self,
+            request: tonic::Request<super::ListClusterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListClusterReply>,
+            tonic::Status,
+        >;
+        async fn list_node(
+            &self,
+            request: tonic::Request<super::ListNodeRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListNodeReply>, tonic::Status>;
+        async fn heartbeat(
+            &self,
+            request: tonic::Request<super::HeartbeatRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::common::CommonReply>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct PlacementCenterServerServer<T> {
@@ -364,6 +606,243 @@ pub mod placement_center_server_server {
                     };
                     Box::pin(fut)
                 }
+                "/placement.PlacementCenterServer/RegisterNode" => {
+                    #[allow(non_camel_case_types)]
+                    struct RegisterNodeSvc<T: PlacementCenterServer>(pub Arc<T>);
+                    impl<
+                        T: PlacementCenterServer,
+                    > tonic::server::UnaryService<super::RegisterNodeRequest>
+                    for RegisterNodeSvc<T> {
+                        type Response = super::super::common::CommonReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RegisterNodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlacementCenterServer>::register_node(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterNodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/placement.PlacementCenterServer/UnRegisterNode" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnRegisterNodeSvc<T: PlacementCenterServer>(pub Arc<T>);
+                    impl<
+                        T: PlacementCenterServer,
+                    > tonic::server::UnaryService<super::UnRegisterNodeRequest>
+                    for UnRegisterNodeSvc<T> {
+                        type Response = super::super::common::CommonReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnRegisterNodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlacementCenterServer>::un_register_node(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UnRegisterNodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/placement.PlacementCenterServer/ListCluster" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListClusterSvc<T: PlacementCenterServer>(pub Arc<T>);
+                    impl<
+                        T: PlacementCenterServer,
+                    > tonic::server::UnaryService<super::ListClusterRequest>
+                    for ListClusterSvc<T> {
+                        type Response = super::ListClusterReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListClusterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlacementCenterServer>::list_cluster(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListClusterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/placement.PlacementCenterServer/ListNode" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListNodeSvc<T: PlacementCenterServer>(pub Arc<T>);
+                    impl<
+                        T: PlacementCenterServer,
+                    > tonic::server::UnaryService<super::ListNodeRequest>
+                    for ListNodeSvc<T> {
+                        type Response = super::ListNodeReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListNodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlacementCenterServer>::list_node(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListNodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/placement.PlacementCenterServer/Heartbeat" => {
+                    #[allow(non_camel_case_types)]
+                    struct HeartbeatSvc<T: PlacementCenterServer>(pub Arc<T>);
+                    impl<
+                        T: PlacementCenterServer,
+                    > tonic::server::UnaryService<super::HeartbeatRequest>
+                    for HeartbeatSvc<T> {
+                        type Response = super::super::common::CommonReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HeartbeatRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlacementCenterServer>::heartbeat(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HeartbeatSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());